@@ -0,0 +1,12 @@
+use tauri::{AppHandle, ipc::InvokeError};
+use crate::services::export_service::{self, ExportReport, ExportRequest};
+use crate::utils::IntoInvokeError;
+
+/// 섹션(또는 사이트 전체)을 이미지/링크를 정리한 zip으로 내보내기. 서버에서 받는 동안 `transfer:progress`
+#[tauri::command]
+pub async fn export_section(request: ExportRequest, app: AppHandle) -> Result<ExportReport, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || export_service::export_section(&request, &app))
+        .await
+        .map_err(|e| InvokeError::from(format!("Export task panicked: {}", e)))?
+        .into_invoke_err()
+}
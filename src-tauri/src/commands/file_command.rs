@@ -1,4 +1,7 @@
-use crate::services::file_service::{self, FileSystemNode};
+use crate::services::file_service::{
+    self, Archetype, DirPage, FileSystemNode, InitialFrontMatter, PublishAction, PublishState, SaveConflict,
+    SaveOutcome, VersionedContent,
+};
 use crate::utils::IntoInvokeError;
 use tauri::ipc::InvokeError;
 
@@ -8,13 +11,28 @@ pub fn get_file_tree() -> Result<Vec<FileSystemNode>, InvokeError> {
 }
 
 #[tauri::command]
-pub fn get_file_content(file_path: &str) -> Result<String, InvokeError> {
-    file_service::read_content(file_path).into_invoke_err()
+pub fn get_dir_children(dir_path: &str, offset: usize, limit: Option<usize>) -> Result<DirPage, InvokeError> {
+    file_service::list_dir_children(dir_path, offset, limit).into_invoke_err()
 }
 
 #[tauri::command]
-pub fn save_file_content(file_path: &str, file_data: &str, manual: bool) -> Result<bool, InvokeError> {
-    file_service::write_content(file_path, file_data, manual).into_invoke_err()
+pub fn get_file_content(file_path: &str) -> Result<VersionedContent, InvokeError> {
+    file_service::read_content_versioned(file_path).into_invoke_err()
+}
+
+/// 충돌 시 에러는 문자열 대신 SaveConflict 객체로 전달된다
+#[tauri::command]
+pub fn save_file_content(
+    file_path: &str,
+    file_data: &str,
+    manual: bool,
+    expected_version: Option<String>,
+) -> Result<SaveOutcome, InvokeError> {
+    file_service::write_content(file_path, file_data, manual, expected_version.as_deref())
+        .map_err(|e| match e.downcast::<SaveConflict>() {
+            Ok(conflict) => InvokeError::from(conflict),
+            Err(e) => InvokeError::from(e.to_string()),
+        })
 }
 
 #[tauri::command]
@@ -27,8 +45,17 @@ pub fn save_file_image(
 }
 
 #[tauri::command]
-pub fn new_content_for_hugo(file_path: &str) -> Result<String, InvokeError> {
-    file_service::create_content(file_path).into_invoke_err()
+pub fn new_content_for_hugo(
+    file_path: &str,
+    kind: Option<String>,
+    front_matter: Option<InitialFrontMatter>,
+) -> Result<String, InvokeError> {
+    file_service::create_content(file_path, kind.as_deref(), front_matter.as_ref()).into_invoke_err()
+}
+
+#[tauri::command]
+pub fn list_archetypes() -> Result<Vec<Archetype>, InvokeError> {
+    file_service::list_archetypes().into_invoke_err()
 }
 
 #[tauri::command]
@@ -41,6 +68,12 @@ pub fn move_file_or_folder(src: &str, dst: &str) -> Result<(), InvokeError> {
     file_service::move_content(src, dst).into_invoke_err()
 }
 
+/// 복제된 새 상대경로 반환
+#[tauri::command]
+pub fn duplicate_file_or_folder(src: &str, reset_front_matter: bool) -> Result<String, InvokeError> {
+    file_service::duplicate_content(src, reset_front_matter).into_invoke_err()
+}
+
 #[tauri::command]
 pub fn toggle_hidden_file(path: &str, state: bool) -> Result<(), InvokeError> {
     file_service::toggle_hidden(path, state).into_invoke_err()
@@ -60,3 +93,18 @@ pub fn download_remote_files(items: Vec<(String, String)>) -> Vec<Result<(), Str
 pub fn sync_pasted_refs(file_path: &str, pasted_text: &str) -> Result<String, InvokeError> {
     file_service::sync_pasted_refs(file_path, pasted_text).into_invoke_err()
 }
+
+#[tauri::command]
+pub fn publish_content(file_path: &str) -> Result<PublishState, InvokeError> {
+    file_service::set_publish_state(file_path, PublishAction::Publish).into_invoke_err()
+}
+
+#[tauri::command]
+pub fn unpublish_content(file_path: &str) -> Result<PublishState, InvokeError> {
+    file_service::set_publish_state(file_path, PublishAction::Unpublish).into_invoke_err()
+}
+
+#[tauri::command]
+pub fn schedule_content(file_path: &str, publish_date: &str) -> Result<PublishState, InvokeError> {
+    file_service::set_publish_state(file_path, PublishAction::Schedule(publish_date.to_string())).into_invoke_err()
+}
@@ -0,0 +1,20 @@
+use tauri::ipc::InvokeError;
+use crate::services::file_service::SaveOutcome;
+use crate::services::history_service::{self, Revision};
+use crate::utils::IntoInvokeError;
+
+#[tauri::command]
+pub fn list_revisions(file_path: &str) -> Result<Vec<Revision>, InvokeError> {
+    history_service::list_revisions(file_path).into_invoke_err()
+}
+
+/// to_id를 생략하면 현재 파일 내용과 비교
+#[tauri::command]
+pub fn diff_revisions(file_path: &str, from_id: &str, to_id: Option<String>) -> Result<String, InvokeError> {
+    history_service::diff_revisions(file_path, from_id, to_id.as_deref()).into_invoke_err()
+}
+
+#[tauri::command]
+pub fn restore_revision(file_path: &str, revision_id: &str) -> Result<SaveOutcome, InvokeError> {
+    history_service::restore_revision(file_path, revision_id).into_invoke_err()
+}
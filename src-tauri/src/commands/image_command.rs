@@ -0,0 +1,39 @@
+use tauri::ipc::InvokeError;
+use crate::services::image_service::{self, ImageThumbnail, LibraryImage, OrphanAction, OrphanReport, ThumbnailRequest};
+use crate::utils::IntoInvokeError;
+
+/// image_path 아래에서 어떤 페이지도 참조하지 않는 이미지 목록
+#[tauri::command]
+pub async fn find_orphan_images() -> Result<OrphanReport, InvokeError> {
+    tauri::async_runtime::spawn_blocking(image_service::find_orphan_images)
+        .await
+        .map_err(|e| InvokeError::from(format!("Orphan scan task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 고아 이미지 삭제/휴지통 이동 (dry_run이면 대상만 반환)
+#[tauri::command]
+pub async fn clean_orphan_images(paths: Vec<String>, action: OrphanAction, dry_run: bool) -> Result<OrphanReport, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || image_service::clean_orphan_images(&paths, action, dry_run))
+        .await
+        .map_err(|e| InvokeError::from(format!("Orphan cleanup task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 이미지 목록 (page_path가 있으면 그 페이지의 이미지만)
+#[tauri::command]
+pub async fn list_images(page_path: Option<String>) -> Result<Vec<LibraryImage>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || image_service::list_images(page_path.as_deref()))
+        .await
+        .map_err(|e| InvokeError::from(format!("Image list task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 썸네일 (로컬 캐시, 없으면 생성)
+#[tauri::command]
+pub async fn get_image_thumbnails(items: Vec<ThumbnailRequest>) -> Result<Vec<ImageThumbnail>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || image_service::get_image_thumbnails(&items))
+        .await
+        .map_err(|e| InvokeError::from(format!("Thumbnail task panicked: {}", e)))?
+        .into_invoke_err()
+}
@@ -0,0 +1,21 @@
+use tauri::{AppHandle, ipc::InvokeError};
+use crate::services::import_service::{self, FolderImportRequest, ImportReport, ImportRequest};
+use crate::utils::IntoInvokeError;
+
+/// Notion / WordPress / Jekyll 내보내기를 섹션 아래 페이지로 가져오기 (dry_run이면 계획만)
+#[tauri::command]
+pub async fn import_content(request: ImportRequest) -> Result<ImportReport, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || import_service::import_content(&request))
+        .await
+        .map_err(|e| InvokeError::from(format!("Import task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 로컬 마크다운 폴더(Obsidian, Typora)를 섹션 아래로 가져오기. 파일은 tar 하나로 올린다 (`transfer:progress`)
+#[tauri::command]
+pub async fn import_markdown_folder(request: FolderImportRequest, app: AppHandle) -> Result<ImportReport, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || import_service::import_markdown_folder(&request, &app))
+        .await
+        .map_err(|e| InvokeError::from(format!("Folder import task panicked: {}", e)))?
+        .into_invoke_err()
+}
@@ -0,0 +1,31 @@
+use tauri::ipc::InvokeError;
+use crate::services::link_check_service::{self, LinkIssue};
+use crate::services::link_service::{self, LinkRewrite};
+use crate::utils::IntoInvokeError;
+
+/// 이동 전에 바뀔 링크 목록 미리보기
+#[tauri::command]
+pub async fn preview_link_rewrites(src: String, dst: String) -> Result<Vec<LinkRewrite>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || link_service::preview_link_rewrites(&src, &dst))
+        .await
+        .map_err(|e| InvokeError::from(format!("Link scan task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 이동 + 사이트 전체의 inbound 링크 재작성. 적용된 재작성 목록 반환.
+#[tauri::command]
+pub async fn move_with_link_rewrite(src: String, dst: String, add_aliases: bool) -> Result<Vec<LinkRewrite>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || link_service::move_with_links(&src, &dst, add_aliases))
+        .await
+        .map_err(|e| InvokeError::from(format!("Move task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 사이트 전체 링크/이미지 점검
+#[tauri::command]
+pub async fn check_site_links(check_external: bool) -> Result<Vec<LinkIssue>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || link_check_service::check_site_links(check_external))
+        .await
+        .map_err(|e| InvokeError::from(format!("Link check task panicked: {}", e)))?
+        .into_invoke_err()
+}
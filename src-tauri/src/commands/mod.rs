@@ -4,4 +4,14 @@ pub mod config_command;
 pub mod setup_command;
 pub mod pty_command;
 pub mod plugin_command;
-pub mod transfer_command;
\ No newline at end of file
+pub mod transfer_command;
+pub mod watch_command;
+pub mod history_command;
+pub mod trash_command;
+pub mod link_command;
+pub mod image_command;
+pub mod taxonomy_command;
+pub mod search_command;
+pub mod replace_command;
+pub mod import_command;
+pub mod export_command;
@@ -0,0 +1,40 @@
+use tauri::ipc::InvokeError;
+use crate::services::replace_service::{
+    self, ReplaceBatchSummary, ReplaceFilePreview, ReplaceRequest, ReplaceResult, ReplaceSelection, ReplaceUndoResult,
+};
+use crate::utils::IntoInvokeError;
+
+/// 찾아 바꾸기 미리보기 (파일별, 줄별 일치)
+#[tauri::command]
+pub async fn preview_replace(request: ReplaceRequest) -> Result<Vec<ReplaceFilePreview>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || replace_service::preview_replace(&request))
+        .await
+        .map_err(|e| InvokeError::from(format!("Replace scan task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 선택한 일치만 바꿔 저장. 되돌리기용 묶음 id 반환.
+#[tauri::command]
+pub async fn apply_replace(request: ReplaceRequest, selections: Vec<ReplaceSelection>) -> Result<ReplaceResult, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || replace_service::apply_replace(&request, &selections))
+        .await
+        .map_err(|e| InvokeError::from(format!("Replace task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+#[tauri::command]
+pub async fn list_replace_batches() -> Result<Vec<ReplaceBatchSummary>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(replace_service::list_replace_batches)
+        .await
+        .map_err(|e| InvokeError::from(format!("Replace batch task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 적용 묶음 한 번에 되돌리기
+#[tauri::command]
+pub async fn undo_replace_batch(id: String) -> Result<ReplaceUndoResult, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || replace_service::undo_replace_batch(&id))
+        .await
+        .map_err(|e| InvokeError::from(format!("Replace undo task panicked: {}", e)))?
+        .into_invoke_err()
+}
@@ -0,0 +1,40 @@
+use tauri::ipc::InvokeError;
+use crate::services::search_index_service::{self, IndexHit, IndexQueryOptions, IndexSyncReport};
+use crate::services::search_service::{self, SavedSearchFolder, SearchHit, SearchOptions};
+use crate::utils::IntoInvokeError;
+
+/// 구조화 쿼리 검색 (`tag:rust draft:true "phrase" -word` 등)
+#[tauri::command]
+pub async fn search_query(query: String, options: SearchOptions) -> Result<Vec<SearchHit>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || search_service::search_query(&query, &options))
+        .await
+        .map_err(|e| InvokeError::from(format!("Search task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 로컬 인덱스 검색 (prefix/fuzzy, 스니펫 + 강조). 오래된 인덱스는 백그라운드에서 동기화
+#[tauri::command]
+pub async fn query_search_index(query: String, options: IndexQueryOptions) -> Result<Vec<IndexHit>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || search_index_service::query_search_index(&query, &options))
+        .await
+        .map_err(|e| InvokeError::from(format!("Search task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 원격 파일 해시와 비교해 로컬 인덱스 갱신. rebuild=true면 처음부터 다시 만든다
+#[tauri::command]
+pub async fn sync_search_index(rebuild: bool) -> Result<IndexSyncReport, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || search_index_service::sync_search_index(rebuild))
+        .await
+        .map_err(|e| InvokeError::from(format!("Index sync task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 서버 설정의 저장된 검색을 실행한 스마트 폴더 목록 (트리 새로고침 때 함께 호출)
+#[tauri::command]
+pub async fn list_saved_search_folders() -> Result<Vec<SavedSearchFolder>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(search_service::list_saved_search_folders)
+        .await
+        .map_err(|e| InvokeError::from(format!("Saved search task panicked: {}", e)))?
+        .into_invoke_err()
+}
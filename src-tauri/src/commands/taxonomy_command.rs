@@ -0,0 +1,30 @@
+use tauri::ipc::InvokeError;
+use crate::services::taxonomy_service::{self, Taxonomy, TaxonomyChange, TaxonomyPageChange};
+use crate::utils::IntoInvokeError;
+
+/// 설정된 taxonomy별 용어와 페이지 수
+#[tauri::command]
+pub async fn list_taxonomies() -> Result<Vec<Taxonomy>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(taxonomy_service::list_taxonomies)
+        .await
+        .map_err(|e| InvokeError::from(format!("Taxonomy scan task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 용어 이름 변경/병합 시 바뀔 페이지 미리보기 (hidden 포함)
+#[tauri::command]
+pub async fn preview_taxonomy_change(change: TaxonomyChange) -> Result<Vec<TaxonomyPageChange>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || taxonomy_service::preview_taxonomy_change(&change))
+        .await
+        .map_err(|e| InvokeError::from(format!("Taxonomy scan task panicked: {}", e)))?
+        .into_invoke_err()
+}
+
+/// 용어 이름 변경/병합 적용. 적용된 페이지 목록 반환.
+#[tauri::command]
+pub async fn apply_taxonomy_change(change: TaxonomyChange) -> Result<Vec<TaxonomyPageChange>, InvokeError> {
+    tauri::async_runtime::spawn_blocking(move || taxonomy_service::apply_taxonomy_change(&change))
+        .await
+        .map_err(|e| InvokeError::from(format!("Taxonomy update task panicked: {}", e)))?
+        .into_invoke_err()
+}
@@ -0,0 +1,19 @@
+use tauri::ipc::InvokeError;
+use crate::services::trash_service::{self, TrashItem};
+use crate::utils::IntoInvokeError;
+
+#[tauri::command]
+pub fn list_trash() -> Result<Vec<TrashItem>, InvokeError> {
+    trash_service::list_trash().into_invoke_err()
+}
+
+/// 복원된 경로 반환 (Content: content 상대경로, Remote: 원격 절대경로)
+#[tauri::command]
+pub fn restore_trash_item(id: &str) -> Result<String, InvokeError> {
+    trash_service::restore_trash(id).into_invoke_err()
+}
+
+#[tauri::command]
+pub fn purge_trash_items(ids: Vec<String>) -> Result<(), InvokeError> {
+    trash_service::purge_trash(&ids).into_invoke_err()
+}
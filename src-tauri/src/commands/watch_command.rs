@@ -0,0 +1,14 @@
+use tauri::ipc::InvokeError;
+use crate::services::watch_service::{self, WatchMode};
+use crate::utils::IntoInvokeError;
+
+/// 원격 content/image 변경 감시 시작 — 변경 시 `content:changed` 이벤트 발생
+#[tauri::command]
+pub fn start_content_watcher() -> Result<WatchMode, InvokeError> {
+    watch_service::start_watcher().into_invoke_err()
+}
+
+#[tauri::command]
+pub fn stop_content_watcher() -> Result<(), InvokeError> {
+    watch_service::stop_watcher().into_invoke_err()
+}
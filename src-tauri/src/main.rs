@@ -31,9 +31,10 @@ pub fn app_handle() -> Option<&'static tauri::AppHandle> {
 }
 use commands::{
     file_command::{
-        get_file_content, get_file_tree, move_file_or_folder,
+        get_file_content, get_file_tree, get_dir_children, move_file_or_folder, duplicate_file_or_folder,
         new_content_for_hugo, remove_file, save_file_content, save_file_image,
         toggle_hidden_file, check_file_hidden, download_remote_files, sync_pasted_refs,
+        publish_content, unpublish_content, schedule_content, list_archetypes,
     },
     config_command::{
         load_config, save_config, save_plugin_local_path, switch_server, check_connection,
@@ -48,6 +49,16 @@ use commands::{
         git_init_site_cmd, install_theme_cmd,
     },
     pty_command::{start_pty_cmd, write_pty_cmd, resize_pty_cmd, stop_pty_cmd},
+    watch_command::{start_content_watcher, stop_content_watcher},
+    history_command::{list_revisions, diff_revisions, restore_revision},
+    trash_command::{list_trash, restore_trash_item, purge_trash_items},
+    link_command::{preview_link_rewrites, move_with_link_rewrite, check_site_links},
+    image_command::{find_orphan_images, clean_orphan_images, list_images, get_image_thumbnails},
+    taxonomy_command::{list_taxonomies, preview_taxonomy_change, apply_taxonomy_change},
+    search_command::{search_query, query_search_index, sync_search_index, list_saved_search_folders},
+    replace_command::{preview_replace, apply_replace, list_replace_batches, undo_replace_batch},
+    import_command::{import_content, import_markdown_folder},
+    export_command::export_section,
     plugin_command::{
         list_plugins, install_plugin, uninstall_plugin,
         enable_plugin, disable_plugin, run_plugin,
@@ -103,11 +114,14 @@ fn main() -> Result<()> {
             switch_server,
             check_connection,
             get_file_tree,
+            get_dir_children,
             get_file_content,
             save_file_content,
             save_file_image,
             new_content_for_hugo,
+            list_archetypes,
             move_file_or_folder,
+            duplicate_file_or_folder,
             remove_file,
             kill_server,
             start_server,
@@ -118,6 +132,9 @@ fn main() -> Result<()> {
             check_file_hidden,
             download_remote_files,
             sync_pasted_refs,
+            publish_content,
+            unpublish_content,
+            schedule_content,
             check_prerequisites_cmd,
             check_hugo_installed_cmd,
             detect_server_platform_cmd,
@@ -132,6 +149,35 @@ fn main() -> Result<()> {
             write_pty_cmd,
             resize_pty_cmd,
             stop_pty_cmd,
+            start_content_watcher,
+            stop_content_watcher,
+            list_revisions,
+            diff_revisions,
+            restore_revision,
+            list_trash,
+            restore_trash_item,
+            purge_trash_items,
+            preview_link_rewrites,
+            move_with_link_rewrite,
+            check_site_links,
+            find_orphan_images,
+            clean_orphan_images,
+            list_images,
+            get_image_thumbnails,
+            list_taxonomies,
+            preview_taxonomy_change,
+            apply_taxonomy_change,
+            search_query,
+            query_search_index,
+            sync_search_index,
+            list_saved_search_folders,
+            import_content,
+            import_markdown_folder,
+            export_section,
+            preview_replace,
+            apply_replace,
+            list_replace_batches,
+            undo_replace_batch,
             list_plugins,
             install_plugin,
             uninstall_plugin,
@@ -19,6 +19,7 @@ pub fn load_app_config() -> Result<AppConfig> {
         servers: client.servers.clone(),
         cms_config: CmsConfig::default(),
         shortcuts: HashMap::new(),
+        saved_searches: Vec::new(),
         plugin_local_path: client.plugin_local_path.clone(),
         download_path: client.download_path.clone(),
     };
@@ -83,9 +84,13 @@ pub fn switch_server(servers: Vec<crate::types::config::ServerEntry>, server_id:
     config.servers = servers;
     config.active_server = server_id;
 
+    // 이전 서버의 변경 감시 중지 (새 서버 연결 후 프론트엔드가 다시 시작)
+    crate::services::watch_service::stop_watcher()?;
+
     // cms_config 초기화 (새 서버의 설정을 로드할 것이므로)
     config.cms_config = CmsConfig::default();
     config.shortcuts = HashMap::new();
+    config.saved_searches = Vec::new();
 
     // 서버 목록을 먼저 로컬에 저장 — 연결에 실패해도(오프라인 서버 추가 등)
     // UI에서 편집한 서버 목록이 사라지지 않도록 한다
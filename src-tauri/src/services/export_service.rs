@@ -0,0 +1,347 @@
+//! 섹션(또는 사이트 전체)을 다른 곳에서 바로 열 수 있는 zip으로 내보내기. 백업/사이트 간 이동용.
+//!
+//! - 페이지: content 기준 경로 그대로 (front matter 포함). hidden 페이지는 `{hidden_path}/…` 아래.
+//!   번들 안의 다른 파일(page resource)도 같이 넣는다.
+//! - 이미지: 서버에서는 `image_path` 트리에 따로 있으므로, 참조된 파일만 페이지 옆에 복사한다.
+//!   일반 페이지는 `{이름}.assets/`, leaf bundle(`index.md`)은 번들 디렉토리 안.
+//! - 링크: 이미지 참조와, 내보낸 페이지를 가리키는 링크(`[..](..)`, `ref`/`relref`)를 zip 안의 상대경로로 바꾼다.
+//!   내보내지 않은 페이지로 가는 링크는 그대로 둔다.
+//! - manifest: 선택 시 `manifest.json`에 원래 경로/제목/첨부 목록을 기록한다.
+//!
+//! 서버에서는 content와 이미지를 각각 tar 한 번으로 받는다 (`transfer_service::download_generated`).
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::Write;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+use zip::write::SimpleFileOptions;
+
+use crate::services::config_service::get_hugo_config;
+use crate::services::file_service::{self, split_image_fragment};
+use crate::services::link_service::{self, LinkKind, PageFile, PageIndex};
+use crate::services::ssh_service::{execute_ssh_command, get_channel_session};
+use crate::services::transfer_service;
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::front_matter;
+use crate::utils::image_refs::replace_image_refs;
+use crate::utils::shell::quote as shq;
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+#[typeshare]
+#[derive(Deserialize, Debug, Clone)]
+pub struct ExportRequest {
+    /// 내보낼 섹션 (content 기준, 예: "posts" / "docs/guide"). 비어 있으면 사이트 전체
+    #[serde(default)]
+    pub section: Option<String>,
+    /// 만들 zip 파일의 로컬 경로
+    pub dest: String,
+    /// hidden 페이지 포함
+    #[serde(default)]
+    pub include_hidden: bool,
+    /// zip 루트에 manifest.json 추가
+    #[serde(default)]
+    pub manifest: bool,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ExportIssue {
+    /// zip 안의 페이지 경로
+    pub path: String,
+    pub message: String,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ExportReport {
+    pub dest: String,
+    pub pages: u32,
+    /// 페이지 옆으로 복사한 이미지/첨부 수
+    pub assets: u32,
+    pub issues: Vec<ExportIssue>,
+}
+
+#[derive(Serialize)]
+struct ManifestPage {
+    /// zip 안의 경로
+    path: String,
+    /// 서버의 content 상대경로
+    source: String,
+    hidden: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    /// 이 페이지 옆으로 복사한 파일 (zip 경로)
+    assets: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Manifest<'a> {
+    exported_at: String,
+    section: Option<&'a str>,
+    pages: Vec<ManifestPage>,
+}
+
+/// 내보낼 페이지 하나
+struct Page {
+    /// zip 경로 (= content 기준 경로, hidden은 `{hidden_path}/` 포함)
+    path: String,
+    /// 링크 해석용 content 상대경로 ("/posts/a.md")
+    rel: String,
+    hidden: bool,
+    content: String,
+}
+
+fn normalize_section(section: Option<&str>) -> Result<Option<&str>> {
+    let Some(s) = section.map(|s| s.trim().trim_matches('/')).filter(|s| !s.is_empty()) else { return Ok(None) };
+    if s.split('/').any(|c| c.is_empty() || c == "." || c == "..") {
+        bail!("Invalid section: {}", s);
+    }
+    Ok(Some(s))
+}
+
+/// 사이트 전체 페이지 목록 (링크 해석용, 본문 없이)
+fn list_pages(hugo: &HugoConfig) -> Result<PageIndex> {
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(
+        &mut channel,
+        &format!("cd {} || exit 1; find . -type f -name '*.md'", shq(&format!("{}/content", hugo.base_path))),
+    )?;
+    let files = output
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let (rel, hidden) = link_service::classify_rel(hugo, l);
+            PageFile { rel, hidden }
+        })
+        .collect();
+    Ok(PageIndex::new(files))
+}
+
+fn parent(path: &str) -> &str {
+    path.rfind('/').map(|i| &path[..i]).unwrap_or("")
+}
+
+/// 페이지 옆 첨부 디렉토리 (zip 경로)
+fn asset_dir(page_path: &str) -> String {
+    let name = page_path.rsplit('/').next().unwrap_or(page_path);
+    if name == "index.md" {
+        parent(page_path).to_string()
+    } else {
+        format!("{}.assets", page_path.strip_suffix(".md").unwrap_or(page_path))
+    }
+}
+
+fn join(dir: &str, name: &str) -> String {
+    if dir.is_empty() { name.to_string() } else { format!("{}/{}", dir, name) }
+}
+
+/// 디렉토리 안에서 겹치지 않는 파일 이름 (`a.png` → `a-2.png`)
+fn unique_name(dir: &str, name: &str, taken: &HashSet<String>) -> String {
+    if !taken.contains(&join(dir, name)) {
+        return name.to_string();
+    }
+    let (stem, ext) = match name.rfind('.') {
+        Some(i) if i > 0 => (&name[..i], &name[i..]),
+        _ => (name, ""),
+    };
+    (2..)
+        .map(|n| format!("{}-{}{}", stem, n, ext))
+        .find(|candidate| !taken.contains(&join(dir, candidate)))
+        .unwrap()
+}
+
+/// 마크다운 링크 대상으로 쓸 수 있도록 공백 등 인코딩
+fn encode_link(path: &str) -> String {
+    path.replace('%', "%25").replace(' ', "%20").replace('(', "%28").replace(')', "%29")
+}
+
+/// 내보낸 페이지로 가는 링크를 zip 안의 상대경로로 변경. ref/relref shortcode는 통째로 경로로 바꾼다.
+fn rewrite_links(page: &Page, content: &str, pages: &PageIndex, exported: &HashMap<String, String>) -> String {
+    let mut lines: Vec<String> = content.split('\n').map(str::to_string).collect();
+    let from_dir = parent(&page.path);
+    for (num, line) in link_service::body_lines(content) {
+        let mut links = link_service::find_links(line);
+        if links.is_empty() {
+            continue;
+        }
+        // 뒤에서부터 바꿔야 앞쪽 위치가 유지된다
+        links.sort_by_key(|l| std::cmp::Reverse(l.start));
+        let mut updated = line.to_string();
+        for link in links {
+            let Some(resolved) = link_service::resolve(&page.rel, &link, pages) else { continue };
+            if link_service::is_asset(&resolved.path) {
+                continue;
+            }
+            let Some(target) = exported.get(&link_service::page_key(&resolved.path)) else { continue };
+            let new_target = format!("{}{}", encode_link(&link_service::relative_path(from_dir, target)), resolved.fragment);
+            match link.kind {
+                LinkKind::Markdown => updated.replace_range(link.start..link.start + link.target.len(), &new_target),
+                LinkKind::Ref => {
+                    let open = updated[..link.start].rfind("{{");
+                    let close = updated[link.start..].find("}}").map(|i| link.start + i + 2);
+                    if let (Some(open), Some(close)) = (open, close) {
+                        updated.replace_range(open..close, &new_target);
+                    }
+                }
+            }
+        }
+        lines[num - 1] = updated;
+    }
+    lines.join("\n")
+}
+
+/// 섹션을 zip으로 내보내기
+pub fn export_section(request: &ExportRequest, app: &tauri::AppHandle) -> Result<ExportReport> {
+    let section = normalize_section(request.section.as_deref())?;
+    if request.dest.trim().is_empty() {
+        bail!("Destination is not set");
+    }
+    let hugo = get_hugo_config()?;
+    let syntax = file_service::site_ref_syntax();
+    let hidden_root = hugo.hidden_path.trim_matches('/');
+    let image_root = hugo.image_path.trim_matches('/');
+
+    let index = list_pages(&hugo)?;
+
+    // 1. content (+ hidden) 받기
+    let suffix = section.map(|s| format!("/{}", s)).unwrap_or_default();
+    let mut roots = vec![format!("content{}", suffix)];
+    if request.include_hidden && section.is_some() && !hidden_root.is_empty() {
+        roots.push(format!("content/{}{}", hidden_root, suffix));
+    }
+    let hidden_prefix = format!("{}/", hidden_root);
+    let mut files: BTreeMap<String, Vec<u8>> = transfer_service::download_generated(&roots, &hugo.base_path, app)?
+        .into_iter()
+        .filter_map(|(name, data)| Some((name.strip_prefix("content/")?.to_string(), data)))
+        // 사이트 전체를 받으면 hidden도 content 아래에 섞여 온다
+        .filter(|(name, _)| request.include_hidden || hidden_root.is_empty() || !name.starts_with(&hidden_prefix))
+        .collect();
+
+    let mut pages: Vec<Page> = Vec::new();
+    for (path, data) in &files {
+        if !path.ends_with(".md") {
+            continue;
+        }
+        let Ok(content) = String::from_utf8(data.clone()) else { continue };
+        let (rel, hidden) = link_service::classify_rel(&hugo, path);
+        pages.push(Page { path: path.clone(), rel, hidden, content });
+    }
+    if pages.is_empty() {
+        bail!("No pages to export in {}", section.unwrap_or("content"));
+    }
+
+    // 페이지 키 → zip 경로 (공개 페이지 우선)
+    pages.sort_by_key(|p| p.hidden);
+    let mut exported: HashMap<String, String> = HashMap::new();
+    for page in &pages {
+        exported.entry(link_service::page_key(&page.rel)).or_insert_with(|| page.path.clone());
+    }
+
+    // 2. 참조된 이미지 받기 (image_path 기준 경로)
+    let mut wanted: HashSet<String> = HashSet::new();
+    for page in &pages {
+        let (local_refs, _) = file_service::parse_all_image_refs(&page.content, &syntax);
+        for r in local_refs {
+            let (ref_path, _) = split_image_fragment(&r);
+            if ref_path.starts_with('/') {
+                wanted.insert(ref_path.trim_start_matches('/').to_string());
+            }
+        }
+    }
+    let mut image_paths: Vec<String> = wanted.iter().map(|p| join(image_root, p)).collect();
+    image_paths.sort();
+    let image_prefix = if image_root.is_empty() { String::new() } else { format!("{}/", image_root) };
+    let images: HashMap<String, Vec<u8>> = transfer_service::download_generated(&image_paths, &hugo.base_path, app)?
+        .into_iter()
+        .filter_map(|(name, data)| Some((name.strip_prefix(&image_prefix)?.to_string(), data)))
+        .collect();
+
+    // 3. 이미지 복사 + 참조/링크 변경
+    let mut taken: HashSet<String> = files.keys().cloned().collect();
+    let mut issues: Vec<ExportIssue> = Vec::new();
+    let mut manifest_pages: Vec<ManifestPage> = Vec::new();
+    let mut asset_count = 0u32;
+    for page in &pages {
+        let dir = asset_dir(&page.path);
+        let from_dir = parent(&page.path);
+        // image_path 기준 경로 → zip 경로 (같은 이미지는 한 번만 복사)
+        let mut copied: HashMap<String, String> = HashMap::new();
+        let mut missing: Vec<String> = Vec::new();
+        let content = replace_image_refs(&page.content, &syntax, |r| {
+            let (ref_path, fragment) = split_image_fragment(&r.path);
+            if !ref_path.starts_with('/') || link_service::is_external(ref_path) {
+                return None;
+            }
+            let key = ref_path.trim_start_matches('/');
+            let zip_path = match copied.get(key) {
+                Some(p) => p.clone(),
+                None => {
+                    let Some(data) = images.get(key) else {
+                        if !missing.iter().any(|m| m == ref_path) {
+                            missing.push(ref_path.to_string());
+                        }
+                        return None;
+                    };
+                    let file_name = key.rsplit('/').next().unwrap_or(key);
+                    let zip_path = join(&dir, &unique_name(&dir, file_name, &taken));
+                    taken.insert(zip_path.clone());
+                    files.insert(zip_path.clone(), data.clone());
+                    copied.insert(key.to_string(), zip_path.clone());
+                    zip_path
+                }
+            };
+            Some(format!("{}{}", encode_link(&link_service::relative_path(from_dir, &zip_path)), fragment))
+        });
+        for m in missing {
+            issues.push(ExportIssue { path: page.path.clone(), message: format!("Image not found on server: {}", m) });
+        }
+
+        let content = rewrite_links(page, &content, &index, &exported);
+        files.insert(page.path.clone(), content.into_bytes());
+        asset_count += copied.len() as u32;
+
+        if request.manifest {
+            let mut assets: Vec<String> = copied.into_values().collect();
+            assets.sort();
+            manifest_pages.push(ManifestPage {
+                path: page.path.clone(),
+                source: page.rel.clone(),
+                hidden: page.hidden,
+                title: front_matter::get(&page.content, "title").map(|t| front_matter::unquote(&t).to_string()),
+                assets,
+            });
+        }
+    }
+
+    if request.manifest {
+        manifest_pages.sort_by(|a, b| a.path.cmp(&b.path));
+        let manifest = Manifest {
+            exported_at: front_matter::format_rfc3339(front_matter::now_unix()),
+            section,
+            pages: manifest_pages,
+        };
+        files.insert(MANIFEST_FILE.to_string(), serde_json::to_vec_pretty(&manifest)?);
+    }
+
+    // 4. zip 쓰기
+    let file = File::create(&request.dest).with_context(|| format!("Failed to create {}", request.dest))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for (name, data) in &files {
+        zip.start_file(name.as_str(), options)?;
+        zip.write_all(data)?;
+    }
+    zip.finish()?;
+
+    Ok(ExportReport {
+        dest: request.dest.clone(),
+        pages: pages.len() as u32,
+        assets: asset_count,
+        issues,
+    })
+}
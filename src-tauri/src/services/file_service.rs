@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 use serde::{Deserialize, Serialize};
 use ssh2::Sftp;
 use std::io::prelude::*;
@@ -10,10 +10,12 @@ use rand::Rng;
 use typeshare::typeshare;
 
 use crate::services::ssh_service::{get_sftp_session, get_channel_session, execute_ssh_command, execute_ssh_command_checked, SftpHandle};
-use crate::services::config_service::get_hugo_config;
-use crate::services::plugin_service;
-use crate::types::config::cms_config::HugoConfig;
+use crate::services::config_service::{get_app_config, get_hugo_config};
+use crate::services::{history_service, image_service, plugin_service, search_index_service, trash_service};
+use crate::types::config::cms_config::{HugoConfig, ResourceLocation};
 use crate::types::plugin::HookEvent;
+use crate::utils::front_matter::{self, FmValue};
+use crate::utils::image_refs::{self, RefSyntax};
 
 // ============================================================
 // 고수준 Hugo 파일 작업
@@ -28,34 +30,20 @@ fn sftp_and_config() -> Result<(SftpHandle, HugoConfig)> {
     Ok((sftp, config))
 }
 
-/// content/hidden 양쪽 경로에서 작업 시도. 하나라도 성공하면 Ok, 모두 실패하면 마지막 에러 반환.
-fn try_both<T, F>(items: impl IntoIterator<Item = T>, mut op: F) -> Result<()>
-where
-    F: FnMut(T) -> Result<()>,
-{
-    let mut last_err: Option<anyhow::Error> = None;
-    let mut ok = false;
-    for item in items {
-        match op(item) {
-            Ok(_) => { ok = true; }
-            Err(e) => { last_err = Some(e); }
-        }
-    }
-    if ok {
-        Ok(())
-    } else {
-        bail!("{}", last_err.unwrap_or_else(|| anyhow::anyhow!("operation failed")))
-    }
+/// leaf bundle 디렉토리: "/posts/x/index.md" → Some("/posts/x")
+pub fn bundle_dir(path: &str) -> Option<&str> {
+    path.strip_suffix("/index.md").filter(|d| !d.is_empty())
 }
 
 /// content/hidden 양쪽을 확인하여 중복되지 않는 경로를 반환.
 /// 이미 존재하면 _1, _2, ... suffix를 붙인다.
+/// `_index.md`(branch bundle)와 `index.md`(leaf bundle)는 디렉토리 이름에 suffix를 붙인다.
 fn find_unique_path(sftp: &Sftp, hugo_config: &HugoConfig, file_path: &str) -> String {
-    let is_dir = file_path.ends_with("/_index.md");
+    let dir_index = ["/_index.md", "/index.md"].into_iter().find(|i| file_path.ends_with(i));
 
-    if is_dir {
+    if let Some(index) = dir_index {
         // e.g. "/new_folder/_index.md" → 디렉토리 "/new_folder" 중복 확인
-        let dir_part = &file_path[..file_path.len() - "/_index.md".len()];
+        let dir_part = &file_path[..file_path.len() - index.len()];
         let (parent, name) = match dir_part.rfind('/') {
             Some(pos) => (&dir_part[..=pos], &dir_part[pos + 1..]),
             None => ("", dir_part),
@@ -68,7 +56,7 @@ fn find_unique_path(sftp: &Sftp, hugo_config: &HugoConfig, file_path: &str) -> S
         for n in 1..1000 {
             let candidate = format!("{}{}_{}", parent, name, n);
             if !path_exists(sftp, hugo_config, &candidate) {
-                return format!("{}/_index.md", candidate);
+                return format!("{}{}", candidate, index);
             }
         }
         // fallback (사실상 도달 불가)
@@ -99,7 +87,7 @@ fn find_unique_path(sftp: &Sftp, hugo_config: &HugoConfig, file_path: &str) -> S
 }
 
 /// content 경로와 hidden 경로 양쪽 모두 존재 여부 확인
-fn path_exists(sftp: &Sftp, hugo_config: &HugoConfig, rel_path: &str) -> bool {
+pub fn path_exists(sftp: &Sftp, hugo_config: &HugoConfig, rel_path: &str) -> bool {
     sftp.stat(Path::new(&hugo_config.content_abs(rel_path))).is_ok()
         || sftp.stat(Path::new(&hugo_config.hidden_abs(rel_path))).is_ok()
 }
@@ -110,7 +98,10 @@ fn parse_find_output(output: &str, prefix: &str, root_name: &str, is_hidden: boo
     let mut root = FileSystemNode {
         name: root_name.to_string(),
         type_: NodeType::Directory,
+        bundle: false,
         is_hidden,
+        publish_state: PublishState::default(),
+        meta: None,
         children: IndexMap::new(),
     };
 
@@ -146,7 +137,10 @@ fn parse_find_output(output: &str, prefix: &str, root_name: &str, is_hidden: boo
                 current.children.entry(name.clone()).or_insert_with(|| FileSystemNode {
                     name: name.clone(),
                     type_: node_type,
+                    bundle: false,
                     is_hidden,
+                    publish_state: PublishState::default(),
+                    meta: None,
                     children: IndexMap::new(),
                 });
             } else {
@@ -154,7 +148,10 @@ fn parse_find_output(output: &str, prefix: &str, root_name: &str, is_hidden: boo
                 let entry = current.children.entry(name.clone()).or_insert_with(|| FileSystemNode {
                     name: name.clone(),
                     type_: NodeType::Directory,
+                    bundle: false,
                     is_hidden,
+                    publish_state: PublishState::default(),
+                    meta: None,
                     children: IndexMap::new(),
                 });
                 current = entry;
@@ -165,12 +162,91 @@ fn parse_find_output(output: &str, prefix: &str, root_name: &str, is_hidden: boo
     root
 }
 
-/// SSH find + grep을 하나의 명령으로 실행하여 파일 목록 + weight + date를 동시 수집.
-/// 반환: (find 출력, weight map, date map)
-fn fetch_files_and_weights(base_path: &str, paths: &[String]) -> (String, HashMap<String, i32>, HashMap<String, String>) {
+/// 트리 구성 시 수집하는 front matter 키 (소문자 — awk에서 tolower로 매칭)
+const TREE_FM_KEYS: &[&str] = &["weight", "date", "draft", "publishdate", "expirydate", "title", "tags"];
+
+/// 본문 단어 수를 싣는 가상 키 (front matter 키와 겹치지 않도록 '@' 접두)
+const WORD_COUNT_KEY: &str = "@words";
+
+/// 파일별 front matter 값: 상대경로("/posts/a.md") → (소문자 키 → 값)
+#[derive(Default)]
+struct FrontMatterIndex(HashMap<String, HashMap<String, String>>);
+
+impl FrontMatterIndex {
+    fn get(&self, rel: &str, key: &str) -> Option<&str> {
+        self.0.get(rel)
+            .and_then(|m| m.get(key))
+            .map(|s| s.as_str())
+            .filter(|s| !s.is_empty())
+    }
+
+    /// 트리 노드에 실을 페이지 메타데이터. 수집된 값이 없는 경로(.md가 아니거나 _index.md 없는 폴더)는 None.
+    fn page_meta(&self, rel: &str) -> Option<PageMeta> {
+        let fields = self.0.get(rel)?;
+        let tags = fields.get("tags")
+            .map(|raw| {
+                let field = format!("tags: {}", raw);
+                front_matter::parse_list_lines(&field.lines().collect::<Vec<_>>())
+            })
+            .unwrap_or_default();
+        Some(PageMeta {
+            title: self.get(rel, "title").map(|s| s.to_string()),
+            date: self.get(rel, "date").map(|s| s.to_string()),
+            tags,
+            draft: self.get(rel, "draft").is_some_and(|d| d.eq_ignore_ascii_case("true")),
+            word_count: self.get(rel, WORD_COUNT_KEY).and_then(|w| w.parse().ok()).unwrap_or(0),
+        })
+    }
+}
+
+/// 마커로 구분된 출력에서 각 마커 뒤 섹션을 잘라낸다. 없는 마커는 빈 문자열.
+fn split_marked_sections<'a>(output: &'a str, markers: &[&str]) -> Vec<&'a str> {
+    let positions: Vec<Option<usize>> = markers.iter().map(|m| output.find(m)).collect();
+    markers
+        .iter()
+        .zip(&positions)
+        .map(|(marker, pos)| {
+            let Some(start) = pos.map(|p| p + marker.len()) else { return "" };
+            // 다음으로 등장하는 마커 직전까지
+            let end = positions
+                .iter()
+                .flatten()
+                .filter(|&&p| p >= start)
+                .min()
+                .copied()
+                .unwrap_or(output.len());
+            &output[start..end]
+        })
+        .collect()
+}
+
+/// 파일당 front matter 키 줄(+ YAML 블록 리스트의 이어지는 줄)과 본문 단어 수를 출력하는 awk 프로그램.
+///
+/// front matter 값은 front matter 블록(첫 줄의 --- 또는 +++ ~ 닫는 구분자) 안에서만
+/// 추출한다 — 단순 grep은 본문의 "date: ..." 같은 줄을 오인하고, 파일당
+/// 여러 번 매칭될 수 있다. 단어 수는 front matter를 제외한 본문의 필드 수(NF) 합이며
+/// 파일이 바뀔 때와 END에서 `경로:0:@words=N` 형태로 출력한다.
+fn front_matter_awk_program() -> String {
+    format!(
+        r#"FNR==1{{ if(f!="") print f":0:{w}="wc; f=FILENAME; wc=0; fm=0; cont=0; if(/^(---|\+\+\+)/){{fm=1; next}} }}
+fm&&/^(---|\+\+\+)/{{fm=0; next}}
+fm&&tolower($0)~/^({keys})[[:space:]]*[:=]/{{print FILENAME":"FNR":"$0; cont=1; next}}
+fm&&cont&&/^([[:space:]]|- )/{{print FILENAME":"FNR":"$0; next}}
+fm{{cont=0; next}}
+{{wc+=NF}}
+END{{ if(f!="") print f":0:{w}="wc }}"#,
+        w = WORD_COUNT_KEY,
+        keys = TREE_FM_KEYS.join("|"),
+    )
+}
+
+/// SSH find + awk를 하나의 명령으로 실행하여 파일 목록, front matter(weight, date, draft, title, tags 등),
+/// 본문 단어 수를 동시 수집.
+/// 반환: (find 출력, front matter index)
+fn fetch_files_and_weights(base_path: &str, paths: &[String]) -> (String, FrontMatterIndex) {
     let mut channel = match get_channel_session() {
         Ok(ch) => ch,
-        Err(_) => return (String::new(), HashMap::new(), HashMap::new()),
+        Err(_) => return (String::new(), FrontMatterIndex::default()),
     };
 
     // find 대상 경로 조합 (경로에 공백/특수문자가 있어도 깨지지 않도록 quoting)
@@ -180,64 +256,35 @@ fn fetch_files_and_weights(base_path: &str, paths: &[String]) -> (String, HashMa
     let find_paths = find_targets.join(" ");
     let content_dir = crate::utils::shell::quote(&format!("{}/content", base_path));
 
-    // weight/date는 front matter 블록(첫 줄의 --- 또는 +++ ~ 닫는 구분자) 안에서만
-    // 추출한다 — 단순 grep은 본문의 "date: ..." 같은 줄을 오인하고, 파일당
-    // 여러 번 매칭될 수 있다. nextfile로 파일당 front matter 1블록만 읽는다.
-    let fm_extract = |key: &str| {
-        format!(
-            "find {} -name '*.md' -exec awk 'FNR==1{{fm=0}} FNR==1&&/^(---|\\+\\+\\+)/{{fm=1;next}} fm&&/^(---|\\+\\+\\+)/{{fm=0;nextfile}} fm&&/^{}[[:space:]]*[:=]/{{print FILENAME\":\"FNR\":\"$0}}' {{}} + 2>/dev/null",
-            content_dir, key
-        )
-    };
+    let fm_extract = format!(
+        "find {} -name '*.md' -exec awk {} {{}} + 2>/dev/null",
+        content_dir,
+        crate::utils::shell::quote(&front_matter_awk_program()),
+    );
     let cmd = format!(
-        "echo '---FILES---'; find {} -maxdepth {} -printf '%y %p\\n' 2>/dev/null; echo '---WEIGHTS---'; {}; echo '---DATES---'; {}; true",
-        find_paths, FILE_TREE_MAX_DEPTH, fm_extract("weight"), fm_extract("date")
+        "echo '---FILES---'; find {} -maxdepth {} -printf '%y %p\\n' 2>/dev/null; echo '---FRONTMATTER---'; {}; true",
+        find_paths, FILE_TREE_MAX_DEPTH, fm_extract
     );
 
     let output = match execute_ssh_command(&mut channel, &cmd) {
         Ok(o) => o,
-        Err(_) => return (String::new(), HashMap::new(), HashMap::new()),
+        Err(_) => return (String::new(), FrontMatterIndex::default()),
     };
 
-    // ---FILES--- / ---WEIGHTS--- / ---DATES--- 구분자로 분리
-    let files_section;
-    let weights_section;
-    let dates_section;
-
-    if let Some(files_start) = output.find("---FILES---") {
-        let after_files = &output[files_start + "---FILES---".len()..];
-        if let Some(weights_start) = after_files.find("---WEIGHTS---") {
-            files_section = after_files[..weights_start].to_string();
-            let after_weights = &after_files[weights_start + "---WEIGHTS---".len()..];
-            if let Some(dates_start) = after_weights.find("---DATES---") {
-                weights_section = after_weights[..dates_start].to_string();
-                dates_section = after_weights[dates_start + "---DATES---".len()..].to_string();
-            } else {
-                weights_section = after_weights.to_string();
-                dates_section = String::new();
-            }
-        } else {
-            files_section = after_files.to_string();
-            weights_section = String::new();
-            dates_section = String::new();
-        }
-    } else {
-        files_section = String::new();
-        weights_section = String::new();
-        dates_section = String::new();
-    }
-
-    // weight / date 파싱
+    let sections = split_marked_sections(&output, &["---FILES---", "---FRONTMATTER---"]);
     let prefix = format!("{}/content", base_path);
-    let weights = parse_weight_output(&weights_section, &prefix);
-    let dates = parse_date_output(&dates_section, &prefix);
+    let front_matter = parse_front_matter_output(sections[1], &prefix);
 
-    (files_section, weights, dates)
+    (sections[0].to_string(), front_matter)
 }
 
-/// grep 출력에서 weight 값 파싱
-fn parse_weight_output(output: &str, prefix: &str) -> HashMap<String, i32> {
-    let mut map = HashMap::new();
+/// awk 출력(`경로:줄번호:key: value`)에서 front matter 값 파싱.
+/// 같은 파일의 같은 키는 처음 나온 값만 사용한다.
+/// 키 없이 들여쓰기/`- `로 시작하는 줄은 직전 키 값에 줄바꿈으로 이어 붙인다 (YAML 블록 리스트).
+fn parse_front_matter_output(output: &str, prefix: &str) -> FrontMatterIndex {
+    let mut index = FrontMatterIndex::default();
+    // 이어 붙일 대상: 직전에 새로 기록한 (파일, 키)
+    let mut last: Option<(String, String)> = None;
     for line in output.lines() {
         let Some((path, rest)) = line.split_once(':') else { continue };
         let Some((_line_num, text)) = rest.split_once(':') else { continue };
@@ -247,92 +294,136 @@ fn parse_weight_output(output: &str, prefix: &str) -> HashMap<String, i32> {
             None => continue,
         };
 
-        let trimmed = text.trim();
-        let after_key = trimmed.strip_prefix("weight").unwrap_or(trimmed).trim();
-        let after_sep = after_key.trim_start_matches(|c: char| c == '=' || c == ':').trim();
-        if let Ok(w) = after_sep.parse::<i32>() {
-            map.entry(rel).or_insert(w);
+        if text.starts_with(char::is_whitespace) || text.starts_with('-') {
+            if let Some((last_rel, last_key)) = &last {
+                if *last_rel == rel {
+                    if let Some(v) = index.0.get_mut(&rel).and_then(|m| m.get_mut(last_key)) {
+                        v.push('\n');
+                        v.push_str(text);
+                    }
+                }
+            }
+            continue;
         }
-    }
-    map
-}
 
-/// grep 출력에서 date 값 파싱
-fn parse_date_output(output: &str, prefix: &str) -> HashMap<String, String> {
-    let mut map = HashMap::new();
-    for line in output.lines() {
-        let Some((path, rest)) = line.split_once(':') else { continue };
-        let Some((_line_num, text)) = rest.split_once(':') else { continue };
-
-        let rel = match path.strip_prefix(prefix) {
-            Some(r) => r.to_string(),
-            None => continue,
-        };
-
-        let trimmed = text.trim();
-        let after_key = trimmed.strip_prefix("date").unwrap_or(trimmed).trim();
-        let after_sep = after_key.trim_start_matches(|c: char| c == '=' || c == ':').trim();
-        let date_str = after_sep.trim_matches('"').trim_matches('\'').trim();
-        if !date_str.is_empty() {
-            map.entry(rel).or_insert_with(|| date_str.to_string());
+        let Some(sep) = text.find([':', '=']) else { continue };
+        let key = text[..sep].trim().to_lowercase();
+        let value = front_matter::unquote(&text[sep + 1..]).trim();
+        let fields = index.0.entry(rel.clone()).or_default();
+        if fields.contains_key(&key) {
+            last = None;
+            continue;
         }
+        fields.insert(key.clone(), value.to_string());
+        last = Some((rel, key));
     }
-    map
+    index
 }
 
 /// 트리의 children을 weight → date(최신순) → name 순으로 재귀 정렬.
 /// current_path: 이 노드까지의 상대 경로 (예: "/blog/post")
-fn sort_tree(node: &mut FileSystemNode, current_path: &str, weights: &HashMap<String, i32>, dates: &HashMap<String, String>) {
+fn sort_tree(node: &mut FileSystemNode, current_path: &str, front_matter: &FrontMatterIndex) {
     for (name, child) in node.children.iter_mut() {
         if child.type_ == NodeType::Directory {
             let child_path = format!("{}/{}", current_path, name);
-            sort_tree(child, &child_path, weights, dates);
+            sort_tree(child, &child_path, front_matter);
         }
     }
 
     node.children.sort_by(|a_name, _a_node, b_name, _b_node| {
-        let w_a = weight_for(current_path, a_name, _a_node, weights);
-        let w_b = weight_for(current_path, b_name, _b_node, weights);
+        let w_a = weight_for(current_path, a_name, _a_node, front_matter);
+        let w_b = weight_for(current_path, b_name, _b_node, front_matter);
         w_a.cmp(&w_b)
             .then_with(|| {
-                let d_a = date_for(current_path, a_name, _a_node, dates);
-                let d_b = date_for(current_path, b_name, _b_node, dates);
+                let d_a = date_for(current_path, a_name, _a_node, front_matter);
+                let d_b = date_for(current_path, b_name, _b_node, front_matter);
                 d_b.cmp(&d_a) // 최신순 (내림차순)
             })
             .then_with(|| a_name.cmp(b_name))
     });
 }
 
-/// 노드의 정렬 weight를 조회.
-fn weight_for(parent: &str, name: &str, node: &FileSystemNode, weights: &HashMap<String, i32>) -> i32 {
-    let key = match node.type_ {
+/// 노드의 front matter 조회 키: 디렉토리는 _index.md (leaf bundle은 index.md), 파일은 자기 자신
+fn fm_key(parent: &str, name: &str, node: &FileSystemNode) -> String {
+    match node.type_ {
+        NodeType::Directory if node.bundle => format!("{}/{}/index.md", parent, name),
         NodeType::Directory => format!("{}/{}/_index.md", parent, name),
         NodeType::File => format!("{}/{}", parent, name),
-    };
-    *weights.get(&key).unwrap_or(&i32::MAX)
+    }
+}
+
+/// 노드의 정렬 weight를 조회.
+fn weight_for(parent: &str, name: &str, node: &FileSystemNode, front_matter: &FrontMatterIndex) -> i32 {
+    front_matter.get(&fm_key(parent, name, node), "weight")
+        .and_then(|w| w.parse().ok())
+        .unwrap_or(i32::MAX)
 }
 
 /// 노드의 정렬 date를 조회. ISO 문자열이므로 사전순 비교 가능.
-fn date_for(parent: &str, name: &str, node: &FileSystemNode, dates: &HashMap<String, String>) -> String {
-    let key = match node.type_ {
-        NodeType::Directory => format!("{}/{}/_index.md", parent, name),
-        NodeType::File => format!("{}/{}", parent, name),
-    };
-    dates.get(&key).cloned().unwrap_or_default()
+fn date_for(parent: &str, name: &str, node: &FileSystemNode, front_matter: &FrontMatterIndex) -> String {
+    front_matter.get(&fm_key(parent, name, node), "date")
+        .map(|d| d.to_string())
+        .unwrap_or_default()
+}
+
+/// front matter 값으로 발행 상태 계산 (Hugo 규칙: draft → expiryDate → publishDate(없으면 date))
+fn compute_publish_state(
+    draft: Option<&str>,
+    publish_date: Option<&str>,
+    date: Option<&str>,
+    expiry_date: Option<&str>,
+    now: i64,
+) -> PublishState {
+    if draft.is_some_and(|d| d.eq_ignore_ascii_case("true")) {
+        return PublishState::Draft;
+    }
+    if expiry_date.and_then(front_matter::parse_date).is_some_and(|t| t <= now) {
+        return PublishState::Expired;
+    }
+    if publish_date.or(date).and_then(front_matter::parse_date).is_some_and(|t| t > now) {
+        return PublishState::Scheduled;
+    }
+    PublishState::Published
 }
 
-/// 파일 트리 구성: SSH find 1회 + grep 1회로 섹션별 트리 반환 (weight 정렬 + hidden 병합)
+/// 트리 전체에 leaf bundle 표시 + 발행 상태 + 페이지 메타데이터를 재귀 적용. sort_tree보다 먼저 호출한다.
+/// current_path: front matter index 조회용 상대 경로 (hidden 트리는 "/{hidden_path}/{section}")
+fn apply_front_matter(node: &mut FileSystemNode, current_path: &str, front_matter: &FrontMatterIndex, now: i64) {
+    for (name, child) in node.children.iter_mut() {
+        // awk는 모든 .md 파일에 단어 수를 남기므로 index.md가 index에 있으면 leaf bundle
+        // (트리 깊이 제한/지연 로딩으로 index.md 노드가 없어도 판별된다)
+        if child.type_ == NodeType::Directory {
+            child.bundle = front_matter.0.contains_key(&format!("{}/{}/index.md", current_path, name));
+        }
+        let key = fm_key(current_path, name, child);
+        child.publish_state = compute_publish_state(
+            front_matter.get(&key, "draft"),
+            front_matter.get(&key, "publishdate"),
+            front_matter.get(&key, "date"),
+            front_matter.get(&key, "expirydate"),
+            now,
+        );
+        child.meta = front_matter.page_meta(&key);
+        if child.type_ == NodeType::Directory {
+            let child_path = format!("{}/{}", current_path, name);
+            apply_front_matter(child, &child_path, front_matter, now);
+        }
+    }
+}
+
+/// 파일 트리 구성: SSH 1회로 파일 목록 + front matter 수집 후 섹션별 트리 반환 (weight 정렬 + hidden 병합)
 pub fn build_file_tree() -> Result<Vec<FileSystemNode>> {
     let hugo_config = get_hugo_config()?;
 
-    // 모든 섹션 + hidden 섹션의 파일 목록 + weight를 SSH 1회로 수집
+    // 모든 섹션 + hidden 섹션의 파일 목록 + front matter를 SSH 1회로 수집
     let mut all_paths: Vec<String> = hugo_config.content_paths.clone();
     for section in &hugo_config.content_paths {
         all_paths.push(format!("{}/{}", hugo_config.hidden_path, section));
     }
-    let (find_output, weights, dates) = fetch_files_and_weights(&hugo_config.base_path, &all_paths);
+    let (find_output, front_matter) = fetch_files_and_weights(&hugo_config.base_path, &all_paths);
 
     let content_prefix = format!("{}/content", hugo_config.base_path);
+    let now = front_matter::now_unix();
     let mut sections = Vec::new();
 
     for section in &hugo_config.content_paths {
@@ -340,14 +431,17 @@ pub fn build_file_tree() -> Result<Vec<FileSystemNode>> {
         let section_abs = format!("{}/{}", content_prefix, section);
         let mut tree = parse_find_output(&find_output, &section_abs, section, false);
 
-        // weight 기준 정렬
+        // weight 기준 정렬 + 발행 상태/메타데이터
         let section_prefix = format!("/{}", section);
-        sort_tree(&mut tree, &section_prefix, &weights, &dates);
+        apply_front_matter(&mut tree, &section_prefix, &front_matter, now);
+        sort_tree(&mut tree, &section_prefix, &front_matter);
 
         // hidden 트리 파싱 + 병합
         let hidden_abs = format!("{}/{}/{}", content_prefix, hugo_config.hidden_path, section);
-        let hidden_tree = parse_find_output(&find_output, &hidden_abs, section, true);
+        let mut hidden_tree = parse_find_output(&find_output, &hidden_abs, section, true);
         if !hidden_tree.children.is_empty() {
+            let hidden_prefix = format!("/{}/{}", hugo_config.hidden_path, section);
+            apply_front_matter(&mut hidden_tree, &hidden_prefix, &front_matter, now);
             merge_tree(&mut tree, hidden_tree);
         }
 
@@ -357,21 +451,124 @@ pub fn build_file_tree() -> Result<Vec<FileSystemNode>> {
     Ok(sections)
 }
 
+/// 디렉토리 한 단계 + 직계 자식의 front matter 수집 (지연 로딩용).
+/// dirs: content 기준 상대 경로 목록 (예: "posts/sub", "{hidden}/posts/sub").
+/// 자식 .md 파일과 하위 폴더의 _index.md / index.md만 읽는다.
+fn fetch_dir_entries(base_path: &str, dirs: &[String]) -> Result<(String, FrontMatterIndex)> {
+    let mut channel = get_channel_session()?;
+
+    let abs_dirs: Vec<String> = dirs.iter()
+        .map(|d| format!("{}/content/{}", base_path, d.trim_matches('/')))
+        .collect();
+    let find_paths = abs_dirs.iter()
+        .map(|d| crate::utils::shell::quote(d))
+        .collect::<Vec<_>>()
+        .join(" ");
+    // glob은 매칭이 없으면 패턴이 그대로 넘어가 awk가 첫 파일에서 중단되므로 find로 목록을 만든다.
+    // 직계 .md + 하위 폴더의 _index.md / index.md (leaf bundle 판별용)
+    let awk = crate::utils::shell::quote(&front_matter_awk_program());
+    let cmd = format!(
+        "echo '---FILES---'; find {0} -mindepth 1 -maxdepth 1 -printf '%y %p\\n' 2>/dev/null; echo '---FRONTMATTER---'; \
+         find {0} -mindepth 1 -maxdepth 1 -type f -name '*.md' -exec awk {1} {{}} + 2>/dev/null; \
+         find {0} -mindepth 2 -maxdepth 2 -type f \\( -name '_index.md' -o -name 'index.md' \\) -exec awk {1} {{}} + 2>/dev/null; true",
+        find_paths, awk,
+    );
+    let output = execute_ssh_command(&mut channel, &cmd)?;
+
+    let sections = split_marked_sections(&output, &["---FILES---", "---FRONTMATTER---"]);
+    let prefix = format!("{}/content", base_path);
+    Ok((sections[0].to_string(), parse_front_matter_output(sections[1], &prefix)))
+}
+
+/// 지연 로딩 트리의 한 페이지
+#[typeshare]
+#[derive(Clone, Debug, Serialize)]
+pub struct DirPage {
+    /// 요청한 디렉토리 상대 경로 (예: "/posts/sub")
+    pub path: String,
+    /// 정렬 + hidden 병합된 직계 자식 (하위 폴더의 children은 비어 있음)
+    pub children: Vec<FileSystemNode>,
+    /// 병합 후 전체 자식 수
+    pub total: u32,
+    pub offset: u32,
+    pub has_more: bool,
+}
+
+/// 지연 로딩 기본 페이지 크기
+const DIR_PAGE_SIZE: usize = 200;
+
+/// 디렉토리 하나의 직계 자식을 페이지 단위로 반환 (깊이 제한 없음).
+/// build_file_tree와 같은 규칙으로 정렬하고 hidden 쪽 항목을 merge_tree로 병합한다.
+pub fn list_dir_children(dir_path: &str, offset: usize, limit: Option<usize>) -> Result<DirPage> {
+    let hugo_config = get_hugo_config()?;
+    let rel = format!("/{}", dir_path.trim_matches('/'));
+    let name = rel.rsplit('/').next().unwrap_or_default().to_string();
+    let hidden_rel = format!("/{}{}", hugo_config.hidden_path, rel);
+
+    let (find_output, front_matter) = fetch_dir_entries(
+        &hugo_config.base_path,
+        &[rel.clone(), hidden_rel.clone()],
+    )?;
+
+    let content_prefix = format!("{}/content", hugo_config.base_path);
+    let now = front_matter::now_unix();
+
+    let mut node = parse_find_output(&find_output, &format!("{}{}", content_prefix, rel), &name, false);
+    apply_front_matter(&mut node, &rel, &front_matter, now);
+    sort_tree(&mut node, &rel, &front_matter);
+
+    let mut hidden_node = parse_find_output(&find_output, &format!("{}{}", content_prefix, hidden_rel), &name, true);
+    if !hidden_node.children.is_empty() {
+        apply_front_matter(&mut hidden_node, &hidden_rel, &front_matter, now);
+        merge_tree(&mut node, hidden_node);
+    }
+
+    let total = node.children.len();
+    let limit = limit.filter(|l| *l > 0).unwrap_or(DIR_PAGE_SIZE);
+    let children: Vec<FileSystemNode> = node.children
+        .into_values()
+        .skip(offset)
+        .take(limit)
+        .collect();
+    let has_more = offset + children.len() < total;
+
+    Ok(DirPage {
+        path: rel,
+        children,
+        total: total as u32,
+        offset: offset as u32,
+        has_more,
+    })
+}
+
 /// 파일 내용 읽기 (relativeFilePath 기반)
 pub fn read_content(file_path: &str) -> Result<String> {
+    read_content_versioned(file_path).map(|v| v.content)
+}
+
+/// 파일 내용 + 버전 읽기. 버전은 저장 시 `expected_version`으로 돌려준다.
+pub fn read_content_versioned(file_path: &str) -> Result<VersionedContent> {
     let (sftp, hugo_config) = sftp_and_config()?;
     let content_path = hugo_config.content_abs(file_path);
     let hidden_path = hugo_config.hidden_abs(file_path);
 
-    get_file(&sftp, Path::new(&content_path))
-        .or_else(|_| get_file(&sftp, Path::new(&hidden_path)))
+    let content = get_file(&sftp, Path::new(&content_path))
+        .or_else(|_| get_file(&sftp, Path::new(&hidden_path)))?;
+    Ok(VersionedContent { version: content_version(&content), content })
+}
+
+/// 내용 기반 버전 (sha256 앞 16자). mtime은 초 단위라 같은 초 안의 두 저장을 구분하지 못한다.
+pub fn content_version(data: &str) -> String {
+    use sha2::{Sha256, Digest};
+    let hash = format!("{:x}", Sha256::digest(data.as_bytes()));
+    hash[..16].to_string()
 }
 
 /// 파일 내용 저장 (relativeFilePath 기반)
 /// manual=true: 수동 저장 → 이미지 sync + hooks 실행
 /// manual=false: 자동 저장 → 순수 저장만
-/// 반환값: sync 성공 여부 (true=전부 성공, false=저장은 됐지만 sync 실패)
-pub fn write_content(file_path: &str, data: &str, manual: bool) -> Result<bool> {
+/// expected_version: Some이면 원격 파일이 그 버전일 때만 저장, 다르면 SaveConflict 에러
+pub fn write_content(file_path: &str, data: &str, manual: bool, expected_version: Option<&str>) -> Result<SaveOutcome> {
     let (sftp, hugo_config) = sftp_and_config()?;
     let content_path = hugo_config.content_abs(file_path);
     let hidden_path = hugo_config.hidden_abs(file_path);
@@ -382,9 +579,37 @@ pub fn write_content(file_path: &str, data: &str, manual: bool) -> Result<bool>
     } else {
         content_path
     };
+
+    // 낙관적 동시성 검사: 읽은 뒤 다른 사람/플러그인이 고쳤으면 덮어쓰지 않는다.
+    // SFTP에는 compare-and-swap이 없으므로 검사~저장 사이의 짧은 틈은 남는다.
+    // 수동 저장이면 덮어쓰일 내용을 히스토리에 남기기 위해서도 읽는다
+    let remote_content = if expected_version.is_some() || manual {
+        get_file(&sftp, Path::new(&save_path)).ok()
+    } else {
+        None
+    };
+    if let Some(expected) = expected_version {
+        let remote_version = remote_content.as_deref().map(content_version);
+        if remote_version.as_deref() != Some(expected) {
+            return Err(SaveConflict {
+                path: file_path.to_string(),
+                remote_content,
+                remote_version,
+            }.into());
+        }
+    }
+
+    let version = content_version(data);
+    crate::services::watch_service::mark_self_write(&save_path);
     save_file(&sftp, Path::new(&save_path), data.to_string())?;
+    search_index_service::note_saved(&hugo_config, &save_path, data);
 
     if manual {
+        // 리비전 스냅샷 (실패해도 저장 자체는 성공으로 처리)
+        if let Err(e) = history_service::record_save(&sftp, &hugo_config, file_path, remote_content.as_deref(), data) {
+            eprintln!("Failed to record revision for {}: {}", file_path, e);
+        }
+
         // 이미지 정합성 동기화 (외부 참조 복사 + 고아 삭제)
         if let Err(e) = sync_images_on_save(&sftp, &hugo_config, file_path, data) {
             crate::emit_hook_actions(vec![crate::types::plugin::PluginResult {
@@ -396,7 +621,7 @@ pub fn write_content(file_path: &str, data: &str, manual: bool) -> Result<bool>
                     toast_type: "warning".to_string(),
                 }],
             }]);
-            return Ok(false);
+            return Ok(SaveOutcome { synced: false, version });
         }
 
         if let Ok(results) = plugin_service::run_hooks(
@@ -407,10 +632,14 @@ pub fn write_content(file_path: &str, data: &str, manual: bool) -> Result<bool>
         }
     }
 
-    Ok(true)
+    Ok(SaveOutcome { synced: true, version })
 }
 
-/// 이미지 저장
+/// 이미지 저장. 사이트 이미지 정책(CmsConfig.image_policy)을 적용하고
+/// 실제 형식의 확장자를 붙인 최종 경로를 반환한다.
+/// 첨부 확장자(CmsConfig.attachment_extensions) 파일은 정책 없이 원래 이름으로 같은 디렉토리에 저장한다.
+/// 형식을 알 수 없고 이미지/첨부 확장자도 아닌 파일은 거부한다.
+/// leaf bundle 페이지이고 `bundle_resource_location`이 Bundle이면 번들 디렉토리에 저장하고 파일명(상대경로)을 반환한다.
 pub fn write_image(file_path: &str, file_name: &str, data: Vec<u8>) -> Result<String> {
     let (sftp, hugo_config) = sftp_and_config()?;
 
@@ -419,39 +648,209 @@ pub fn write_image(file_path: &str, file_name: &str, data: Vec<u8>) -> Result<St
         anyhow::bail!("Invalid image path: {}/{}", file_path, file_name);
     }
 
-    // TODO: extract image_ext from image raw data
-    let image_ext = "";
-    let ret_path = format!("{}/{}{}", file_path, file_name, image_ext);
+    let cms = get_app_config()?.cms_config;
+    let bundle_target = bundle_dir(file_path)
+        .filter(|_| cms.bundle_resource_location == ResourceLocation::Bundle)
+        .map(|dir| {
+            let hidden = hugo_config.hidden_abs(dir);
+            if sftp.stat(Path::new(&hidden)).is_ok() { hidden } else { hugo_config.content_abs(dir) }
+        });
+    // 파일명 → (저장 절대경로, 본문에 쓸 참조 경로)
+    let target = |name: &str| match &bundle_target {
+        Some(dir) => (format!("{}/{}", dir, name), name.to_string()),
+        None => {
+            let ret_path = format!("{}/{}", file_path, name);
+            (image_abs(&hugo_config, &ret_path), ret_path)
+        }
+    };
 
-    save_image(
-        &sftp,
-        Path::new(&format!("{}/{}/{}", &hugo_config.base_path, &hugo_config.image_path, ret_path)),
-        data,
-    )?;
+    let attachment_exts = cms.attachment_exts();
+    if let Some((stem, ext)) = file_name
+        .rsplit_once('.')
+        .filter(|(stem, ext)| !stem.is_empty() && attachment_exts.contains(&ext.to_lowercase()))
+    {
+        // 공백은 markdown 링크를 깨뜨리므로 '-'로, 같은 이름이 있으면 _1, _2 ...
+        let stem: String = stem.chars().map(|c| if c.is_whitespace() { '-' } else { c }).collect();
+        let (mut abs, mut ret_path) = target(&format!("{}.{}", stem, ext));
+        let mut n = 1;
+        while sftp.stat(Path::new(&abs)).is_ok() {
+            (abs, ret_path) = target(&format!("{}_{}.{}", stem, n, ext));
+            n += 1;
+        }
+        save_image(&sftp, Path::new(&abs), data)?;
+        return Ok(ret_path);
+    }
+
+    let processed = image_service::process_upload(data, &cms.image_policy)?;
+    // 이미지도 첨부도 아닌 파일은 이미지 동기화/고아 정리가 추적하지 못하므로 받지 않는다
+    if processed.ext.is_none() && !image_service::is_image_file(file_name) {
+        anyhow::bail!("Unsupported file type: {}", file_name);
+    }
+    // 이름에 붙어 온 확장자는 실제 형식과 다를 수 있으므로 교체
+    let stem = match file_name.rsplit_once('.') {
+        Some((stem, _)) if !stem.is_empty() => stem,
+        _ => file_name,
+    };
+    let name = match processed.ext {
+        Some(ext) => format!("{}.{}", stem, ext),
+        None => file_name.to_string(),
+    };
+    let (abs, ret_path) = target(&name);
+    save_image(&sftp, Path::new(&abs), processed.data)?;
     Ok(ret_path)
 }
 
-/// Hugo 새 콘텐츠 생성 (중복 이름 자동 처리)
-pub fn create_content(file_path: &str) -> Result<String> {
-    let (sftp, hugo_config) = sftp_and_config()?;
+/// 새 페이지에 바로 적용할 front matter 값 (None이면 archetype 값 유지)
+#[typeshare]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct InitialFrontMatter {
+    pub title: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub draft: Option<bool>,
+}
+
+/// 사이트/테마의 archetype
+#[typeshare]
+#[derive(Clone, Debug, Serialize)]
+pub struct Archetype {
+    /// `hugo new --kind`에 넘기는 이름 (파일명에서 .md 제외)
+    pub kind: String,
+    /// None = 사이트 archetypes/, Some = 테마 이름
+    pub theme: Option<String>,
+    /// 디렉토리 archetype (leaf bundle을 통째로 생성)
+    pub bundle: bool,
+}
+
+/// 사이트 설정 파일의 `theme` 값 (문자열 또는 한 줄 배열). 여러 줄 YAML 리스트는 인식하지 않는다.
+fn parse_theme_lines(output: &str) -> Vec<String> {
+    let mut themes = Vec::new();
+    for line in output.lines() {
+        let Some(sep) = line.find([':', '=']) else { continue };
+        let value = line[sep + 1..].trim().trim_start_matches('[').trim_end_matches([']', ',']);
+        for item in value.split(',') {
+            let name = front_matter::unquote(item.trim()).trim();
+            if !name.is_empty() && !themes.iter().any(|t| t == name) {
+                themes.push(name.to_string());
+            }
+        }
+    }
+    themes
+}
+
+/// Hugo 사이트 설정 파일 후보 (base_path 기준, 셸 glob)
+pub const SITE_CONFIG_FILES: &str =
+    "hugo.toml hugo.yaml hugo.yml config.toml config.yaml config.yml config/_default/hugo.* config/_default/config.*";
+
+/// 사이트 archetypes/ + 설정된 테마의 archetypes/ 목록.
+/// 같은 kind는 Hugo 조회 순서(사이트 → 테마 순서)대로 앞의 것만 남긴다.
+pub fn list_archetypes() -> Result<Vec<Archetype>> {
+    let hugo_config = get_hugo_config()?;
     let mut channel = get_channel_session()?;
+    let cmd = format!(
+        "cd {} || exit 1; echo '---CONFIG---'; \
+         cat {} 2>/dev/null \
+         | grep -iE '^[[:space:]]*\"?theme\"?[[:space:]]*[:=]'; \
+         echo '---ARCHETYPES---'; find archetypes themes/*/archetypes -mindepth 1 -maxdepth 1 -printf '%y %p\\n' 2>/dev/null; true",
+        crate::utils::shell::quote(&hugo_config.base_path),
+        SITE_CONFIG_FILES,
+    );
+    let output = execute_ssh_command(&mut channel, &cmd)?;
+    let sections = split_marked_sections(&output, &["---CONFIG---", "---ARCHETYPES---"]);
+    let themes = parse_theme_lines(sections[0]);
+
+    let mut found: Vec<(usize, Archetype)> = Vec::new();
+    for line in sections[1].lines() {
+        let Some((type_char, path)) = line.trim().split_once(' ') else { continue };
+        let (theme, name) = match path.strip_prefix("archetypes/") {
+            Some(name) => (None, name),
+            None => {
+                let Some(rest) = path.strip_prefix("themes/") else { continue };
+                let Some((theme, name)) = rest.split_once("/archetypes/") else { continue };
+                (Some(theme.to_string()), name)
+            }
+        };
+        // 사이트 → 설정된 테마 순서. 설정에 없는 테마는 Hugo가 쓰지 않으므로 제외
+        let rank = match &theme {
+            None => 0,
+            Some(t) => match themes.iter().position(|x| x == t) {
+                Some(i) => i + 1,
+                None => continue,
+            },
+        };
+        let bundle = type_char == "d";
+        let kind = if bundle { Some(name) } else { name.strip_suffix(".md") };
+        let Some(kind) = kind.filter(|k| !k.is_empty() && !k.starts_with('.')) else { continue };
+        found.push((rank, Archetype { kind: kind.to_string(), theme, bundle }));
+    }
 
-    let unique_path = find_unique_path(&sftp, &hugo_config, file_path);
+    found.sort_by(|(ra, a), (rb, b)| ra.cmp(rb).then_with(|| a.kind.cmp(&b.kind)));
+    let mut seen = std::collections::HashSet::new();
+    let mut archetypes: Vec<Archetype> = found
+        .into_iter()
+        .map(|(_, a)| a)
+        .filter(|a| seen.insert(a.kind.clone()))
+        .collect();
+    archetypes.sort_by(|a, b| a.kind.cmp(&b.kind));
+    Ok(archetypes)
+}
+
+/// Hugo 새 콘텐츠 생성 (중복 이름 자동 처리).
+/// kind가 있으면 `hugo new --kind`로 해당 archetype을 쓴다. 디렉토리 archetype이면
+/// 경로를 leaf bundle("…/name/index.md")로 바꿔 번들 디렉토리를 생성한다.
+/// initial 값은 `hugo new` 성공 후 front matter에 덮어쓴다.
+pub fn create_content(file_path: &str, kind: Option<&str>, initial: Option<&InitialFrontMatter>) -> Result<String> {
+    let (sftp, hugo_config) = sftp_and_config()?;
+
+    let kind = kind.map(str::trim).filter(|k| !k.is_empty());
+    let archetype = match kind {
+        Some(k) => Some(
+            list_archetypes()?
+                .into_iter()
+                .find(|a| a.kind == k)
+                .with_context(|| format!("Unknown archetype: {}", k))?,
+        ),
+        None => None,
+    };
+
+    // 디렉토리 archetype: "/posts/new_file.md" → "/posts/new_file/index.md"
+    let requested = match &archetype {
+        Some(a) if a.bundle && bundle_dir(file_path).is_none() => {
+            format!("{}/index.md", file_path.trim_end_matches(".md").trim_end_matches("/_index"))
+        }
+        _ => file_path.to_string(),
+    };
+    let unique_path = find_unique_path(&sftp, &hugo_config, &requested);
 
     // unique_path에 섹션이 포함됨: e.g. "/posts/my-post/_index.md"
-    let clean_path = unique_path.trim_start_matches('/');
-    // clean_path(사용자 입력 유래)와 base_path는 quoting 필수.
+    // 디렉토리 archetype은 번들 디렉토리 경로를 넘긴다
+    let target = match &archetype {
+        Some(a) if a.bundle => bundle_dir(&unique_path).unwrap_or(&unique_path),
+        _ => &unique_path,
+    };
+    let clean_path = target.trim_start_matches('/');
+    let kind_arg = match kind {
+        Some(k) => format!(" --kind {}", crate::utils::shell::quote(k)),
+        None => String::new(),
+    };
+    // clean_path(사용자 입력 유래), kind와 base_path는 quoting 필수.
     // hugo_cmd_path는 `~` 확장이 필요할 수 있어 quoting하지 않는다 (설정값).
+    let mut channel = get_channel_session()?;
     execute_ssh_command_checked(
         &mut channel,
         &format!(
-            "cd {} ; {} new {}",
+            "cd {} ; {} new{} {}",
             crate::utils::shell::quote(&hugo_config.base_path),
             &hugo_config.hugo_cmd_path,
+            kind_arg,
             crate::utils::shell::quote(clean_path),
         ),
     )?;
 
+    if let Some(initial) = initial {
+        apply_initial_front_matter(&sftp, &hugo_config, &unique_path, initial)?;
+    }
+    search_index_service::note_created(&hugo_config, &hugo_config.content_abs(target));
+
     if let Ok(results) = plugin_service::run_hooks(
         HookEvent::AfterFileCreate,
         serde_json::json!({ "path": &unique_path }),
@@ -462,12 +861,33 @@ pub fn create_content(file_path: &str) -> Result<String> {
     Ok(unique_path)
 }
 
+/// 방금 만든 페이지의 front matter에 초기값 기록 (값이 있는 필드만)
+fn apply_initial_front_matter(sftp: &Sftp, hugo_config: &HugoConfig, rel: &str, initial: &InitialFrontMatter) -> Result<()> {
+    let abs = hugo_config.content_abs(rel);
+    let content = get_file(sftp, Path::new(&abs)).with_context(|| format!("Created page not found: {}", rel))?;
+    let mut updated = content.clone();
+    if let Some(title) = &initial.title {
+        updated = front_matter::set(&updated, "title", &FmValue::Str(title.clone()));
+    }
+    if let Some(tags) = &initial.tags {
+        updated = front_matter::set(&updated, "tags", &FmValue::List(tags.clone()));
+    }
+    if let Some(draft) = initial.draft {
+        updated = front_matter::set(&updated, "draft", &FmValue::Bool(draft));
+    }
+    if updated != content {
+        save_file(sftp, Path::new(&abs), updated)?;
+    }
+    Ok(())
+}
+
 /// 파일/폴더 삭제 (content + hidden 양쪽 시도)
-/// 이미지 디렉토리는 삭제하지 않음 (고아로 유지, 플러그인에서 정리)
 pub fn remove_content(path: &str) -> Result<()> {
-    let (mut sftp, hugo_config) = sftp_and_config()?;
-    let targets = [hugo_config.content_abs(path), hugo_config.hidden_abs(path)];
-    try_both(targets, |p| rmrf_file(&mut sftp, Path::new(&p)))?;
+    let (sftp, hugo_config) = sftp_and_config()?;
+    // 영구 삭제 대신 휴지통으로 이동 (content/hidden + 이미지 디렉토리)
+    trash_service::trash_content(&sftp, &hugo_config, path)?;
+    search_index_service::note_removed(&hugo_config, &hugo_config.content_abs(path));
+    search_index_service::note_removed(&hugo_config, &hugo_config.hidden_abs(path));
 
     if let Ok(results) = plugin_service::run_hooks(
         HookEvent::AfterFileDelete,
@@ -483,8 +903,14 @@ pub fn remove_content(path: &str) -> Result<()> {
 /// 1. content/hidden rename
 /// 2. 이미지 디렉토리 rename → 실패 시 1 되돌리기
 /// 3. 참조 업데이트 → 실패 시 2,1 되돌리기
+///
+/// leaf bundle의 index.md끼리의 이동은 번들 디렉토리 이동으로 처리한다.
 pub fn move_content(src: &str, dst: &str) -> Result<()> {
     let (sftp, hugo_config) = sftp_and_config()?;
+    let (src, dst) = match (bundle_dir(src), bundle_dir(dst)) {
+        (Some(s), Some(d)) => (s, d),
+        _ => (src, dst),
+    };
 
     if path_exists(&sftp, &hugo_config, dst) {
         bail!("Destination already exists: {}", dst);
@@ -515,6 +941,13 @@ pub fn move_content(src: &str, dst: &str) -> Result<()> {
             return Err(anyhow::anyhow!("Failed to move hidden: {}", e));
         }
     }
+    // 이후 단계가 실패해 되돌려지면 다음 인덱스 동기화에서 바로잡힌다
+    if content_exists {
+        search_index_service::note_moved(&hugo_config, &content_src, &content_dst);
+    }
+    if hidden_exists {
+        search_index_service::note_moved(&hugo_config, &hidden_src, &hidden_dst);
+    }
 
     // === Phase 2: Rename image directory ===
     if src != dst {
@@ -588,6 +1021,13 @@ pub fn move_content(src: &str, dst: &str) -> Result<()> {
         }
     }
 
+    // === Phase 4: 리비전 히스토리 이동 (실패해도 이동은 유지) ===
+    if src != dst {
+        if let Err(e) = history_service::move_history(&sftp, &hugo_config, src, dst) {
+            eprintln!("Failed to move revision history {} -> {}: {}", src, dst, e);
+        }
+    }
+
     // === Hook ===
     if let Ok(results) = plugin_service::run_hooks(
         HookEvent::AfterFileMove,
@@ -599,9 +1039,143 @@ pub fn move_content(src: &str, dst: &str) -> Result<()> {
     Ok(())
 }
 
-/// 숨김 상태 토글 (토글 전 대상 경로 존재 여부 체크)
+/// 파일/폴더 복제 (content/hidden + 이미지 디렉토리). 새 경로는 find_unique_path로 정한다.
+/// reset_front_matter=true면 복제된 페이지(폴더는 _index.md, leaf bundle은 index.md)의 title을 새 이름으로, date를 현재 시각으로 바꾼다.
+/// 반환: 새 상대경로 (파일 "/posts/a_1.md", 폴더 "/posts/x_1")
+pub fn duplicate_content(src: &str, reset_front_matter: bool) -> Result<String> {
+    let (sftp, hugo_config) = sftp_and_config()?;
+    // leaf bundle의 index.md는 번들 디렉토리째 복제
+    let src = bundle_dir(src).unwrap_or(src);
+
+    let content_src = hugo_config.content_abs(src);
+    let hidden_src = hugo_config.hidden_abs(src);
+    let content_stat = sftp.stat(Path::new(&content_src)).ok();
+    let hidden_stat = sftp.stat(Path::new(&hidden_src)).ok();
+    let Some(stat) = content_stat.as_ref().or(hidden_stat.as_ref()) else {
+        bail!("Source does not exist: {}", src);
+    };
+    let is_dir = stat.is_dir();
+
+    let dst = if is_dir {
+        let unique = find_unique_path(&sftp, &hugo_config, &format!("{}/_index.md", src.trim_end_matches('/')));
+        unique.trim_end_matches("/_index.md").to_string()
+    } else {
+        find_unique_path(&sftp, &hugo_config, src)
+    };
+    if dst == src || path_exists(&sftp, &hugo_config, &dst) {
+        bail!("Could not find a free path to duplicate {}", src);
+    }
+
+    // === Phase 1: content/hidden 복사 ===
+    let mut copies = Vec::new();
+    if content_stat.is_some() {
+        copies.push((content_src, hugo_config.content_abs(&dst)));
+    }
+    if hidden_stat.is_some() {
+        copies.push((hidden_src, hugo_config.hidden_abs(&dst)));
+    }
+    // === Phase 2: 이미지 디렉토리 복사 (legacy 위치여도 새 형식 경로로) ===
+    if let Some((src_img, _)) = find_image_dir(&sftp, &hugo_config, src) {
+        copies.push((src_img, image_abs(&hugo_config, &dst)));
+    }
+    let mut copied: Vec<String> = Vec::new();
+    // 롤백: 이미 만든 복사본(content/hidden/이미지 디렉토리) 삭제
+    let remove_copies = |copied: &[String]| {
+        for path in copied {
+            if let Ok(mut channel) = get_channel_session() {
+                let _ = execute_ssh_command(&mut channel, &format!("rm -rf -- {}", crate::utils::shell::quote(path)));
+            }
+        }
+    };
+    for (from, to) in &copies {
+        if let Some(parent) = Path::new(to).parent() {
+            mkdir_recursive(&sftp, parent)?;
+        }
+        let result = execute_ssh_command_checked(
+            &mut get_channel_session()?,
+            &format!("cp -a -- {} {}", crate::utils::shell::quote(from), crate::utils::shell::quote(to)),
+        );
+        if let Err(e) = result {
+            remove_copies(&copied);
+            return Err(e.context(format!("Failed to copy {}", from)));
+        }
+        copied.push(to.clone());
+    }
+
+    // Phase 3/4가 실패하면 원본 이미지를 가리키는 복사본이 남지 않도록 지운다
+    if let Err(e) = finish_duplicate(&sftp, &hugo_config, src, &dst, is_dir, reset_front_matter) {
+        remove_copies(&copied);
+        return Err(e);
+    }
+    if content_stat.is_some() {
+        search_index_service::note_created(&hugo_config, &hugo_config.content_abs(&dst));
+    }
+    if hidden_stat.is_some() {
+        search_index_service::note_created(&hugo_config, &hugo_config.hidden_abs(&dst));
+    }
+
+    if let Ok(results) = plugin_service::run_hooks(
+        HookEvent::AfterFileCreate,
+        serde_json::json!({ "path": &dst, "duplicated_from": src }),
+    ) {
+        crate::emit_hook_actions(results);
+    }
+
+    Ok(dst)
+}
+
+/// 복제 Phase 3/4. 실패하면 호출자가 복사본을 지운다
+fn finish_duplicate(sftp: &Sftp, hugo_config: &HugoConfig, src: &str, dst: &str, is_dir: bool, reset_front_matter: bool) -> Result<()> {
+    // === Phase 3: 복사본의 이미지 참조를 새 경로로 (이동과 같은 규칙) ===
+    sync_images_on_move(sftp, hugo_config, src, dst)?;
+
+    // === Phase 4: title/date 초기화 ===
+    if reset_front_matter {
+        let page = if !is_dir {
+            dst.to_string()
+        } else if path_exists(sftp, hugo_config, &format!("{}/index.md", dst)) {
+            format!("{}/index.md", dst)
+        } else {
+            format!("{}/_index.md", dst)
+        };
+        let page_abs = [hugo_config.content_abs(&page), hugo_config.hidden_abs(&page)]
+            .into_iter()
+            .find(|p| sftp.stat(Path::new(p)).is_ok());
+        if let Some(abs) = page_abs {
+            let content = get_file(sftp, Path::new(&abs))?;
+            let name = dst.rsplit('/').next().unwrap_or("").trim_end_matches(".md");
+            let updated = front_matter::set(&content, "title", &FmValue::Str(title_from_name(name)));
+            let updated = front_matter::set(
+                &updated,
+                "date",
+                &FmValue::Bare(front_matter::format_rfc3339(front_matter::now_unix())),
+            );
+            save_file(sftp, Path::new(&abs), updated)?;
+        }
+    }
+    Ok(())
+}
+
+/// hugo new와 같은 규칙으로 파일명에서 제목 생성: "my-post_1" → "My Post 1"
+fn title_from_name(name: &str) -> String {
+    name.split(['-', '_', ' '])
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// 숨김 상태 토글 (토글 전 대상 경로 존재 여부 체크).
+/// leaf bundle의 index.md는 리소스와 떨어지지 않도록 번들 디렉토리째 옮긴다.
 pub fn toggle_hidden(path: &str, state: bool) -> Result<()> {
     let (sftp, hugo_config) = sftp_and_config()?;
+    let path = bundle_dir(path).unwrap_or(path);
 
     let (src, dst) = if state {
         (hugo_config.hidden_abs(path), hugo_config.content_abs(path))
@@ -613,7 +1187,9 @@ pub fn toggle_hidden(path: &str, state: bool) -> Result<()> {
         bail!("Destination already exists: {}", dst);
     }
 
-    move_file(&sftp, Path::new(&src), Path::new(&dst))
+    move_file(&sftp, Path::new(&src), Path::new(&dst))?;
+    search_index_service::note_moved(&hugo_config, &src, &dst);
+    Ok(())
 }
 
 /// 숨김 상태 확인
@@ -622,18 +1198,79 @@ pub fn check_hidden(path: &str) -> Result<bool> {
     Ok(sftp.stat(Path::new(&hugo_config.hidden_abs(path))).is_ok())
 }
 
+/// 발행 상태 변경 요청
+pub enum PublishAction {
+    /// draft 해제 + 미래 publishDate/지난 expiryDate 정리
+    Publish,
+    /// draft: true
+    Unpublish,
+    /// draft 해제 + publishDate 지정
+    Schedule(String),
+}
+
+/// 단일 파일의 front matter로 발행 상태 계산
+fn publish_state_of(content: &str, now: i64) -> PublishState {
+    compute_publish_state(
+        front_matter::get(content, "draft").as_deref(),
+        front_matter::get(content, "publishDate").as_deref(),
+        front_matter::get(content, "date").as_deref(),
+        front_matter::get(content, "expiryDate").as_deref(),
+        now,
+    )
+}
+
+/// front matter의 draft/publishDate/expiryDate를 수정하여 발행 상태 변경.
+/// 순수 저장(autosave 경로)만 수행하고 변경 후 상태를 반환한다.
+pub fn set_publish_state(file_path: &str, action: PublishAction) -> Result<PublishState> {
+    let VersionedContent { content, version } = read_content_versioned(file_path)?;
+    let now = front_matter::now_unix();
+
+    let updated = match action {
+        PublishAction::Publish => {
+            let mut c = front_matter::set(&content, "draft", &FmValue::Bool(false));
+            let publish_at = front_matter::get(&c, "publishDate")
+                .or_else(|| front_matter::get(&c, "date"))
+                .and_then(|d| front_matter::parse_date(&d));
+            if publish_at.is_some_and(|t| t > now) {
+                c = front_matter::set(&c, "publishDate", &FmValue::Bare(front_matter::format_rfc3339(now)));
+            }
+            let expired = front_matter::get(&c, "expiryDate")
+                .and_then(|d| front_matter::parse_date(&d))
+                .is_some_and(|t| t <= now);
+            if expired {
+                c = front_matter::remove(&c, "expiryDate");
+            }
+            c
+        }
+        PublishAction::Unpublish => front_matter::set(&content, "draft", &FmValue::Bool(true)),
+        PublishAction::Schedule(date) => {
+            let date = date.trim();
+            if front_matter::parse_date(date).is_none() {
+                bail!("Invalid publish date: {}", date);
+            }
+            let c = front_matter::set(&content, "draft", &FmValue::Bool(false));
+            front_matter::set(&c, "publishDate", &FmValue::Bare(date.to_string()))
+        }
+    };
+
+    if updated != content {
+        write_content(file_path, &updated, false, Some(&version))?;
+    }
+    Ok(publish_state_of(&updated, now))
+}
+
 // ============================================================
 // 이미지 동기화
 // ============================================================
 
 /// 이미지 절대경로: {base_path}/{image_path}/{rel}
-fn image_abs(config: &HugoConfig, rel: &str) -> String {
+pub fn image_abs(config: &HugoConfig, rel: &str) -> String {
     format!("{}/{}/{}", config.base_path, config.image_path, rel.trim_start_matches('/'))
 }
 
 /// 경로에서 섹션 접두사를 제거하여 legacy 경로 반환
 /// e.g. "/posts/my-post/_index.md" → Some("my-post/_index.md")
-fn strip_section_prefix<'a>(config: &HugoConfig, rel: &'a str) -> Option<&'a str> {
+pub fn strip_section_prefix<'a>(config: &HugoConfig, rel: &'a str) -> Option<&'a str> {
     let clean = rel.trim_start_matches('/');
     for section in &config.content_paths {
         let prefix = format!("{}/", section);
@@ -646,7 +1283,7 @@ fn strip_section_prefix<'a>(config: &HugoConfig, rel: &'a str) -> Option<&'a str
 
 /// 이미지 디렉토리를 새 경로 → legacy 경로 순서로 검색
 /// 반환: (found_abs_path, is_legacy)
-fn find_image_dir(sftp: &Sftp, config: &HugoConfig, rel: &str) -> Option<(String, bool)> {
+pub fn find_image_dir(sftp: &Sftp, config: &HugoConfig, rel: &str) -> Option<(String, bool)> {
     // 새 경로 (섹션 포함): {image_path}/posts/my-post/_index.md
     let new_path = image_abs(config, rel);
     if sftp.stat(Path::new(&new_path)).is_ok() {
@@ -683,10 +1320,17 @@ fn find_md_files_recursive(sftp: &Sftp, dir: &str) -> Result<Vec<String>> {
     Ok(result)
 }
 
-/// md 파일 내 이미지 참조 경로만 치환 (old_prefix → new_prefix)
-/// ![...](...) 패턴 안에서만 치환하여 본문 텍스트나 일반 링크는 건드리지 않음
+/// md 파일 내 이미지/첨부파일 참조 경로만 치환 (old_prefix → new_prefix)
+/// 참조(image_refs) 안에서만 치환하여 본문 텍스트나 페이지 링크는 건드리지 않음
 /// 경로 시작 부분(prefix)만 매칭하여 부분 문자열 오매칭 방지
-fn update_image_refs_in_file(sftp: &Sftp, config: &HugoConfig, rel_path: &str, old_prefix: &str, new_prefix: &str) -> Result<()> {
+fn update_image_refs_in_file(
+    sftp: &Sftp,
+    config: &HugoConfig,
+    rel_path: &str,
+    old_prefix: &str,
+    new_prefix: &str,
+    syntax: &RefSyntax,
+) -> Result<()> {
     let content_path = config.content_abs(rel_path);
     let hidden_path = config.hidden_abs(rel_path);
 
@@ -708,23 +1352,10 @@ fn update_image_refs_in_file(sftp: &Sftp, config: &HugoConfig, rel_path: &str, o
         }
     };
 
-    // ![alt](path) 패턴
-    let md_re = regex::Regex::new(r"(!\[[^\]]*\]\()([^)]+)(\))").unwrap();
-    let updated = md_re.replace_all(&content, |caps: &regex::Captures| {
-        let prefix = &caps[1];
-        let path = replace_path_prefix(&caps[2]);
-        let suffix = &caps[3];
-        format!("{}{}{}", prefix, path, suffix)
-    }).to_string();
-
-    // <img src="path"> 패턴
-    let img_re = regex::Regex::new(r#"(<img\s[^>]*src\s*=\s*["'])([^"']+)(["'])"#).unwrap();
-    let updated = img_re.replace_all(&updated, |caps: &regex::Captures| {
-        let prefix = &caps[1];
-        let path = replace_path_prefix(&caps[2]);
-        let suffix = &caps[3];
-        format!("{}{}{}", prefix, path, suffix)
-    }).to_string();
+    let updated = image_refs::replace_image_refs(&content, syntax, |r| {
+        let new_path = replace_path_prefix(&r.path);
+        (new_path != r.path).then_some(new_path)
+    });
 
     if content != updated {
         save_file(sftp, Path::new(&abs_path), updated)?;
@@ -741,14 +1372,15 @@ fn sync_images_on_move(sftp: &Sftp, config: &HugoConfig, src: &str, dst: &str) -
 
     let content_base = config.content_abs("");
     let hidden_base = config.hidden_abs("");
+    let syntax = site_ref_syntax();
 
     let apply_refs = |rel_path: &str| {
         // 새 형식 prefix 치환: posts/old-name → posts/new-name
-        let _ = update_image_refs_in_file(sftp, config, rel_path, old_prefix, new_prefix);
+        let _ = update_image_refs_in_file(sftp, config, rel_path, old_prefix, new_prefix, &syntax);
         // Legacy 형식 치환: old-name → new-name (섹션 없는 참조)
         if let Some(lo) = &legacy_old {
             if lo != old_prefix {
-                let _ = update_image_refs_in_file(sftp, config, rel_path, lo, new_prefix);
+                let _ = update_image_refs_in_file(sftp, config, rel_path, lo, new_prefix, &syntax);
             }
         }
     };
@@ -780,7 +1412,7 @@ fn sync_images_on_move(sftp: &Sftp, config: &HugoConfig, src: &str, dst: &str) -
 }
 
 /// SFTP 파일 내용을 바이트로 읽기
-fn read_file_bytes(sftp: &Sftp, path: &Path) -> Result<Vec<u8>> {
+pub fn read_file_bytes(sftp: &Sftp, path: &Path) -> Result<Vec<u8>> {
     let mut file = sftp.open(path)?;
     let mut data = Vec::new();
     file.read_to_end(&mut data)?;
@@ -804,10 +1436,13 @@ fn copy_file_checked(sftp: &Sftp, src: &Path, dst: &Path) -> Result<CopyResult>
                 return Ok(CopyResult::Skipped);
             }
         }
-        // 내용이 다름 → 새 UUID 이름 생성
+        // 내용이 다름 → 새 UUID 이름 생성 (첨부파일이 열리도록 확장자는 유지)
         let mut rng = rand::thread_rng();
         let hex: String = (0..16).map(|_| format!("{:x}", rng.gen::<u8>())).collect();
-        let new_name = hex;
+        let new_name = match dst.extension().and_then(|e| e.to_str()) {
+            Some(ext) => format!("{}.{}", hex, ext),
+            None => hex,
+        };
         // 원격 경로는 '/'로 조립 (Windows의 PathBuf::join은 '\'를 넣는다)
         let dst_str = dst.to_string_lossy();
         let parent_str = dst_str.rsplit_once('/').map(|(p, _)| p).unwrap_or("");
@@ -828,45 +1463,39 @@ fn copy_file_checked(sftp: &Sftp, src: &Path, dst: &Path) -> Result<CopyResult>
     Ok(CopyResult::Copied)
 }
 
-/// md 내 모든 이미지 참조 추출 (로컬 + 외부 URL)
 /// 이미지 경로에서 fragment(#...) 부분을 분리하여 (경로, fragment) 반환
 /// 예: "image.png#center-w60" → ("image.png", "#center-w60")
 ///     "https://example.com/img.png#center" → ("https://example.com/img.png", "#center")
 ///     "image.png" → ("image.png", "")
-fn split_image_fragment(path: &str) -> (&str, &str) {
+pub fn split_image_fragment(path: &str) -> (&str, &str) {
     match path.find('#') {
         Some(idx) => (&path[..idx], &path[idx..]),
         None => (path, ""),
     }
 }
 
-fn parse_all_image_refs(content: &str) -> (Vec<String>, Vec<String>) {
+/// md 내 모든 이미지/첨부파일 참조 추출 (로컬, 외부 URL)
+pub fn parse_all_image_refs(content: &str, syntax: &RefSyntax) -> (Vec<String>, Vec<String>) {
     let mut local_refs = Vec::new();
     let mut external_urls = Vec::new();
 
-    let md_re = regex::Regex::new(r"!\[[^\]]*\]\(([^)]+)\)").unwrap();
-    for cap in md_re.captures_iter(content) {
-        let path = cap[1].trim().to_string();
-        if path.starts_with("http://") || path.starts_with("https://") {
-            external_urls.push(path);
-        } else {
-            local_refs.push(path);
-        }
-    }
-
-    let img_re = regex::Regex::new(r#"<img\s[^>]*src\s*=\s*["']([^"']+)["']"#).unwrap();
-    for cap in img_re.captures_iter(content) {
-        let path = cap[1].trim().to_string();
-        if path.starts_with("http://") || path.starts_with("https://") {
-            external_urls.push(path);
+    for r in image_refs::find_image_refs(content, syntax) {
+        if r.path.starts_with("http://") || r.path.starts_with("https://") {
+            external_urls.push(r.path);
         } else {
-            local_refs.push(path);
+            local_refs.push(r.path);
         }
     }
 
     (local_refs, external_urls)
 }
 
+/// 사이트 설정의 참조 인식 규칙 (추가 shortcode + 첨부 확장자). 설정을 읽을 수 없으면 기본값
+pub fn site_ref_syntax() -> RefSyntax {
+    let cms = get_app_config().map(|c| c.cms_config).unwrap_or_default();
+    RefSyntax { attachment_exts: cms.attachment_exts(), shortcodes: cms.image_shortcodes }
+}
+
 /// URL에서 파일명 생성 (sha2 해시 12자 + 확장자)
 fn generate_url_filename(url: &str) -> String {
     use sha2::{Sha256, Digest};
@@ -886,7 +1515,7 @@ fn generate_url_filename(url: &str) -> String {
     }
 }
 
-/// URL에서 이미지를 다운로드하여 SFTP로 업로드
+/// URL에서 이미지/첨부파일을 다운로드하여 SFTP로 업로드
 fn download_url_to_sftp(sftp: &Sftp, url: &str, dst: &Path) -> Result<()> {
     let resp = ureq::get(url).call()
         .map_err(|e| anyhow::anyhow!("Download failed: {}", e))?;
@@ -906,21 +1535,15 @@ fn download_url_to_sftp(sftp: &Sftp, url: &str, dst: &Path) -> Result<()> {
     Ok(())
 }
 
-/// md 내 이미지 참조를 치환하는 헬퍼
-fn replace_image_ref(content: &str, old_ref: &str, new_ref: &str) -> String {
-    let escaped = regex::escape(old_ref);
-    // ![alt](path) 패턴
-    let md_re = regex::Regex::new(&format!(r"(!\[[^\]]*\]\(){}(\))", escaped)).unwrap();
-    let result = md_re.replace_all(content, format!("${{1}}{}${{2}}", new_ref)).to_string();
-    // <img src="path"> 패턴
-    let img_re = regex::Regex::new(&format!(r#"(<img\s[^>]*src\s*=\s*["']){}(["'])"#, escaped)).unwrap();
-    img_re.replace_all(&result, format!("${{1}}{}${{2}}", new_ref)).to_string()
+/// md 내 이미지 참조를 치환하는 헬퍼 (경로가 정확히 old_ref인 참조만)
+fn replace_image_ref(content: &str, old_ref: &str, new_ref: &str, syntax: &RefSyntax) -> String {
+    image_refs::replace_image_refs(content, syntax, |r| (r.path == old_ref).then(|| new_ref.to_string()))
 }
 
-/// 저장 시 이미지 정합성 동기화:
-/// 1. 외부 이미지 참조 → 내 디렉토리로 복사 + 참조 수정
+/// 저장 시 이미지/첨부파일 정합성 동기화:
+/// 1. 다른 페이지의 이미지/첨부파일 참조 → 내 디렉토리로 복사 + 참조 수정
 /// 2. 외부 URL → 다운로드 + 내 디렉토리에 저장 + 참조 수정
-/// 고아 이미지는 삭제하지 않음 (플러그인에서 별도 처리)
+/// 고아 이미지는 삭제하지 않음 (`find_orphan_images` / `clean_orphan_images`에서 별도 처리)
 fn sync_images_on_save(sftp: &Sftp, config: &HugoConfig, file_path: &str, content: &str) -> Result<()> {
     if !file_path.ends_with(".md") {
         return Ok(());
@@ -928,8 +1551,10 @@ fn sync_images_on_save(sftp: &Sftp, config: &HugoConfig, file_path: &str, conten
 
     let rel = file_path.trim_start_matches('/');
     let my_prefix = format!("{}/", rel);
+    let is_bundle = bundle_dir(file_path).is_some();
 
-    let (local_refs, external_urls) = parse_all_image_refs(content);
+    let syntax = site_ref_syntax();
+    let (local_refs, external_urls) = parse_all_image_refs(content, &syntax);
     let mut updated_content = content.to_string();
     let mut modified = false;
 
@@ -937,6 +1562,9 @@ fn sync_images_on_save(sftp: &Sftp, config: &HugoConfig, file_path: &str, conten
     for img_ref in &local_refs {
         // fragment(#center-w60 등) 분리: 파일 경로에서 제외하고, 치환 시 보존
         let (ref_path, fragment) = split_image_fragment(img_ref);
+        if is_bundle && !ref_path.starts_with('/') {
+            continue; // leaf bundle의 page resource (번들 디렉토리 기준 상대경로)
+        }
         let ref_clean = ref_path.trim_start_matches('/');
         if ref_clean.starts_with(&my_prefix) {
             continue; // 이미 내 디렉토리
@@ -962,7 +1590,7 @@ fn sync_images_on_save(sftp: &Sftp, config: &HugoConfig, file_path: &str, conten
                 Ok(CopyResult::Renamed(new_name)) => {
                     // 이름 충돌 → 새 이름으로 링크 업데이트 (fragment 보존)
                     let renamed_ref = format!("/{}{}{}", my_prefix, new_name, fragment);
-                    updated_content = replace_image_ref(&updated_content, img_ref, &renamed_ref);
+                    updated_content = replace_image_ref(&updated_content, img_ref, &renamed_ref, &syntax);
                     modified = true;
                     continue;
                 }
@@ -972,7 +1600,7 @@ fn sync_images_on_save(sftp: &Sftp, config: &HugoConfig, file_path: &str, conten
         }
 
         // 링크 업데이트 (항상 / prefix)
-        updated_content = replace_image_ref(&updated_content, img_ref, &new_ref);
+        updated_content = replace_image_ref(&updated_content, img_ref, &new_ref, &syntax);
         modified = true;
     }
 
@@ -985,7 +1613,7 @@ fn sync_images_on_save(sftp: &Sftp, config: &HugoConfig, file_path: &str, conten
 
         // 이미 다운로드 되어있으면 스킵
         if sftp.stat(Path::new(&dst_abs)).is_ok() {
-            updated_content = replace_image_ref(&updated_content, url, &new_ref);
+            updated_content = replace_image_ref(&updated_content, url, &new_ref, &syntax);
             modified = true;
             continue;
         }
@@ -994,7 +1622,7 @@ fn sync_images_on_save(sftp: &Sftp, config: &HugoConfig, file_path: &str, conten
             continue; // 다운로드 실패 → 해당 URL 스킵
         }
 
-        updated_content = replace_image_ref(&updated_content, url, &new_ref);
+        updated_content = replace_image_ref(&updated_content, url, &new_ref, &syntax);
         modified = true;
     }
 
@@ -1013,8 +1641,8 @@ fn sync_images_on_save(sftp: &Sftp, config: &HugoConfig, file_path: &str, conten
     Ok(())
 }
 
-/// 붙여넣기 텍스트의 외부 이미지 참조를 처리하여 수정된 텍스트 반환
-/// 이미지 파일 복사 + URL 다운로드만 수행, 파일 저장은 하지 않음
+/// 붙여넣기 텍스트의 외부 이미지/첨부파일 참조를 처리하여 수정된 텍스트 반환
+/// 파일 복사 + URL 다운로드만 수행, 파일 저장은 하지 않음
 pub fn sync_pasted_refs(file_path: &str, pasted_text: &str) -> Result<String> {
     let (sftp, config) = sftp_and_config()?;
 
@@ -1024,13 +1652,18 @@ pub fn sync_pasted_refs(file_path: &str, pasted_text: &str) -> Result<String> {
 
     let rel = file_path.trim_start_matches('/');
     let my_prefix = format!("{}/", rel);
+    let is_bundle = bundle_dir(file_path).is_some();
 
-    let (local_refs, external_urls) = parse_all_image_refs(pasted_text);
+    let syntax = site_ref_syntax();
+    let (local_refs, external_urls) = parse_all_image_refs(pasted_text, &syntax);
     let mut updated = pasted_text.to_string();
 
     // 로컬 외부참조 → 내 디렉토리로 복사 (fragment 보존)
     for img_ref in &local_refs {
         let (ref_path, fragment) = split_image_fragment(img_ref);
+        if is_bundle && !ref_path.starts_with('/') {
+            continue;
+        }
         let ref_clean = ref_path.trim_start_matches('/');
         if ref_clean.starts_with(&my_prefix) {
             continue;
@@ -1052,7 +1685,7 @@ pub fn sync_pasted_refs(file_path: &str, pasted_text: &str) -> Result<String> {
             match copy_file_checked(&sftp, Path::new(&src_abs), Path::new(&dst_abs)) {
                 Ok(CopyResult::Renamed(new_name)) => {
                     let renamed_ref = format!("/{}{}{}", my_prefix, new_name, fragment);
-                    updated = replace_image_ref(&updated, img_ref, &renamed_ref);
+                    updated = replace_image_ref(&updated, img_ref, &renamed_ref, &syntax);
                     continue;
                 }
                 Ok(_) => {}
@@ -1060,7 +1693,7 @@ pub fn sync_pasted_refs(file_path: &str, pasted_text: &str) -> Result<String> {
             }
         }
 
-        updated = replace_image_ref(&updated, img_ref, &new_ref);
+        updated = replace_image_ref(&updated, img_ref, &new_ref, &syntax);
     }
 
     // 외부 URL → 다운로드 + 저장 (fragment 보존)
@@ -1071,7 +1704,7 @@ pub fn sync_pasted_refs(file_path: &str, pasted_text: &str) -> Result<String> {
         let dst_abs = image_abs(&config, &format!("/{}{}", my_prefix, filename));
 
         if sftp.stat(Path::new(&dst_abs)).is_ok() {
-            updated = replace_image_ref(&updated, url, &new_ref);
+            updated = replace_image_ref(&updated, url, &new_ref, &syntax);
             continue;
         }
 
@@ -1079,7 +1712,7 @@ pub fn sync_pasted_refs(file_path: &str, pasted_text: &str) -> Result<String> {
             continue;
         }
 
-        updated = replace_image_ref(&updated, url, &new_ref);
+        updated = replace_image_ref(&updated, url, &new_ref, &syntax);
     }
 
     Ok(updated)
@@ -1106,7 +1739,15 @@ where
 pub struct FileSystemNode {
     pub name:       String,
     pub type_:      NodeType,
+    /// leaf bundle 디렉토리 (index.md + 리소스). meta/publish_state는 index.md 기준
+    #[serde(default)]
+    pub bundle:     bool,
     pub is_hidden:  bool,
+    #[serde(default)]
+    pub publish_state: PublishState,
+    /// front matter 메타데이터 (.md 파일 / _index.md 또는 index.md가 있는 폴더)
+    #[serde(default)]
+    pub meta: Option<PageMeta>,
     #[typeshare(serialized_as = "Vec<FileSystemNode>")]
     #[serde(serialize_with = "serialize_values")]
     pub children:   IndexMap<String, FileSystemNode>,
@@ -1119,6 +1760,65 @@ pub enum NodeType {
     Directory,
 }
 
+/// 트리에서 제목/필터에 쓰는 페이지 메타데이터 (build_file_tree의 같은 SSH 호출에서 수집)
+#[typeshare]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PageMeta {
+    pub title: Option<String>,
+    pub date: Option<String>,
+    pub tags: Vec<String>,
+    pub draft: bool,
+    pub word_count: u32,
+}
+
+/// 버전이 붙은 파일 내용
+#[typeshare]
+#[derive(Clone, Debug, Serialize)]
+pub struct VersionedContent {
+    pub content: String,
+    pub version: String,
+}
+
+/// 저장 결과
+#[typeshare]
+#[derive(Clone, Debug, Serialize)]
+pub struct SaveOutcome {
+    /// false = 저장은 됐지만 이미지 sync 실패
+    pub synced: bool,
+    /// 저장된 내용의 버전 (다음 저장의 expected_version)
+    pub version: String,
+}
+
+/// 저장 충돌: 읽은 뒤 원격 파일이 바뀌었다. UI에서 덮어쓰기/다시 읽기/병합을 고른다.
+#[typeshare]
+#[derive(Clone, Debug, Serialize)]
+pub struct SaveConflict {
+    pub path: String,
+    /// 현재 원격 내용 (None = 원격에서 삭제됨)
+    pub remote_content: Option<String>,
+    pub remote_version: Option<String>,
+}
+
+impl std::fmt::Display for SaveConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "File was changed on the server: {}", self.path)
+    }
+}
+
+impl std::error::Error for SaveConflict {}
+
+/// front matter(draft, publishDate, expiryDate)로 계산한 Hugo 발행 상태.
+/// hidden_path 이동(is_hidden)과는 별개.
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum PublishState {
+    #[default]
+    Published,
+    Draft,
+    Scheduled,
+    Expired,
+}
+
 
 // public_list(root) 에 hidden_list 를 병합한다.
 // public 쪽의 FileSystemNode 에 추가하는 것
@@ -1141,9 +1841,13 @@ pub fn merge_tree(
                     .or_insert_with(||FileSystemNode {    
                         name,
                         type_: NodeType::Directory,
+                        bundle: hidden_child.bundle,
                         is_hidden: false,            // public에 없는 폴더라면 메인 우선이라 false 기본값
+                        publish_state: hidden_child.publish_state,
+                        meta: hidden_child.meta.clone(),
                         children: IndexMap::new(),
                     });
+                entry.bundle |= hidden_child.bundle;
                 // 하위 디렉터리 재귀 병합    
                 merge_tree(entry, hidden_child);
             }
@@ -1157,6 +1861,12 @@ pub fn merge_tree(
 }
 
 
+/// 서버 홈 아래 사이트별 데이터 디렉토리: `~/<dir_name>/<site_key>`
+pub fn site_data_dir(sftp: &Sftp, dir_name: &str, site_key: &str) -> Result<String> {
+    let home = sftp.realpath(Path::new(".")).context("Failed to resolve home directory")?;
+    Ok(format!("{}/{}/{}", home.to_string_lossy().trim_end_matches('/'), dir_name, site_key))
+}
+
 // 재귀적으로 폴더 생성
 pub fn mkdir_recursive(sftp: &Sftp, path: &Path) -> Result<()> {
     // 원격(Linux) 경로는 항상 '/'로 조립한다 — Windows에서 PathBuf로 합치면
@@ -53,15 +53,13 @@ fn validate_remote_path(path: &str) -> Result<()> {
     Ok(())
 }
 
+/// 휴지통으로 이동 (휴지통 안의 경로는 영구 삭제)
 pub fn delete_remote(paths: &[String]) -> Result<()> {
     if paths.is_empty() { return Ok(()); }
     for p in paths {
         validate_remote_path(p)?;
     }
-    let args = paths.iter().map(|p| shq(p)).collect::<Vec<_>>().join(" ");
-    let mut ch = get_channel_session()?;
-    execute_ssh_command_checked(&mut ch, &format!("rm -rf {}", args))?;
-    Ok(())
+    crate::services::trash_service::trash_remote(paths)
 }
 
 pub fn move_remote(src: &str, dst: &str) -> Result<()> {
@@ -0,0 +1,248 @@
+//! 페이지 리비전 히스토리: 수동 저장마다 gzip 스냅샷을 서버에 보관한다.
+//!
+//! 저장 위치: `~/.inn_history/<site>/<content 상대경로>/<millis>-<version>.md.gz`
+//! (content 트리를 그대로 미러링하므로 파일/폴더 이동 시 디렉토리 rename 한 번으로 따라간다)
+
+use std::io::prelude::*;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use ssh2::Sftp;
+use similar::TextDiff;
+use time::OffsetDateTime;
+use typeshare::typeshare;
+
+use crate::services::file_service::{self, content_version, mkdir_recursive, site_data_dir, SaveOutcome};
+use crate::services::ssh_service::get_sftp_session;
+use crate::services::config_service::get_hugo_config;
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::front_matter;
+
+const HISTORY_DIR: &str = ".inn_history";
+const REVISION_EXT: &str = ".md.gz";
+
+// ── 보존 정책 ──
+/// 페이지당 최대 리비전 수
+const KEEP_MAX: usize = 50;
+/// 이보다 오래된 리비전은 삭제 (KEEP_MIN개는 나이와 상관없이 보존)
+const KEEP_DAYS: i64 = 90;
+const KEEP_MIN: usize = 5;
+
+/// 리비전 하나 (id = 파일명에서 확장자를 뺀 `<millis>-<version>`)
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct Revision {
+    pub id: String,
+    /// 저장 시각 (RFC3339, UTC)
+    pub created_at: String,
+    /// 내용 버전 (read_content_versioned의 version과 같은 규칙)
+    pub version: String,
+}
+
+struct RevisionFile {
+    millis: i64,
+    version: String,
+    name: String,
+}
+
+impl RevisionFile {
+    fn parse(name: &str) -> Option<Self> {
+        let stem = name.strip_suffix(REVISION_EXT)?;
+        let (millis, version) = stem.split_once('-')?;
+        Some(Self {
+            millis: millis.parse().ok()?,
+            version: version.to_string(),
+            name: name.to_string(),
+        })
+    }
+
+    fn id(&self) -> &str {
+        self.name.strip_suffix(REVISION_EXT).unwrap_or(&self.name)
+    }
+}
+
+/// 페이지(또는 폴더)의 히스토리 디렉토리. content_abs로 `..` 등을 정리한 상대경로를 쓴다.
+fn history_dir(sftp: &Sftp, hugo: &HugoConfig, rel: &str) -> Result<String> {
+    let content_base = hugo.content_abs("");
+    let abs = hugo.content_abs(rel);
+    let clean = abs.strip_prefix(&content_base).unwrap_or("");
+    if clean.is_empty() {
+        bail!("Invalid path: {}", rel);
+    }
+    if hugo.site_key().is_empty() {
+        bail!("Hugo base path is not set");
+    }
+    Ok(format!("{}{}", site_data_dir(sftp, HISTORY_DIR, &hugo.site_key())?, clean))
+}
+
+/// 리비전 파일 목록 (오래된 순)
+fn list_files(sftp: &Sftp, dir: &str) -> Vec<RevisionFile> {
+    let mut files: Vec<RevisionFile> = sftp
+        .readdir(Path::new(dir))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(_, stat)| stat.is_file())
+        .filter_map(|(p, _)| RevisionFile::parse(&p.file_name()?.to_string_lossy()))
+        .collect();
+    files.sort_by(|a, b| a.millis.cmp(&b.millis).then_with(|| a.name.cmp(&b.name)));
+    files
+}
+
+fn now_millis() -> i64 {
+    (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+fn write_revision(sftp: &Sftp, dir: &str, millis: i64, content: &str) -> Result<()> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(content.as_bytes())?;
+    let compressed = encoder.finish()?;
+    let path = format!("{}/{}-{}{}", dir, millis, content_version(content), REVISION_EXT);
+    let mut file = sftp.create(Path::new(&path))?;
+    file.write_all(&compressed)?;
+    Ok(())
+}
+
+fn read_revision(sftp: &Sftp, dir: &str, id: &str) -> Result<String> {
+    if id.contains('/') || id.contains("..") {
+        bail!("Invalid revision id: {}", id);
+    }
+    let path = format!("{}/{}{}", dir, id, REVISION_EXT);
+    let file = sftp.open(Path::new(&path)).with_context(|| format!("Revision not found: {}", id))?;
+    let mut content = String::new();
+    GzDecoder::new(file).read_to_string(&mut content)?;
+    Ok(content)
+}
+
+/// 보존 정책 적용: 최신 KEEP_MAX개 + KEEP_DAYS 이내만 남긴다 (최신 KEEP_MIN개는 항상 보존)
+fn prune(sftp: &Sftp, dir: &str) {
+    let files = list_files(sftp, dir);
+    let cutoff = now_millis() - KEEP_DAYS * 24 * 60 * 60 * 1000;
+    let count = files.len();
+    for (i, f) in files.iter().enumerate() {
+        let rank_from_newest = count - i; // 1 = 최신
+        if rank_from_newest <= KEEP_MIN {
+            continue;
+        }
+        if rank_from_newest > KEEP_MAX || f.millis < cutoff {
+            let _ = sftp.unlink(Path::new(&format!("{}/{}", dir, f.name)));
+        }
+    }
+}
+
+/// 수동 저장 시 스냅샷 기록.
+/// 덮어쓰이는 원격 내용(previous)이 마지막 리비전과 다르면(자동 저장/외부 수정분) 그것도 먼저 남긴다.
+pub fn record_save(sftp: &Sftp, hugo: &HugoConfig, rel: &str, previous: Option<&str>, new: &str) -> Result<()> {
+    let dir = history_dir(sftp, hugo, rel)?;
+    mkdir_recursive(sftp, Path::new(&dir))?;
+
+    let mut last_version = list_files(sftp, &dir).pop().map(|f| f.version);
+    let mut millis = now_millis();
+    if let Some(prev) = previous {
+        let prev_version = content_version(prev);
+        if last_version.as_deref() != Some(prev_version.as_str()) {
+            write_revision(sftp, &dir, millis, prev)?;
+            last_version = Some(prev_version);
+            millis += 1;
+        }
+    }
+    if last_version.as_deref() != Some(content_version(new).as_str()) {
+        write_revision(sftp, &dir, millis, new)?;
+    }
+
+    prune(sftp, &dir);
+    Ok(())
+}
+
+/// 페이지 이동/이름 변경 시 히스토리도 함께 이동. 대상에 기존 히스토리가 있으면 병합한다.
+pub fn move_history(sftp: &Sftp, hugo: &HugoConfig, src: &str, dst: &str) -> Result<()> {
+    let src_dir = history_dir(sftp, hugo, src)?;
+    let dst_dir = history_dir(sftp, hugo, dst)?;
+    if sftp.stat(Path::new(&src_dir)).is_err() {
+        return Ok(());
+    }
+    merge_dir(sftp, &src_dir, &dst_dir)
+}
+
+fn merge_dir(sftp: &Sftp, src: &str, dst: &str) -> Result<()> {
+    if sftp.stat(Path::new(dst)).is_err() {
+        return file_service::move_file(sftp, Path::new(src), Path::new(dst));
+    }
+    for (child, stat) in sftp.readdir(Path::new(src))? {
+        let Some(name) = child.file_name().map(|n| n.to_string_lossy().to_string()) else { continue };
+        let child_src = format!("{}/{}", src, name);
+        let child_dst = format!("{}/{}", dst, name);
+        if stat.is_dir() {
+            merge_dir(sftp, &child_src, &child_dst)?;
+        } else if sftp.stat(Path::new(&child_dst)).is_err() {
+            sftp.rename(Path::new(&child_src), Path::new(&child_dst), None)?;
+        }
+    }
+    // 이름이 겹친 리비전(같은 millis+version)은 동일 내용이므로 버린다
+    for f in list_files(sftp, src) {
+        let _ = sftp.unlink(Path::new(&format!("{}/{}", src, f.name)));
+    }
+    sftp.rmdir(Path::new(src)).ok();
+    prune_tree(sftp, dst);
+    Ok(())
+}
+
+/// 병합으로 KEEP_MAX를 넘었을 수 있으므로 대상 트리 전체에 보존 정책 재적용
+fn prune_tree(sftp: &Sftp, dir: &str) {
+    prune(sftp, dir);
+    // readdir의 PathBuf는 Windows에서 '\'로 join되므로 파일명만 취해 '/'로 다시 조립
+    for (child, stat) in sftp.readdir(Path::new(dir)).unwrap_or_default() {
+        if let (true, Some(name)) = (stat.is_dir(), child.file_name()) {
+            prune_tree(sftp, &format!("{}/{}", dir, name.to_string_lossy()));
+        }
+    }
+}
+
+/// 리비전 목록 (최신순)
+pub fn list_revisions(file_path: &str) -> Result<Vec<Revision>> {
+    let sftp = get_sftp_session()?;
+    let hugo = get_hugo_config()?;
+    let dir = history_dir(&sftp, &hugo, file_path)?;
+    Ok(list_files(&sftp, &dir)
+        .into_iter()
+        .rev()
+        .map(|f| Revision {
+            id: f.id().to_string(),
+            created_at: front_matter::format_rfc3339(f.millis / 1000),
+            version: f.version.clone(),
+        })
+        .collect())
+}
+
+/// 두 리비전의 unified diff. to_id가 None이면 현재 파일 내용과 비교.
+pub fn diff_revisions(file_path: &str, from_id: &str, to_id: Option<&str>) -> Result<String> {
+    let sftp = get_sftp_session()?;
+    let hugo = get_hugo_config()?;
+    let dir = history_dir(&sftp, &hugo, file_path)?;
+
+    let old = read_revision(&sftp, &dir, from_id)?;
+    let (new, new_label) = match to_id {
+        Some(id) => (read_revision(&sftp, &dir, id)?, id.to_string()),
+        None => (file_service::read_content(file_path)?, "current".to_string()),
+    };
+
+    Ok(TextDiff::from_lines(&old, &new)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("{} ({})", file_path, from_id), &format!("{} ({})", file_path, new_label))
+        .to_string())
+}
+
+/// 리비전 복원: 수동 저장과 같은 경로로 기록하므로 복원 직전 내용도 히스토리에 남는다
+pub fn restore_revision(file_path: &str, revision_id: &str) -> Result<SaveOutcome> {
+    let content = {
+        let sftp = get_sftp_session()?;
+        let hugo = get_hugo_config()?;
+        let dir = history_dir(&sftp, &hugo, file_path)?;
+        read_revision(&sftp, &dir, revision_id)?
+    };
+    file_service::write_content(file_path, &content, true, None)
+}
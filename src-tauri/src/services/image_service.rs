@@ -0,0 +1,653 @@
+//! 이미지 관리: 업로드 처리 파이프라인, 고아 이미지 정리, 이미지 라이브러리(목록 + 로컬 썸네일 캐시).
+//!
+//! 고아 이미지 = image_path 아래에 있지만 어떤 페이지(content + hidden)에서도 참조하지 않는 파일.
+//! 페이지 첨부파일(pdf, zip 등)도 같은 디렉토리에 저장되므로 함께 다룬다.
+//! 참조 추출은 저장 시 동기화와 같은 `parse_all_image_refs`를 쓴다.
+//! 새 형식 참조("/posts/a.md/x.png")는 find_image_dir의 legacy 위치("/a.md/x.png")도 참조된 것으로 본다.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use base64::prelude::*;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::imageops::FilterType;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, Rgb, RgbImage};
+use img_parts::{Bytes, DynImage, ImageEXIF};
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::services::config_service::{get_app_config, get_hugo_config};
+use crate::services::file_service::{
+    find_image_dir, image_abs, parse_all_image_refs, read_file_bytes, site_ref_syntax, split_image_fragment,
+    strip_section_prefix,
+};
+use crate::services::link_service::{fetch_markdown, is_external, normalize_path};
+use crate::services::ssh_service::{
+    execute_ssh_command, execute_ssh_command_checked, get_channel_session, get_sftp_session, SftpHandle,
+};
+use crate::services::trash_service;
+use crate::types::config::cms_config::{HugoConfig, ImageOutputFormat, ImagePolicy};
+use crate::utils::shell::quote as shq;
+
+/// SSH 호출 하나에 담는 삭제 파일 수
+const RM_BATCH: usize = 200;
+/// 다시 해시할 파일이 이보다 많으면 경로를 나열하지 않고 범위 전체를 해시한다
+const HASH_LIST_MAX: usize = 200;
+
+// ── 업로드 처리 ──
+
+/// 정책을 적용한 최종 이미지
+pub struct ProcessedImage {
+    pub data: Vec<u8>,
+    /// 실제 형식의 확장자 ("jpg", "png", "webp" …). 형식을 알 수 없으면(SVG 등) None — 원래 이름 유지
+    pub ext: Option<&'static str>,
+}
+
+fn format_ext(format: ImageFormat) -> Option<&'static str> {
+    format.extensions_str().first().copied()
+}
+
+/// 애니메이션 PNG/WebP는 디코딩하면 첫 프레임만 남으므로 재인코딩하지 않는다
+fn is_animated(data: &[u8], format: ImageFormat) -> bool {
+    match format {
+        ImageFormat::Png => PngDecoder::new(Cursor::new(data)).and_then(|d| d.is_apng()).unwrap_or(false),
+        ImageFormat::WebP => WebPDecoder::new(Cursor::new(data)).map(|d| d.has_animation()).unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// 업로드된 바이트에 사이트 이미지 정책 적용: 실제 형식 판별, 회전 보정, 축소, 형식 변환, 메타데이터 제거.
+/// 재인코딩이 필요 없으면 픽셀은 그대로 두고 메타데이터만 무손실로 제거한다.
+/// 형식을 판별할 수 없는 파일(SVG 등)은 그대로 저장한다.
+pub fn process_upload(data: Vec<u8>, policy: &ImagePolicy) -> Result<ProcessedImage> {
+    let Ok(format) = image::guess_format(&data) else {
+        return Ok(ProcessedImage { data, ext: None });
+    };
+    // GIF/AVIF/ICO 등과 애니메이션은 그대로 저장 (확장자만 바로잡음)
+    if !matches!(format, ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP) || is_animated(&data, format) {
+        return Ok(ProcessedImage { data, ext: format_ext(format) });
+    }
+
+    if let Some(processed) = reencode_if_needed(&data, format, policy)? {
+        return Ok(processed);
+    }
+    let data = if policy.strip_metadata { strip_metadata(data)? } else { data };
+    Ok(ProcessedImage { data, ext: format_ext(format) })
+}
+
+/// 형식 변환/축소/회전이 필요하면 디코딩 후 재인코딩. 필요 없으면 None.
+fn reencode_if_needed(data: &[u8], format: ImageFormat, policy: &ImagePolicy) -> Result<Option<ProcessedImage>> {
+    let mut decoder = ImageReader::with_format(Cursor::new(data), format).into_decoder()?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let (mut width, mut height) = decoder.dimensions();
+    if matches!(
+        orientation,
+        Orientation::Rotate90 | Orientation::Rotate270 | Orientation::Rotate90FlipH | Orientation::Rotate270FlipH
+    ) {
+        std::mem::swap(&mut width, &mut height);
+    }
+
+    let target = match policy.format {
+        ImageOutputFormat::Keep => format,
+        ImageOutputFormat::Webp => ImageFormat::WebP,
+        ImageOutputFormat::Jpeg => ImageFormat::Jpeg,
+    };
+    let too_large = (policy.max_width > 0 && width > policy.max_width)
+        || (policy.max_height > 0 && height > policy.max_height);
+    // 메타데이터를 지우면 Orientation 태그도 사라지므로 그 경우에도 픽셀에 반영한다
+    let rotate = orientation != Orientation::NoTransforms && (policy.auto_orient || policy.strip_metadata);
+    if target == format && !too_large && !rotate {
+        return Ok(None);
+    }
+
+    let mut img = DynamicImage::from_decoder(decoder)?;
+    // 재인코딩하면 EXIF가 빠지므로 auto_orient와 상관없이 회전을 반영한다
+    img.apply_orientation(orientation);
+    if too_large {
+        let max_w = if policy.max_width > 0 { policy.max_width } else { u32::MAX };
+        let max_h = if policy.max_height > 0 { policy.max_height } else { u32::MAX };
+        img = img.resize(max_w, max_h, FilterType::Lanczos3);
+    }
+    Ok(Some(ProcessedImage { data: encode(&img, target, policy.quality)?, ext: format_ext(target) }))
+}
+
+fn encode(img: &DynamicImage, format: ImageFormat, quality: u8) -> Result<Vec<u8>> {
+    let mut out = Cursor::new(Vec::new());
+    match format {
+        ImageFormat::Jpeg => {
+            let rgb = DynamicImage::ImageRgb8(flatten_on_white(img));
+            rgb.write_with_encoder(JpegEncoder::new_with_quality(&mut out, quality.clamp(1, 100)))?;
+        }
+        ImageFormat::WebP => {
+            // image 크레이트의 WebP 인코더는 무손실만 지원하므로 libwebp로 손실 압축
+            let quality = f32::from(quality.clamp(1, 100));
+            let encoded = if img.color().has_alpha() {
+                let rgba = img.to_rgba8();
+                webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height()).encode(quality)
+            } else {
+                let rgb = img.to_rgb8();
+                webp::Encoder::from_rgb(&rgb, rgb.width(), rgb.height()).encode(quality)
+            };
+            return Ok(encoded.to_vec());
+        }
+        _ => img.write_to(&mut out, format)?,
+    }
+    Ok(out.into_inner())
+}
+
+/// JPEG에는 알파가 없으므로 투명 영역을 흰 배경에 합성
+fn flatten_on_white(img: &DynamicImage) -> RgbImage {
+    if !img.color().has_alpha() {
+        return img.to_rgb8();
+    }
+    let rgba = img.to_rgba8();
+    RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let p = rgba.get_pixel(x, y);
+        let a = u32::from(p[3]);
+        Rgb([0, 1, 2].map(|i| ((u32::from(p[i]) * a + 255 * (255 - a)) / 255) as u8))
+    })
+}
+
+/// 재인코딩 없이 EXIF(GPS 포함)와 JPEG의 XMP(APP1)를 제거
+fn strip_metadata(data: Vec<u8>) -> Result<Vec<u8>> {
+    let Some(mut img) = DynImage::from_bytes(Bytes::from(data.clone())).context("Failed to parse image metadata")? else {
+        return Ok(data);
+    };
+    match &mut img {
+        DynImage::Jpeg(jpeg) => jpeg.remove_segments_by_marker(img_parts::jpeg::markers::APP1),
+        other => other.set_exif(None),
+    }
+    Ok(img.encoder().bytes().to_vec())
+}
+
+// ── 고아 이미지 ──
+
+/// 고아 이미지 한 건
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct OrphanImage {
+    /// image_path 기준 경로 ("/posts/a.md/x.png")
+    pub path: String,
+    pub size: u64,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum OrphanAction {
+    /// 영구 삭제
+    Delete,
+    /// 휴지통 항목 하나로 묶어 이동
+    Trash,
+}
+
+/// 고아 이미지 목록 / 정리 결과
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct OrphanReport {
+    pub images: Vec<OrphanImage>,
+    pub total_size: u64,
+    /// true면 아무것도 지우지 않은 미리보기
+    pub dry_run: bool,
+    /// Trash로 정리했을 때 생성된 휴지통 항목
+    pub trash_id: Option<String>,
+}
+
+fn image_root(hugo: &HugoConfig) -> String {
+    format!("{}/{}", hugo.base_path, hugo.image_path.trim_matches('/'))
+}
+
+/// image_path 아래 모든 파일과 크기
+fn list_images_with_size(hugo: &HugoConfig) -> Result<Vec<OrphanImage>> {
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(
+        &mut channel,
+        &format!("cd {} 2>/dev/null && find . -type f -printf '%s %p\\n'; true", shq(&image_root(hugo))),
+    )?;
+    Ok(output
+        .lines()
+        .filter_map(|line| {
+            let (size, path) = line.split_once(' ')?;
+            Some(OrphanImage {
+                path: path.strip_prefix('.')?.to_string(),
+                size: size.parse().ok()?,
+            })
+        })
+        .collect())
+}
+
+/// 이미지 경로 → 참조하는 페이지들 (content 상대경로). 새 형식 참조는 legacy 위치로도 등록한다.
+fn image_references(hugo: &HugoConfig) -> Result<HashMap<String, Vec<String>>> {
+    let (pages, files) = fetch_markdown(hugo, None, &[])?;
+    // content 목록이 비어 있으면 설정 오류일 가능성이 높다 — 전부 고아로 판정하지 않도록 중단
+    if pages.files.is_empty() {
+        bail!("No content files found");
+    }
+    let syntax = site_ref_syntax();
+    let mut refs: HashMap<String, Vec<String>> = HashMap::new();
+    for file in &files {
+        let (local_refs, _) = parse_all_image_refs(&file.content, &syntax);
+        for img_ref in &local_refs {
+            let (path, _) = split_image_fragment(img_ref);
+            if path.is_empty() || is_external(path) {
+                continue;
+            }
+            let normalized = normalize_path("/", path);
+            let legacy = strip_section_prefix(hugo, &normalized).map(|l| format!("/{}", l));
+            for key in std::iter::once(normalized).chain(legacy) {
+                let pages = refs.entry(key).or_default();
+                if !pages.contains(&file.rel) {
+                    pages.push(file.rel.clone());
+                }
+            }
+        }
+    }
+    Ok(refs)
+}
+
+fn scan_orphans(hugo: &HugoConfig) -> Result<Vec<OrphanImage>> {
+    let referenced = image_references(hugo)?;
+    let mut orphans: Vec<OrphanImage> = list_images_with_size(hugo)?
+        .into_iter()
+        .filter(|img| !referenced.contains_key(&img.path))
+        .collect();
+    orphans.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(orphans)
+}
+
+fn report(images: Vec<OrphanImage>, dry_run: bool, trash_id: Option<String>) -> OrphanReport {
+    let total_size = images.iter().map(|i| i.size).sum();
+    OrphanReport { images, total_size, dry_run, trash_id }
+}
+
+/// 고아 이미지 탐지 (아무것도 변경하지 않음)
+pub fn find_orphan_images() -> Result<OrphanReport> {
+    let hugo = get_hugo_config()?;
+    if hugo.is_empty() {
+        bail!("Hugo config is not set");
+    }
+    Ok(report(scan_orphans(&hugo)?, true, None))
+}
+
+/// 고아 이미지 정리. 실행 시점에 다시 스캔해서 여전히 고아인 것만 처리한다
+/// (미리보기 이후 새로 참조된 이미지는 건너뜀). dry_run이면 처리 대상만 반환.
+pub fn clean_orphan_images(paths: &[String], action: OrphanAction, dry_run: bool) -> Result<OrphanReport> {
+    let hugo = get_hugo_config()?;
+    if hugo.is_empty() {
+        bail!("Hugo config is not set");
+    }
+    let requested: HashSet<&str> = paths.iter().map(String::as_str).collect();
+    let targets: Vec<OrphanImage> = scan_orphans(&hugo)?
+        .into_iter()
+        .filter(|img| requested.contains(img.path.as_str()))
+        .collect();
+    if dry_run || targets.is_empty() {
+        return Ok(report(targets, dry_run, None));
+    }
+
+    let root = image_root(&hugo);
+    let rels: Vec<String> = targets.iter().map(|img| img.path.clone()).collect();
+    let trash_id = match action {
+        OrphanAction::Trash => {
+            let sftp = get_sftp_session()?;
+            Some(trash_service::trash_images(&sftp, &hugo, &root, &rels)?.id)
+        }
+        OrphanAction::Delete => {
+            for chunk in rels.chunks(RM_BATCH) {
+                let args: Vec<String> = chunk.iter().map(|rel| shq(&format!(".{}", rel))).collect();
+                let mut channel = get_channel_session()?;
+                execute_ssh_command_checked(&mut channel, &format!("cd {} && rm -f -- {}", shq(&root), args.join(" ")))?;
+            }
+            None
+        }
+    };
+    remove_empty_dirs(&root, &rels);
+    Ok(report(targets, false, trash_id))
+}
+
+/// 비워진 이미지 디렉토리 정리 (image_path 자체는 남긴다, best-effort)
+fn remove_empty_dirs(root: &str, rels: &[String]) {
+    let dirs: HashSet<String> = rels
+        .iter()
+        .filter_map(|rel| rel.rsplit_once('/').map(|(dir, _)| dir.trim_start_matches('/').to_string()))
+        .filter(|dir| !dir.is_empty())
+        .collect();
+    if dirs.is_empty() {
+        return;
+    }
+    let args: Vec<String> = dirs.iter().map(|d| shq(d)).collect();
+    if let Ok(mut channel) = get_channel_session() {
+        // 상대경로라 rmdir -p가 image_path 위로 올라가지 않는다
+        let _ = execute_ssh_command(
+            &mut channel,
+            &format!("cd {} && rmdir -p --ignore-fail-on-non-empty -- {} 2>/dev/null; true", shq(root), args.join(" ")),
+        );
+    }
+}
+
+// ── 이미지 라이브러리 ──
+
+/// 썸네일 최대 가로/세로 (px)
+const THUMB_SIZE: u32 = 256;
+const THUMB_QUALITY: u8 = 80;
+/// 크기를 읽을 때 한 번에 받는 양 / 최대 (JPEG는 EXIF 뒤에 SOF가 있어 더 받아야 할 수 있다)
+const HEADER_CHUNK: u64 = 16 * 1024;
+const HEADER_MAX: usize = 512 * 1024;
+
+/// image_path 아래 이미지 한 건
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct LibraryImage {
+    /// image_path 기준 경로 ("/posts/a.md/x.png")
+    pub path: String,
+    pub size: u64,
+    /// 내용 sha256 (썸네일 캐시 키)
+    pub hash: String,
+    /// 원본 크기 (회전 반영). 헤더를 읽을 수 없는 파일(SVG 등)은 None
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// 이 이미지를 참조하는 페이지 (content 상대경로)
+    pub referenced_by: Vec<String>,
+}
+
+/// 썸네일 요청 (list_images 결과의 path/hash)
+#[typeshare]
+#[derive(Deserialize, Debug, Clone)]
+pub struct ThumbnailRequest {
+    pub path: String,
+    pub hash: String,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ImageThumbnail {
+    pub path: String,
+    pub hash: String,
+    /// 원본 크기 (회전 반영)
+    pub width: u32,
+    pub height: u32,
+    /// "data:image/jpeg;base64,…"
+    pub data_url: String,
+}
+
+/// 썸네일 캐시 메타 (`<hash>.json`, 썸네일 바이트는 `<hash>.img`)
+#[derive(Serialize, Deserialize)]
+struct ThumbMeta {
+    width: u32,
+    height: u32,
+    mime: String,
+}
+
+/// 로컬 썸네일 캐시: `<OS 캐시 디렉토리>/im-not-notion/thumbnails`. 내용 해시가 키라 서버/사이트와 무관하게 공유된다.
+fn thumb_cache_dir() -> Result<PathBuf> {
+    let base = dirs_next::cache_dir()
+        .or_else(|| dirs_next::home_dir().map(|h| h.join(".cache")))
+        .context("Failed to determine cache directory")?;
+    Ok(base.join("im-not-notion").join("thumbnails"))
+}
+
+fn is_valid_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+pub fn is_image_file(path: &str) -> bool {
+    let ext = path.rsplit_once('.').map(|(_, e)| e.to_ascii_lowercase()).unwrap_or_default();
+    ext == "svg" || ImageFormat::from_extension(&ext).is_some()
+}
+
+/// 이미지 헤더의 크기 (회전 반영). 헤더가 덜 받아졌으면 None
+fn header_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let mut decoder = ImageReader::new(Cursor::new(data)).with_guessed_format().ok()?.into_decoder().ok()?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let (width, height) = decoder.dimensions();
+    let rotated = matches!(
+        orientation,
+        Orientation::Rotate90 | Orientation::Rotate270 | Orientation::Rotate90FlipH | Orientation::Rotate270FlipH
+    );
+    Some(if rotated { (height, width) } else { (width, height) })
+}
+
+/// 원격 이미지의 앞부분만 받아 크기를 구한다
+fn read_remote_dimensions(sftp: &ssh2::Sftp, abs: &str) -> Option<(u32, u32)> {
+    let mut file = sftp.open(Path::new(abs)).ok()?;
+    let mut buf = Vec::new();
+    loop {
+        let n = (&mut file).take(HEADER_CHUNK).read_to_end(&mut buf).ok()?;
+        if let Some(dims) = header_dimensions(&buf) {
+            return Some(dims);
+        }
+        if n == 0 || buf.len() >= HEADER_MAX {
+            return None;
+        }
+    }
+}
+
+/// 크기 캐시 (`<hash>.dim`, "가로 세로") — 썸네일이 없어도 목록에 크기를 채운다
+fn read_cached_dimensions(dir: &Path, hash: &str) -> Option<(u32, u32)> {
+    if let Some(meta) = read_thumb_meta(dir, hash) {
+        return Some((meta.width, meta.height));
+    }
+    let text = fs::read_to_string(dir.join(format!("{}.dim", hash))).ok()?;
+    let (w, h) = text.trim().split_once(' ')?;
+    Some((w.parse().ok()?, h.parse().ok()?))
+}
+
+fn read_thumb_meta(dir: &Path, hash: &str) -> Option<ThumbMeta> {
+    let json = fs::read_to_string(dir.join(format!("{}.json", hash))).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// 원격 이미지 해시 캐시 항목. 크기와 mtime이 그대로면 다시 해시하지 않는다
+#[derive(Serialize, Deserialize, Clone)]
+struct HashEntry {
+    size: u64,
+    mtime: String,
+    hash: String,
+}
+
+/// 해시 캐시 파일: 썸네일 캐시 디렉토리의 `hashes_<서버 id>_<site_key>.json` (키: image_path 기준 경로)
+fn hash_cache_path(hugo: &HugoConfig) -> Result<PathBuf> {
+    let key = format!("{}_{}", get_app_config()?.active_server, hugo.site_key());
+    let key: String = key.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' }).collect();
+    Ok(thumb_cache_dir()?.join(format!("hashes_{}.json", key)))
+}
+
+fn load_hash_cache(path: &Path) -> HashMap<String, HashEntry> {
+    fs::read(path).ok().and_then(|bytes| serde_json::from_slice(&bytes).ok()).unwrap_or_default()
+}
+
+/// `sha256sum` 출력 → 경로(`./…`) → 해시
+fn parse_sha256sum(output: &str) -> HashMap<&str, &str> {
+    output
+        .lines()
+        // sha256sum은 특수문자가 있는 파일명 앞에 '\'를 붙인다
+        .filter_map(|line| line.trim_start_matches('\\').split_once("  "))
+        .map(|(hash, path)| (path, hash))
+        .collect()
+}
+
+/// 이미지 목록. page가 있으면 그 페이지의 이미지 디렉토리(legacy 위치 포함)만, 없으면 사이트 전체.
+pub fn list_images(page: Option<&str>) -> Result<Vec<LibraryImage>> {
+    let hugo = get_hugo_config()?;
+    if hugo.is_empty() {
+        bail!("Hugo config is not set");
+    }
+    let root = image_root(&hugo);
+    let scope = match page {
+        Some(page) => {
+            let sftp = get_sftp_session()?;
+            let Some((dir, _)) = find_image_dir(&sftp, &hugo, page) else { return Ok(Vec::new()) };
+            let rel = dir.strip_prefix(&root).context("Image dir is outside image_path")?;
+            format!("./{}", rel.trim_matches('/'))
+        }
+        None => ".".to_string(),
+    };
+
+    // 크기/mtime 목록만 받고, 해시는 캐시와 달라진 파일만 다시 계산한다
+    let output = execute_ssh_command(
+        &mut get_channel_session()?,
+        &format!(
+            "cd {} 2>/dev/null || exit 0; find {} -type f -printf '%s %T@ %p\\n'",
+            shq(&root),
+            shq(&scope),
+        ),
+    )?;
+    let listing: Vec<(u64, &str, &str)> = output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ' ');
+            let size = parts.next()?.parse().ok()?;
+            let mtime = parts.next()?;
+            let dot_path = parts.next()?;
+            is_image_file(dot_path).then_some((size, mtime, dot_path))
+        })
+        .collect();
+
+    let cache_path = hash_cache_path(&hugo).ok();
+    let mut cache = cache_path.as_deref().map(load_hash_cache).unwrap_or_default();
+    let stale: Vec<&str> = listing
+        .iter()
+        .filter(|(size, mtime, dot_path)| {
+            !cache
+                .get(dot_path.trim_start_matches('.'))
+                .is_some_and(|e| e.size == *size && e.mtime == *mtime)
+        })
+        .map(|(_, _, dot_path)| *dot_path)
+        .collect();
+    if !stale.is_empty() {
+        let targets = if stale.len() > HASH_LIST_MAX {
+            format!("find {} -type f -print0", shq(&scope))
+        } else {
+            format!("printf '%s\\0' {}", stale.iter().map(|p| shq(p)).collect::<Vec<_>>().join(" "))
+        };
+        let hashes = execute_ssh_command(
+            &mut get_channel_session()?,
+            &format!("cd {} 2>/dev/null || exit 0; {} | xargs -0 -r sha256sum", shq(&root), targets),
+        )?;
+        let hash_by_path = parse_sha256sum(&hashes);
+        for (size, mtime, dot_path) in &listing {
+            if let Some(hash) = hash_by_path.get(dot_path) {
+                let entry = HashEntry { size: *size, mtime: mtime.to_string(), hash: hash.to_string() };
+                cache.insert(dot_path.trim_start_matches('.').to_string(), entry);
+            }
+        }
+    }
+    // 범위 안에서 사라진 파일은 캐시에서도 뺀다
+    let prefix = format!("{}/", scope.trim_start_matches('.'));
+    let listed: HashSet<&str> = listing.iter().map(|(_, _, p)| p.trim_start_matches('.')).collect();
+    cache.retain(|path, _| !path.starts_with(&prefix) || listed.contains(path.as_str()));
+    if let Some(path) = &cache_path {
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        if let Ok(json) = serde_json::to_vec(&cache) {
+            let _ = fs::write(path, json);
+        }
+    }
+
+    let references = image_references(&hugo).unwrap_or_default();
+    let cache_dir = thumb_cache_dir().ok();
+    if let Some(dir) = &cache_dir {
+        let _ = fs::create_dir_all(dir);
+    }
+    let mut sftp: Option<SftpHandle> = None;
+    let mut images: Vec<LibraryImage> = listing
+        .iter()
+        .filter_map(|&(size, _, dot_path)| {
+            let path = dot_path.strip_prefix('.')?;
+            let hash = cache.get(path)?.hash.clone();
+            let mut dims = cache_dir.as_deref().and_then(|d| read_cached_dimensions(d, &hash));
+            if dims.is_none() && !path.to_ascii_lowercase().ends_with(".svg") {
+                if sftp.is_none() {
+                    sftp = get_sftp_session().ok();
+                }
+                dims = sftp.as_ref().and_then(|s| read_remote_dimensions(s, &format!("{}{}", root, path)));
+                if let (Some((w, h)), Some(dir)) = (dims, &cache_dir) {
+                    let _ = fs::write(dir.join(format!("{}.dim", hash)), format!("{} {}", w, h));
+                }
+            }
+            Some(LibraryImage {
+                path: path.to_string(),
+                size,
+                width: dims.map(|d| d.0),
+                height: dims.map(|d| d.1),
+                referenced_by: references.get(path).cloned().unwrap_or_default(),
+                hash,
+            })
+        })
+        .collect();
+    images.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(images)
+}
+
+/// 썸네일 생성: 회전 반영 후 THUMB_SIZE 안으로 축소. 알파가 있으면 PNG, 없으면 JPEG.
+fn make_thumbnail(data: &[u8]) -> Result<(ThumbMeta, Vec<u8>)> {
+    let mut decoder = ImageReader::new(Cursor::new(data)).with_guessed_format()?.into_decoder()?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut img = DynamicImage::from_decoder(decoder)?;
+    img.apply_orientation(orientation);
+    let (width, height) = (img.width(), img.height());
+    let thumb = img.thumbnail(THUMB_SIZE, THUMB_SIZE);
+    let (format, mime) = if thumb.color().has_alpha() {
+        (ImageFormat::Png, "image/png")
+    } else {
+        (ImageFormat::Jpeg, "image/jpeg")
+    };
+    let bytes = encode(&thumb, format, THUMB_QUALITY)?;
+    Ok((ThumbMeta { width, height, mime: mime.to_string() }, bytes))
+}
+
+/// 썸네일 일괄 조회. 캐시에 없으면 원본을 SFTP로 받아 생성 후 캐시한다.
+/// 디코딩할 수 없는 이미지(SVG 등)는 결과에서 빠진다.
+pub fn get_image_thumbnails(items: &[ThumbnailRequest]) -> Result<Vec<ImageThumbnail>> {
+    let dir = thumb_cache_dir()?;
+    fs::create_dir_all(&dir)?;
+    let mut remote: Option<(SftpHandle, HugoConfig)> = None;
+    let mut out = Vec::new();
+
+    for item in items {
+        if !is_valid_hash(&item.hash) || item.path.split('/').any(|c| c == "..") {
+            continue;
+        }
+        let bytes_path = dir.join(format!("{}.img", item.hash));
+        let cached = read_thumb_meta(&dir, &item.hash).zip(fs::read(&bytes_path).ok());
+        let (meta, bytes) = match cached {
+            Some(hit) => hit,
+            None => {
+                if remote.is_none() {
+                    remote = Some((get_sftp_session()?, get_hugo_config()?));
+                }
+                let Some((sftp, hugo)) = remote.as_ref() else { continue };
+                let generated = read_file_bytes(sftp, Path::new(&image_abs(hugo, &item.path)))
+                    .and_then(|data| make_thumbnail(&data));
+                match generated {
+                    Ok((meta, bytes)) => {
+                        // 캐시 쓰기 실패는 다음에 다시 만들면 되므로 무시
+                        let _ = fs::write(&bytes_path, &bytes);
+                        if let Ok(json) = serde_json::to_string(&meta) {
+                            let _ = fs::write(dir.join(format!("{}.json", item.hash)), json);
+                        }
+                        (meta, bytes)
+                    }
+                    Err(e) => {
+                        eprintln!("thumbnail failed for {}: {:#}", item.path, e);
+                        continue;
+                    }
+                }
+            }
+        };
+        out.push(ImageThumbnail {
+            path: item.path.clone(),
+            hash: item.hash.clone(),
+            width: meta.width,
+            height: meta.height,
+            data_url: format!("data:{};base64,{}", meta.mime, BASE64_STANDARD.encode(&bytes)),
+        });
+    }
+    Ok(out)
+}
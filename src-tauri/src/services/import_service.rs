@@ -0,0 +1,1531 @@
+//! 다른 도구에서 내보낸 글 가져오기: Notion(Markdown & CSV export zip), WordPress(WXR XML), Jekyll(`_posts`).
+//!
+//! 세 형식 모두 먼저 `Doc` 목록(front matter 포함 내용 + 첨부 + 가져온 페이지끼리의 링크)으로 바꾼 뒤 같은 방식으로 쓴다.
+//! - 경로: 선택한 섹션 아래 `{slug}.md`. 하위 페이지가 있으면 `{slug}/_index.md`로 만들고 하위 페이지를 그 안에 둔다.
+//!   기존 페이지와 이름이 겹치면 `-2`, `-3` …을 붙인다.
+//! - 첨부: 편집기 붙여넣기와 같은 `write_image`로 저장하므로 이미지 정책/저장 위치 설정이 그대로 적용된다.
+//! - 가져온 페이지끼리의 링크는 `{{< ref "/section/a.md" >}}`로 바꾼다.
+//!
+//! 변환하지 못한 것(남겨 둔 HTML, Liquid 태그, 찾지 못한 첨부 등)은 보고서의 issues로 돌려준다.
+//!
+//! 로컬 마크다운 폴더(Obsidian, Typora)는 `import_markdown_folder`가 따로 처리한다 (파일 아래쪽 설명 참고).
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use ssh2::Sftp;
+use typeshare::typeshare;
+
+use crate::services::config_service::{get_app_config, get_hugo_config};
+use crate::services::file_service::{self, mkdir_recursive, path_exists};
+use crate::services::ssh_service::{execute_ssh_command, get_channel_session, get_sftp_session};
+use crate::services::{image_service, search_index_service, transfer_service};
+use crate::types::config::cms_config::{CmsConfig, HugoConfig};
+use crate::utils::front_matter::{self, FmValue};
+use crate::utils::shell::quote as shq;
+
+/// 원격 첨부 다운로드 최대 크기
+const MAX_DOWNLOAD: u64 = 100 * 1024 * 1024;
+
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ImportSource {
+    /// Notion "Markdown & CSV" export zip
+    Notion,
+    /// WordPress 도구 > 내보내기 파일 (WXR .xml)
+    WordPress,
+    /// Jekyll 사이트 루트 또는 `_posts` 폴더
+    Jekyll,
+}
+
+#[typeshare]
+#[derive(Deserialize, Debug, Clone)]
+pub struct ImportRequest {
+    pub source: ImportSource,
+    /// 로컬 경로 (zip / xml 파일 또는 폴더)
+    pub path: String,
+    /// 가져온 페이지를 둘 섹션 (content 기준, 예: "posts")
+    pub section: String,
+    /// true면 아무것도 쓰지 않고 만들어질 경로와 문제만 계산
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ImportedPage {
+    /// 원본에서의 이름 (zip 안 경로, 글 제목, 파일명)
+    pub source: String,
+    /// 만든 페이지 ("/posts/a.md")
+    pub path: String,
+    pub title: String,
+    /// 저장한 첨부 수 (dry_run이면 저장할 수)
+    pub attachments: u32,
+}
+
+/// 변환하지 못한 것
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ImportIssue {
+    pub source: String,
+    pub message: String,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ImportReport {
+    pub pages: Vec<ImportedPage>,
+    pub issues: Vec<ImportIssue>,
+    pub dry_run: bool,
+}
+
+enum AssetData {
+    Bytes(Vec<u8>),
+    Url(String),
+}
+
+struct Asset {
+    /// 본문에 적힌 참조 그대로 (바꿔 쓸 대상)
+    target: String,
+    name: String,
+    data: AssetData,
+}
+
+/// 변환된 페이지 하나
+struct Doc {
+    source: String,
+    title: String,
+    slug: String,
+    /// 상위 Doc (항상 이 Doc보다 앞에 있다)
+    parent: Option<usize>,
+    /// front matter 포함 전체 내용
+    content: String,
+    assets: Vec<Asset>,
+    /// (본문에 적힌 링크 대상, 가리키는 Doc)
+    links: Vec<(String, usize)>,
+}
+
+#[derive(Default)]
+struct Converted {
+    docs: Vec<Doc>,
+    issues: Vec<ImportIssue>,
+}
+
+impl Converted {
+    fn issue(&mut self, source: &str, message: impl Into<String>) {
+        self.issues.push(ImportIssue { source: source.to_string(), message: message.into() });
+    }
+}
+
+/// 가져오기 실행 (dry_run이면 계획만)
+pub fn import_content(request: &ImportRequest) -> Result<ImportReport> {
+    let section = validate_section(&request.section)?;
+    let path = Path::new(&request.path);
+    let Converted { docs, mut issues } = match request.source {
+        ImportSource::Notion => convert_notion(path)?,
+        ImportSource::WordPress => convert_wordpress(path)?,
+        ImportSource::Jekyll => convert_jekyll(path)?,
+    };
+    if docs.is_empty() {
+        bail!("Nothing to import in {}", request.path);
+    }
+
+    let hugo = get_hugo_config()?;
+    let sftp = get_sftp_session()?;
+    let paths = plan_paths(&sftp, &hugo, section, &docs);
+
+    let mut pages = Vec::with_capacity(docs.len());
+    for (doc, rel) in docs.iter().zip(&paths) {
+        let mut content = doc.content.clone();
+        for (target, to) in &doc.links {
+            let fragment = target.find('#').map(|i| &target[i..]).unwrap_or("");
+            content = replace_target(&content, target, &format!("{{{{< ref \"{}{}\" >}}}}", paths[*to], fragment));
+        }
+
+        let mut attachments = 0;
+        let mut used_names = HashSet::new();
+        for asset in &doc.assets {
+            if request.dry_run {
+                attachments += 1;
+                continue;
+            }
+            match store_asset(rel, asset, &mut used_names) {
+                Ok(saved) => {
+                    content = replace_target(&content, &asset.target, &saved);
+                    attachments += 1;
+                }
+                Err(e) => issues.push(ImportIssue {
+                    source: doc.source.clone(),
+                    message: format!("Attachment {} not imported: {}", asset.target, e),
+                }),
+            }
+        }
+
+        if !request.dry_run {
+            if let Err(e) = write_page(&sftp, &hugo, rel, &content) {
+                issues.push(ImportIssue { source: doc.source.clone(), message: format!("Page not written: {}", e) });
+                continue;
+            }
+        }
+        pages.push(ImportedPage { source: doc.source.clone(), path: rel.clone(), title: doc.title.clone(), attachments });
+    }
+    Ok(ImportReport { pages, issues, dry_run: request.dry_run })
+}
+
+// ── 쓰기 ──
+
+fn validate_section(section: &str) -> Result<&str> {
+    let trimmed = section.trim().trim_matches('/');
+    if trimmed.is_empty() || trimmed.split('/').any(|c| c.is_empty() || c == "." || c == "..") {
+        bail!("Invalid section: {}", section);
+    }
+    Ok(trimmed)
+}
+
+/// 각 Doc의 content 상대경로. 하위 페이지가 있는 Doc은 `_index.md`, 겹치는 이름은 `-2`, `-3` …
+fn plan_paths(sftp: &Sftp, hugo: &HugoConfig, section: &str, docs: &[Doc]) -> Vec<String> {
+    let has_children: HashSet<usize> = docs.iter().filter_map(|d| d.parent).collect();
+    let mut dirs: Vec<String> = Vec::with_capacity(docs.len());
+    let mut paths = Vec::with_capacity(docs.len());
+    let mut taken: HashSet<String> = HashSet::new();
+
+    for (i, doc) in docs.iter().enumerate() {
+        let parent_dir = match doc.parent {
+            Some(p) if p < i => dirs[p].clone(),
+            _ => format!("/{}", section),
+        };
+        let base = if doc.slug.is_empty() { "untitled" } else { doc.slug.as_str() };
+        let mut n = 1;
+        let stem = loop {
+            let candidate = if n == 1 { format!("{}/{}", parent_dir, base) } else { format!("{}/{}-{}", parent_dir, base, n) };
+            let free = !taken.contains(&candidate)
+                && !path_exists(sftp, hugo, &candidate)
+                && !path_exists(sftp, hugo, &format!("{}.md", candidate));
+            if free {
+                break candidate;
+            }
+            n += 1;
+        };
+        taken.insert(stem.clone());
+        paths.push(if has_children.contains(&i) { format!("{}/_index.md", stem) } else { format!("{}.md", stem) });
+        dirs.push(stem);
+    }
+    paths
+}
+
+fn write_page(sftp: &Sftp, hugo: &HugoConfig, rel: &str, content: &str) -> Result<()> {
+    let abs = hugo.content_abs(rel);
+    if let Some(parent) = Path::new(&abs).parent() {
+        mkdir_recursive(sftp, parent)?;
+    }
+    file_service::write_content(rel, content, false, None)?;
+    Ok(())
+}
+
+/// 첨부 저장 후 본문에 쓸 경로 반환. 한 페이지 안에서 같은 파일명은 `-2`, `-3` …
+fn store_asset(rel: &str, asset: &Asset, used_names: &mut HashSet<String>) -> Result<String> {
+    let data = match &asset.data {
+        AssetData::Bytes(bytes) => bytes.clone(),
+        AssetData::Url(url) => download(url)?,
+    };
+    let name = sanitize_file_name(&asset.name);
+    let (stem, ext) = name.rsplit_once('.').unwrap_or((name.as_str(), ""));
+    let mut unique = name.clone();
+    let mut n = 2;
+    while !used_names.insert(unique.clone()) {
+        unique = if ext.is_empty() { format!("{}-{}", stem, n) } else { format!("{}-{}.{}", stem, n, ext) };
+        n += 1;
+    }
+    file_service::write_image(rel, &unique, data)
+}
+
+fn download(url: &str) -> Result<Vec<u8>> {
+    let resp = ureq::get(url).call().map_err(|e| anyhow::anyhow!("Download failed: {}", e))?;
+    let mut data = Vec::new();
+    resp.into_reader().take(MAX_DOWNLOAD).read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// 본문의 링크/이미지 대상 교체. `](target`와 `="target"` 형태만 바꾼다
+fn replace_target(content: &str, target: &str, to: &str) -> String {
+    content
+        .replace(&format!("]({}", target), &format!("]({}", to))
+        .replace(&format!("=\"{}\"", target), &format!("=\"{}\"", to))
+}
+
+// ── 공통 도우미 ──
+
+static LINK_TARGET: Lazy<Regex> = Lazy::new(|| Regex::new(r"\]\(\s*<?([^)\s>]+)").unwrap());
+static HTML_SRC: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\b(?:src|href)="([^"]+)""#).unwrap());
+
+/// 본문의 링크/이미지 대상 (중복 제거, 나온 순서)
+fn link_targets(body: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    LINK_TARGET
+        .captures_iter(body)
+        .chain(HTML_SRC.captures_iter(body))
+        .map(|c| c[1].to_string())
+        .filter(|t| seen.insert(t.clone()))
+        .collect()
+}
+
+fn is_external(target: &str) -> bool {
+    target.contains("://") || ["mailto:", "tel:", "data:", "#", "//"].iter().any(|p| target.starts_with(p))
+}
+
+/// 쿼리/fragment 제거
+fn strip_query(target: &str) -> &str {
+    target.split(['?', '#']).next().unwrap_or(target)
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|h| std::str::from_utf8(h).ok());
+        match hex.filter(|_| bytes[i] == b'%').and_then(|h| u8::from_str_radix(h, 16).ok()) {
+            Some(b) => {
+                out.push(b);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// URL/파일명용 slug. 유니코드 글자(한글 등)는 유지하고 나머지는 '-'로
+fn slugify(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            out.push(c);
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    let out: String = out.chars().take(80).collect();
+    out.trim_end_matches('-').to_string()
+}
+
+/// 마크다운 링크를 깨뜨리는 문자 제거 (공백은 '-')
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '/' | '\\' | '(' | ')' | '[' | ']' | '<' | '>' | '"' | '\'' | '#' | '?' | '%'))
+        .map(|c| if c.is_whitespace() { '-' } else { c })
+        .collect();
+    let cleaned = cleaned.replace("..", ".");
+    let cleaned = cleaned.trim_matches(['.', '-']);
+    if cleaned.is_empty() { "file".to_string() } else { cleaned.to_string() }
+}
+
+/// 파일명 (경로의 마지막 부분, percent-decode)
+fn base_name(path: &str) -> String {
+    percent_decode(strip_query(path).rsplit('/').next().unwrap_or(path))
+}
+
+/// front matter 블록(구분자 포함)과 본문 분리. 블록이 없으면 ("", 전체)
+fn split_front_matter(content: &str) -> (&str, &str) {
+    let delimiter = match content.lines().next().map(str::trim_end) {
+        Some("---") => "---",
+        Some("+++") => "+++",
+        _ => return ("", content),
+    };
+    let mut offset = content.find('\n').map_or(content.len(), |i| i + 1);
+    while offset < content.len() {
+        let end = content[offset..].find('\n').map_or(content.len(), |i| offset + i + 1);
+        let line = content[offset..end].trim_end();
+        if line == delimiter || (delimiter == "---" && line == "...") {
+            return (&content[..end], &content[end..]);
+        }
+        offset = end;
+    }
+    ("", content)
+}
+
+/// 본문 앞에 front matter 필드 추가 (같은 키는 처음 것만)
+fn with_fields(body: &str, fields: &[(String, FmValue)]) -> String {
+    let mut seen = HashSet::new();
+    let mut content = format!("\n{}", body.trim_start_matches('\n'));
+    for (key, value) in fields {
+        if seen.insert(key.as_str()) {
+            content = front_matter::set(&content, key, value);
+        }
+    }
+    content
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
+}
+
+// ── HTML → Markdown (WordPress 본문, Jekyll .html 글) ──
+
+static WP_BLOCK_COMMENT: Lazy<Regex> = Lazy::new(|| Regex::new(r"<!--\s*/?wp:[^>]*-->").unwrap());
+static WP_CAPTION: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)\[caption[^\]]*\](.*?)\[/caption\]").unwrap());
+static WP_SHORTCODE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\[(gallery|embed|video|audio|playlist|wpvideo|code|sourcecode)\b[^\]]*\]").unwrap());
+static HTML_PRE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<pre[^>]*>(.*?)</pre>").unwrap());
+static HTML_CODE_LANG: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)class="[^"]*\blang(?:uage)?-([\w+-]+)"#).unwrap());
+static HTML_HEADING: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<h([1-6])[^>]*>(.*?)</h[1-6]>").unwrap());
+static HTML_IMG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<img\b([^>]*)>").unwrap());
+static HTML_LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<a\b([^>]*)>(.*?)</a>").unwrap());
+static HTML_ATTR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?is)\b([a-z-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap());
+static HTML_STRONG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<(?:strong|b)\b[^>]*>(.*?)</(?:strong|b)>").unwrap());
+static HTML_EM: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<(?:em|i)\b[^>]*>(.*?)</(?:em|i)>").unwrap());
+static HTML_DEL: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<(?:del|s|strike)\b[^>]*>(.*?)</(?:del|s|strike)>").unwrap());
+static HTML_CODE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<code\b[^>]*>(.*?)</code>").unwrap());
+static HTML_BR: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<br\s*/?>").unwrap());
+static HTML_HR: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<hr\b[^>]*>").unwrap());
+static HTML_OL: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<ol\b[^>]*>(.*?)</ol>").unwrap());
+static HTML_LI: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<li\b[^>]*>").unwrap());
+static HTML_P: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)</?p\b[^>]*>").unwrap());
+static HTML_BLOCKQUOTE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<blockquote\b[^>]*>(.*?)</blockquote>").unwrap());
+static HTML_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)</?([a-z][a-z0-9]*)\b[^>]*>").unwrap());
+static HTML_ENTITY: Lazy<Regex> = Lazy::new(|| Regex::new(r"&(#x[0-9a-fA-F]+|#[0-9]+|[a-zA-Z]+);").unwrap());
+static BLANK_LINES: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n{3,}").unwrap());
+
+/// 내용만 남기고 지우는 태그 (나머지 태그는 HTML 그대로 남기고 보고)
+const STRIP_TAGS: &[&str] = &[
+    "div", "span", "figure", "figcaption", "section", "article", "header", "footer", "main", "font", "center", "u",
+    "small", "mark", "abbr", "cite", "ins", "ul", "li", "p", "a",
+];
+
+fn html_attr(attrs: &str, name: &str) -> Option<String> {
+    HTML_ATTR
+        .captures_iter(attrs)
+        .find(|c| c[1].eq_ignore_ascii_case(name))
+        .map(|c| decode_entities(c.get(2).or_else(|| c.get(3)).map_or("", |m| m.as_str())))
+}
+
+fn decode_entities(s: &str) -> String {
+    HTML_ENTITY
+        .replace_all(s, |c: &Captures| {
+            let entity = &c[1];
+            let code = if let Some(hex) = entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                u32::from_str_radix(hex, 16).ok()
+            } else if let Some(dec) = entity.strip_prefix('#') {
+                dec.parse().ok()
+            } else {
+                None
+            };
+            if let Some(ch) = code.and_then(char::from_u32) {
+                return ch.to_string();
+            }
+            match entity {
+                "amp" => "&",
+                "lt" => "<",
+                "gt" => ">",
+                "quot" => "\"",
+                "apos" => "'",
+                "nbsp" => " ",
+                "hellip" => "…",
+                "ndash" => "–",
+                "mdash" => "—",
+                "lsquo" => "‘",
+                "rsquo" => "’",
+                "ldquo" => "“",
+                "rdquo" => "”",
+                "middot" => "·",
+                "copy" => "©",
+                _ => return c[0].to_string(),
+            }
+            .to_string()
+        })
+        .into_owned()
+}
+
+/// 태그 제거 + 엔티티 해석 + 공백 정리 (요약/제목용)
+fn html_to_text(html: &str) -> String {
+    let text = decode_entities(&HTML_TAG.replace_all(html, " "));
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 흔한 태그만 마크다운으로 바꾼다. 반환: (마크다운, HTML 그대로 남긴 태그/shortcode 이름)
+fn html_to_markdown(html: &str) -> (String, Vec<String>) {
+    let mut kept: Vec<String> = Vec::new();
+    let mut s = WP_BLOCK_COMMENT.replace_all(html, "").into_owned();
+    s = WP_CAPTION.replace_all(&s, "$1").into_owned();
+    for c in WP_SHORTCODE.captures_iter(&s) {
+        let name = format!("[{}]", c[1].to_lowercase());
+        if !kept.contains(&name) {
+            kept.push(name);
+        }
+    }
+
+    // 코드 블록은 이후 변환에서 빼 두었다가 마지막에 되돌린다
+    let mut blocks: Vec<String> = Vec::new();
+    s = HTML_PRE
+        .replace_all(&s, |c: &Captures| {
+            let lang = HTML_CODE_LANG.captures(&c[0]).map(|l| l[1].to_string()).unwrap_or_default();
+            let code = decode_entities(&HTML_TAG.replace_all(&c[1], ""));
+            blocks.push(format!("```{}\n{}\n```", lang, code.trim_matches('\n')));
+            format!("\n\n\u{1}{}\u{1}\n\n", blocks.len() - 1)
+        })
+        .into_owned();
+
+    s = HTML_HEADING
+        .replace_all(&s, |c: &Captures| {
+            let level: usize = c[1].parse().unwrap_or(2);
+            format!("\n\n{} {}\n\n", "#".repeat(level), c[2].replace('\n', " ").trim())
+        })
+        .into_owned();
+    s = HTML_IMG
+        .replace_all(&s, |c: &Captures| {
+            let src = html_attr(&c[1], "src").unwrap_or_default();
+            let alt = html_attr(&c[1], "alt").unwrap_or_default();
+            format!("![{}]({})", alt.replace(['[', ']'], ""), src.replace(' ', "%20"))
+        })
+        .into_owned();
+    s = HTML_LINK
+        .replace_all(&s, |c: &Captures| match html_attr(&c[1], "href") {
+            Some(href) if !href.is_empty() => format!("[{}]({})", c[2].trim(), href.replace(' ', "%20")),
+            _ => c[2].to_string(),
+        })
+        .into_owned();
+    s = HTML_STRONG.replace_all(&s, "**$1**").into_owned();
+    s = HTML_EM.replace_all(&s, "*$1*").into_owned();
+    s = HTML_DEL.replace_all(&s, "~~$1~~").into_owned();
+    s = HTML_CODE.replace_all(&s, "`$1`").into_owned();
+    s = HTML_BR.replace_all(&s, "  \n").into_owned();
+    s = HTML_HR.replace_all(&s, "\n\n---\n\n").into_owned();
+    s = HTML_OL.replace_all(&s, |c: &Captures| format!("\n\n{}\n\n", HTML_LI.replace_all(&c[1], "\n1. "))).into_owned();
+    s = HTML_LI.replace_all(&s, "\n- ").into_owned();
+    s = HTML_P.replace_all(&s, "\n\n").into_owned();
+    s = HTML_BLOCKQUOTE
+        .replace_all(&s, |c: &Captures| {
+            let inner = HTML_TAG.replace_all(&c[1], |t: &Captures| {
+                if STRIP_TAGS.contains(&t[1].to_lowercase().as_str()) { String::new() } else { t[0].to_string() }
+            });
+            let quoted: Vec<String> = inner.trim().lines().map(|l| format!("> {}", l.trim()).trim_end().to_string()).collect();
+            format!("\n\n{}\n\n", quoted.join("\n"))
+        })
+        .into_owned();
+    s = HTML_TAG
+        .replace_all(&s, |c: &Captures| {
+            let name = c[1].to_lowercase();
+            if STRIP_TAGS.contains(&name.as_str()) {
+                return String::new();
+            }
+            let label = format!("<{}>", name);
+            if !kept.contains(&label) {
+                kept.push(label);
+            }
+            c[0].to_string()
+        })
+        .into_owned();
+    s = decode_entities(&s);
+
+    let lines: Vec<&str> = s.lines().map(|l| if l.trim().is_empty() { "" } else { l.trim_end_matches(['\t', '\r']) }).collect();
+    let mut markdown = BLANK_LINES.replace_all(&lines.join("\n"), "\n\n").trim().to_string();
+    for (i, block) in blocks.iter().enumerate() {
+        markdown = markdown.replace(&format!("\u{1}{}\u{1}", i), block);
+    }
+    (markdown, kept)
+}
+
+// ── Notion ──
+
+static NOTION_HASH: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(.*?)\s+[0-9a-f]{32}$").unwrap());
+static NOTION_PROPERTY: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([^:]{1,60}):\s?(.*)$").unwrap());
+
+const MONTHS: &[&str] = &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+
+/// Notion 파일/폴더 이름에서 뒤의 32자리 id 제거
+fn notion_name(segment: &str) -> &str {
+    NOTION_HASH.captures(segment).and_then(|c| c.get(1)).map_or(segment, |m| m.as_str())
+}
+
+/// zip 항목 전체 (경로 → 내용). 루트의 zip(큰 export의 Part-N.zip)은 풀어서 합친다
+fn read_zip(data: Vec<u8>, entries: &mut BTreeMap<String, Vec<u8>>, nested: bool) -> Result<()> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(data)).context("Not a zip file")?;
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        if file.is_dir() {
+            continue;
+        }
+        let Some(name) = file.enclosed_name() else { continue };
+        let name = name.to_string_lossy().replace('\\', "/");
+        let mut buf = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut buf)?;
+        if !nested && !name.contains('/') && name.to_lowercase().ends_with(".zip") {
+            read_zip(buf, entries, true)?;
+        } else {
+            entries.insert(name, buf);
+        }
+    }
+    Ok(())
+}
+
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                c => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+/// '/' 구분 상대경로 결합 ("a/b" + "../c.png" → "a/c.png")
+fn join_rel_path(dir: &str, rel: &str) -> String {
+    let mut parts: Vec<&str> = dir.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in rel.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            s => parts.push(s),
+        }
+    }
+    parts.join("/")
+}
+
+/// `..`가 루트보다 위로 올라가는지 (join_rel_path는 루트에서 멈춘다)
+fn escapes_root(rel: &str) -> bool {
+    let mut depth = 0usize;
+    for segment in rel.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." if depth == 0 => return true,
+            ".." => depth -= 1,
+            _ => depth += 1,
+        }
+    }
+    false
+}
+
+/// Notion 날짜 ("January 1, 2023 10:00 AM", "2023/01/01", 기간이면 시작일) → front matter 값
+fn notion_date(value: &str) -> Option<String> {
+    let first = value.split('→').next()?.trim();
+    let iso = first.replace('/', "-");
+    if front_matter::parse_date(&iso).is_some() {
+        return Some(iso);
+    }
+    let mut words = first.split_whitespace();
+    let month_word = words.next()?.to_lowercase();
+    let month = MONTHS.iter().position(|m| month_word.starts_with(m))? + 1;
+    let day: u32 = words.next()?.trim_end_matches(',').parse().ok()?;
+    let year: u32 = words.next()?.parse().ok()?;
+    let Some(time) = words.next() else {
+        return Some(format!("{:04}-{:02}-{:02}", year, month, day));
+    };
+    let (h, m) = time.split_once(':')?;
+    let mut hour: u32 = h.parse().ok()?;
+    let minute: u32 = m.parse().ok()?;
+    match words.next().map(|w| w.to_uppercase()) {
+        Some(ampm) if ampm == "PM" && hour < 12 => hour += 12,
+        Some(ampm) if ampm == "AM" && hour == 12 => hour = 0,
+        _ => {}
+    }
+    Some(format!("{:04}-{:02}-{:02}T{:02}:{:02}:00", year, month, day, hour, minute))
+}
+
+/// Notion 속성 → front matter 필드
+fn notion_property(key: &str, value: &str) -> Option<(String, FmValue)> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    let date = |k: &str| Some((k.to_string(), notion_date(value).map_or(FmValue::Str(value.to_string()), FmValue::Bare)));
+    match key.trim().to_lowercase().as_str() {
+        "tags" | "tag" | "labels" | "multi-select" => Some(("tags".to_string(), FmValue::List(split_list(value)))),
+        "category" | "categories" => Some(("categories".to_string(), FmValue::List(split_list(value)))),
+        "date" | "created" | "created time" | "published" | "publish date" => date("date"),
+        "updated" | "last edited" | "last edited time" => date("lastmod"),
+        _ => {
+            let field = slugify(key).replace('-', "_");
+            (!field.is_empty() && field != "title").then(|| (field, FmValue::Str(value.to_string())))
+        }
+    }
+}
+
+/// Notion 데이터베이스 (CSV)
+struct NotionDatabase {
+    headers: Vec<String>,
+    /// 첫 열(이름) → 나머지 열
+    rows: HashMap<String, Vec<String>>,
+}
+
+fn convert_notion(path: &Path) -> Result<Converted> {
+    let data = std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut entries = BTreeMap::new();
+    read_zip(data, &mut entries, false)?;
+
+    // 노드: 페이지(.md)와 데이터베이스(.csv)를 확장자 뺀 경로로 (하위 항목은 그 경로의 폴더에 있다)
+    let mut databases: HashMap<String, NotionDatabase> = HashMap::new();
+    let mut keys: Vec<String> = Vec::new();
+    for name in entries.keys() {
+        if let Some(key) = name.strip_suffix(".md") {
+            keys.push(key.to_string());
+        } else if let Some(stem) = name.strip_suffix(".csv") {
+            let key = stem.strip_suffix("_all").unwrap_or(stem);
+            // `_all.csv`(모든 행)가 있으면 그것을 쓴다
+            if databases.contains_key(key) && !stem.ends_with("_all") {
+                continue;
+            }
+            let mut rows = parse_csv(&String::from_utf8_lossy(&entries[name])).into_iter();
+            let headers = rows.next().unwrap_or_default();
+            let rows = rows.filter(|r| !r.is_empty()).map(|r| (r[0].trim().to_string(), r[1..].to_vec())).collect();
+            databases.insert(key.to_string(), NotionDatabase { headers, rows });
+            keys.push(key.to_string());
+        }
+    }
+    keys.sort_by(|a, b| (a.matches('/').count(), a).cmp(&(b.matches('/').count(), b)));
+    keys.dedup();
+    let index: HashMap<&str, usize> = keys.iter().enumerate().map(|(i, k)| (k.as_str(), i)).collect();
+
+    let mut out = Converted::default();
+    for key in &keys {
+        let parent_key = key.rsplit_once('/').map(|(dir, _)| dir);
+        let parent = parent_key.and_then(|p| index.get(p).copied());
+        let file_title = notion_name(key.rsplit('/').next().unwrap_or(key)).trim().to_string();
+        let source = format!("{}.md", key);
+
+        let Some(raw) = entries.get(&source) else {
+            // 데이터베이스 자체: 제목만 있는 목록 페이지
+            let title = if file_title.is_empty() { "Untitled".to_string() } else { file_title };
+            out.docs.push(Doc {
+                source: format!("{}.csv", key),
+                slug: slugify(&title),
+                content: with_fields("", &[("title".to_string(), FmValue::Str(title.clone()))]),
+                title,
+                parent,
+                assets: Vec::new(),
+                links: Vec::new(),
+            });
+            continue;
+        };
+        let text = String::from_utf8_lossy(raw).replace("\r\n", "\n");
+        let mut lines = text.lines().peekable();
+        while lines.peek().is_some_and(|l| l.trim().is_empty()) {
+            lines.next();
+        }
+        let title = match lines.peek().and_then(|l| l.strip_prefix("# ")) {
+            Some(heading) => {
+                let heading = heading.trim().to_string();
+                lines.next();
+                heading
+            }
+            None => file_title,
+        };
+        let title = if title.is_empty() { "Untitled".to_string() } else { title };
+
+        // 데이터베이스 행: 제목 아래의 `속성: 값` 줄을 front matter로 (CSV 값이 있으면 그쪽 우선)
+        let mut fields = vec![("title".to_string(), FmValue::Str(title.clone()))];
+        if let Some(db) = parent_key.and_then(|p| databases.get(p)) {
+            while lines.peek().is_some_and(|l| l.trim().is_empty()) {
+                lines.next();
+            }
+            let mut properties: Vec<(String, String)> = Vec::new();
+            while let Some(c) = lines.peek().and_then(|l| NOTION_PROPERTY.captures(l)) {
+                if !db.headers.iter().any(|h| h.trim() == c[1].trim()) {
+                    break;
+                }
+                properties.push((c[1].trim().to_string(), c[2].to_string()));
+                lines.next();
+            }
+            if let Some(row) = db.rows.get(&title) {
+                for (header, value) in db.headers.iter().skip(1).zip(row) {
+                    match properties.iter_mut().find(|(k, _)| k == header.trim()) {
+                        Some(existing) => existing.1 = value.clone(),
+                        None => properties.push((header.trim().to_string(), value.clone())),
+                    }
+                }
+            }
+            fields.extend(properties.iter().filter_map(|(k, v)| notion_property(k, v)));
+        }
+        let body: String = lines.collect::<Vec<_>>().join("\n");
+
+        // 링크: 다른 페이지/데이터베이스 → ref, zip 안 파일 → 첨부
+        let dir = key.rsplit_once('/').map_or("", |(d, _)| d);
+        let mut assets = Vec::new();
+        let mut links = Vec::new();
+        for target in link_targets(&body) {
+            if is_external(&target) {
+                continue;
+            }
+            let resolved = join_rel_path(dir, &percent_decode(strip_query(&target)));
+            let node = resolved.strip_suffix(".md").or_else(|| resolved.strip_suffix(".csv"));
+            if let Some(&to) = node.and_then(|n| index.get(n)) {
+                links.push((target, to));
+            } else if let Some(bytes) = entries.get(&resolved) {
+                assets.push(Asset { name: base_name(&resolved), target, data: AssetData::Bytes(bytes.clone()) });
+            } else {
+                out.issue(&source, format!("Link target not found in export: {}", percent_decode(&target)));
+            }
+        }
+        if body.contains("<aside>") {
+            out.issue(&source, "Callout kept as raw HTML <aside>");
+        }
+
+        out.docs.push(Doc {
+            source,
+            slug: slugify(&title),
+            content: with_fields(&body, &fields),
+            title,
+            parent,
+            assets,
+            links,
+        });
+    }
+    Ok(out)
+}
+
+// ── WordPress (WXR) ──
+
+const WP_NS: &str = "wordpress.org/export";
+const CONTENT_NS: &str = "purl.org/rss/1.0/modules/content";
+const EXCERPT_NS: &str = "/excerpt/";
+
+/// 로컬 이름 + 네임스페이스 URI 일부로 자식 요소 텍스트 (WXR 버전마다 wp 네임스페이스 URI가 다르다)
+fn wx_text(node: roxmltree::Node, ns_hint: &str, name: &str) -> String {
+    node.children()
+        .find(|c| c.is_element() && c.tag_name().name() == name && c.tag_name().namespace().unwrap_or("").contains(ns_hint))
+        .map(|c| c.children().filter(|t| t.is_text()).filter_map(|t| t.text()).collect::<String>())
+        .unwrap_or_default()
+}
+
+/// 링크 비교용 URL (scheme, 끝 '/' 제거)
+fn normalize_url(url: &str) -> String {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    url.trim_end_matches('/').to_lowercase()
+}
+
+/// WXR 날짜 ("2024-01-31 10:00:00", `0000-…`은 없음) → front matter 값
+fn wp_date(gmt: &str, local: &str) -> Option<String> {
+    let valid = |s: &str| !s.is_empty() && !s.starts_with("0000") && front_matter::parse_date(s).is_some();
+    if valid(gmt) {
+        Some(front_matter::format_rfc3339(front_matter::parse_date(gmt)?))
+    } else if valid(local) {
+        Some(local.replacen(' ', "T", 1))
+    } else {
+        None
+    }
+}
+
+struct WpItem {
+    id: String,
+    parent: String,
+    title: String,
+    link: String,
+    guid: String,
+    slug: String,
+    date: Option<String>,
+    draft: bool,
+    author: String,
+    content: String,
+    excerpt: String,
+    tags: Vec<String>,
+    categories: Vec<String>,
+}
+
+fn convert_wordpress(path: &Path) -> Result<Converted> {
+    let xml = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let doc = roxmltree::Document::parse(&xml).context("Invalid WXR file")?;
+    let channel = doc.descendants().find(|n| n.has_tag_name("channel")).context("Not a WordPress export (no <channel>)")?;
+    let base_url = wx_text(channel, WP_NS, "base_site_url");
+    let base_url = if base_url.is_empty() { wx_text(channel, "", "link") } else { base_url };
+    let base_url = base_url.trim_end_matches('/');
+
+    let mut out = Converted::default();
+    let mut attachments: HashSet<String> = HashSet::new();
+    let mut skipped: BTreeMap<String, u32> = BTreeMap::new();
+    let mut items: Vec<WpItem> = Vec::new();
+    for item in channel.children().filter(|n| n.has_tag_name("item")) {
+        let post_type = wx_text(item, WP_NS, "post_type");
+        let status = wx_text(item, WP_NS, "status");
+        if post_type == "attachment" {
+            attachments.insert(normalize_url(&wx_text(item, WP_NS, "attachment_url")));
+            continue;
+        }
+        if !matches!(post_type.as_str(), "post" | "page") || matches!(status.as_str(), "trash" | "auto-draft" | "inherit") {
+            *skipped.entry(if post_type.is_empty() { "unknown".to_string() } else { post_type }).or_default() += 1;
+            continue;
+        }
+        let terms = |domain: &str| -> Vec<String> {
+            item.children()
+                .filter(|c| c.has_tag_name("category") && c.attribute("domain") == Some(domain))
+                .filter_map(|c| c.text())
+                .map(|t| decode_entities(t.trim()))
+                .filter(|t| !t.is_empty())
+                .collect()
+        };
+        items.push(WpItem {
+            id: wx_text(item, WP_NS, "post_id"),
+            parent: wx_text(item, WP_NS, "post_parent"),
+            title: html_to_text(&wx_text(item, "", "title")),
+            link: wx_text(item, "", "link"),
+            guid: wx_text(item, "", "guid"),
+            slug: percent_decode(&wx_text(item, WP_NS, "post_name")),
+            date: wp_date(&wx_text(item, WP_NS, "post_date_gmt"), &wx_text(item, WP_NS, "post_date")),
+            draft: status != "publish",
+            author: wx_text(item, "purl.org/dc/elements", "creator"),
+            content: wx_text(item, CONTENT_NS, "encoded"),
+            excerpt: html_to_text(&wx_text(item, EXCERPT_NS, "encoded")),
+            tags: terms("post_tag"),
+            categories: terms("category"),
+        });
+    }
+    for (post_type, count) in skipped {
+        out.issue(&path.display().to_string(), format!("Skipped {} item(s) of type {}", count, post_type));
+    }
+
+    // 상위 페이지가 먼저 오도록 깊이순 정렬
+    let by_id: HashMap<String, usize> = items.iter().enumerate().map(|(i, it)| (it.id.clone(), i)).collect();
+    let depth = |mut i: usize| {
+        let mut d = 0;
+        while let Some(&p) = by_id.get(&items[i].parent) {
+            if d > items.len() {
+                break;
+            }
+            d += 1;
+            i = p;
+        }
+        d
+    };
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by_key(|&i| depth(i));
+    let position: HashMap<usize, usize> = order.iter().enumerate().map(|(pos, &i)| (i, pos)).collect();
+
+    // 가져오는 글끼리의 링크 (permalink, ?p=ID guid)
+    let mut link_map: HashMap<String, usize> = HashMap::new();
+    for (i, item) in items.iter().enumerate() {
+        for url in [&item.link, &item.guid] {
+            if !url.is_empty() {
+                link_map.insert(normalize_url(url), position[&i]);
+            }
+        }
+    }
+
+    for &i in &order {
+        let item = &items[i];
+        let title = if item.title.is_empty() { format!("Untitled {}", item.id) } else { item.title.clone() };
+        let source = format!("{} (#{})", title, item.id);
+        let (body, kept) = html_to_markdown(&item.content);
+        for label in kept {
+            out.issue(&source, format!("Kept as raw HTML: {}", label));
+        }
+
+        let mut assets = Vec::new();
+        let mut links = Vec::new();
+        for target in link_targets(&body) {
+            let absolute = if target.starts_with('/') && !target.starts_with("//") {
+                format!("{}{}", base_url, target)
+            } else {
+                target.clone()
+            };
+            let normalized = normalize_url(strip_query(&absolute));
+            if let Some(&to) = link_map.get(&normalized) {
+                links.push((target, to));
+            } else if attachments.contains(&normalized) || normalized.contains("/wp-content/uploads/") {
+                assets.push(Asset { name: base_name(&absolute), target, data: AssetData::Url(absolute) });
+            }
+        }
+
+        let mut fields = vec![("title".to_string(), FmValue::Str(title.clone()))];
+        if let Some(date) = &item.date {
+            fields.push(("date".to_string(), FmValue::Bare(date.clone())));
+        }
+        fields.push(("draft".to_string(), FmValue::Bool(item.draft)));
+        if !item.tags.is_empty() {
+            fields.push(("tags".to_string(), FmValue::List(item.tags.clone())));
+        }
+        if !item.categories.is_empty() {
+            fields.push(("categories".to_string(), FmValue::List(item.categories.clone())));
+        }
+        if !item.excerpt.is_empty() {
+            fields.push(("summary".to_string(), FmValue::Str(item.excerpt.clone())));
+        }
+        if !item.author.is_empty() {
+            fields.push(("author".to_string(), FmValue::Str(item.author.clone())));
+        }
+        // 예전 주소로 들어와도 찾아가도록
+        let old_path = item.link.split_once("://").map_or("", |(_, rest)| rest.find('/').map_or("", |i| &rest[i..]));
+        if !item.draft && !old_path.is_empty() && old_path != "/" && !old_path.contains('?') {
+            fields.push(("aliases".to_string(), FmValue::List(vec![old_path.to_string()])));
+        }
+
+        let slug = [slugify(&item.slug), slugify(&title)].into_iter().find(|s| !s.is_empty()).unwrap_or(format!("post-{}", item.id));
+        out.docs.push(Doc {
+            source,
+            slug,
+            parent: by_id.get(&item.parent).map(|p| position[p]),
+            content: with_fields(&body, &fields),
+            title,
+            assets,
+            links,
+        });
+    }
+    Ok(out)
+}
+
+// ── Jekyll ──
+
+static JEKYLL_POST_NAME: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d{4}-\d{2}-\d{2})-(.+)$").unwrap());
+static LIQUID_HIGHLIGHT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?s)\{%-?\s*highlight\s+([\w+-]+)[^%]*%\}\n?(.*?)\{%-?\s*endhighlight\s*-?%\}").unwrap()
+});
+static LIQUID_RAW: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{%-?\s*(?:raw|endraw)\s*-?%\}").unwrap());
+static LIQUID_POST_URL: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{%-?\s*(?:post_url|link)\s+(\S+)\s*-?%\}").unwrap());
+static LIQUID_SITE_URL: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{\{-?\s*site\.(?:baseurl|url)\s*-?\}\}").unwrap());
+static LIQUID_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{%-?\s*\w+[^%]*%\}").unwrap());
+static LIQUID_OUTPUT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{\{-?\s*[^\s<%}][^}]*\}\}").unwrap());
+
+const JEKYLL_EXTS: &[&str] = &["md", "markdown", "mkd", "mkdn", "html"];
+
+/// 글 파일 (이름순)
+fn jekyll_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()).is_some_and(|e| JEKYLL_EXTS.contains(&e.to_lowercase().as_str())))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Jekyll 목록 값: 따옴표 없는 한 줄 문자열은 공백으로 구분
+fn jekyll_list(content: &str, key: &str) -> Vec<String> {
+    let list = front_matter::get_list(content, key);
+    match (list.as_slice(), front_matter::get(content, key)) {
+        ([single], Some(raw)) if !raw.starts_with('[') && single == &raw => {
+            raw.split_whitespace().map(str::to_string).collect()
+        }
+        _ => list,
+    }
+}
+
+fn convert_jekyll(path: &Path) -> Result<Converted> {
+    let (root, dirs) = if path.join("_posts").is_dir() {
+        (path.to_path_buf(), vec![path.join("_posts"), path.join("_drafts")])
+    } else if path.is_dir() {
+        let root = path.parent().unwrap_or(path).to_path_buf();
+        let drafts = root.join("_drafts");
+        (root, vec![path.to_path_buf(), drafts])
+    } else {
+        bail!("Not a folder: {}", path.display());
+    };
+
+    let mut files: Vec<(PathBuf, bool)> = Vec::new();
+    for (i, dir) in dirs.iter().enumerate() {
+        if dir.is_dir() {
+            files.extend(jekyll_files(dir).into_iter().map(|f| (f, i == 1)));
+        }
+    }
+    // `{% post_url 2024-01-31-name %}`가 가리킬 글 (파일명에서 확장자 뺀 것)
+    let stems: HashMap<String, usize> = files
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (f, _))| Some((f.file_stem()?.to_str()?.to_string(), i)))
+        .collect();
+
+    let mut out = Converted::default();
+    for (file, is_draft) in &files {
+        let source = file.strip_prefix(&root).unwrap_or(file).display().to_string();
+        let text = match std::fs::read_to_string(file) {
+            Ok(t) => t.replace("\r\n", "\n"),
+            Err(e) => {
+                out.issue(&source, format!("Failed to read: {}", e));
+                continue;
+            }
+        };
+        let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+        let (file_date, file_slug) = match JEKYLL_POST_NAME.captures(stem) {
+            Some(c) => (Some(c[1].to_string()), c[2].to_string()),
+            None => (None, stem.to_string()),
+        };
+
+        // front matter: Jekyll 전용 키를 Hugo 키로
+        let (head, _) = split_front_matter(&text);
+        let mut fm = if head.is_empty() { "---\n---\n".to_string() } else { head.to_string() };
+        let title = match front_matter::get(&fm, "title") {
+            Some(title) => title,
+            None => {
+                let title = file_slug.replace(['-', '_'], " ");
+                fm = front_matter::set(&fm, "title", &FmValue::Str(title.clone()));
+                title
+            }
+        };
+        fm = front_matter::remove(&fm, "layout");
+        if front_matter::get(&fm, "date").is_none() {
+            if let Some(date) = &file_date {
+                fm = front_matter::set(&fm, "date", &FmValue::Bare(date.clone()));
+            }
+        }
+        let unpublished = front_matter::get(&fm, "published").is_some_and(|p| p == "false");
+        fm = front_matter::remove(&fm, "published");
+        if *is_draft || unpublished {
+            fm = front_matter::set(&fm, "draft", &FmValue::Bool(true));
+        }
+        let mut categories = jekyll_list(&fm, "categories");
+        categories.extend(jekyll_list(&fm, "category"));
+        fm = front_matter::remove(&fm, "category");
+        if !categories.is_empty() {
+            fm = front_matter::set(&fm, "categories", &FmValue::List(categories));
+        }
+        let tags = jekyll_list(&fm, "tags");
+        if !tags.is_empty() {
+            fm = front_matter::set(&fm, "tags", &FmValue::List(tags));
+        }
+        if let Some(excerpt) = front_matter::get(&fm, "excerpt") {
+            fm = front_matter::remove(&fm, "excerpt");
+            fm = front_matter::set(&fm, "summary", &FmValue::Str(excerpt));
+        }
+        if let Some(permalink) = front_matter::get(&fm, "permalink") {
+            fm = front_matter::remove(&fm, "permalink");
+            if permalink.contains(':') {
+                out.issue(&source, format!("Permalink pattern not converted: {}", permalink));
+            } else {
+                fm = front_matter::set(&fm, "url", &FmValue::Str(permalink));
+            }
+        }
+
+        // 본문: Liquid → Hugo
+        let (_, body) = split_front_matter(&text);
+        let mut body = if file.extension().is_some_and(|e| e.eq_ignore_ascii_case("html")) {
+            let (markdown, kept) = html_to_markdown(body);
+            for label in kept {
+                out.issue(&source, format!("Kept as raw HTML: {}", label));
+            }
+            markdown
+        } else {
+            body.to_string()
+        };
+        body = LIQUID_HIGHLIGHT
+            .replace_all(&body, |c: &Captures| format!("```{}\n{}\n```", &c[1], c[2].trim_end_matches('\n')))
+            .into_owned();
+        body = LIQUID_RAW.replace_all(&body, "").into_owned();
+        body = LIQUID_SITE_URL.replace_all(&body, "").into_owned();
+        let mut links = Vec::new();
+        body = LIQUID_POST_URL
+            .replace_all(&body, |c: &Captures| {
+                let name = c[1].rsplit('/').next().unwrap_or(&c[1]);
+                let name = name.rsplit_once('.').filter(|(_, e)| JEKYLL_EXTS.contains(e)).map_or(name, |(s, _)| s);
+                match stems.get(name) {
+                    Some(&to) => {
+                        let placeholder = format!("jekyll-post:{}", name);
+                        links.push((placeholder.clone(), to));
+                        placeholder
+                    }
+                    None => c[0].to_string(),
+                }
+            })
+            .into_owned();
+        for tag in LIQUID_TAG.find_iter(&body).chain(LIQUID_OUTPUT.find_iter(&body)) {
+            out.issue(&source, format!("Liquid not converted: {}", tag.as_str()));
+        }
+
+        // 사이트 루트 기준 파일 참조 → 첨부
+        let mut assets = Vec::new();
+        for target in link_targets(&body) {
+            if !target.starts_with('/') || target.starts_with("//") {
+                continue;
+            }
+            let rel = percent_decode(strip_query(&target));
+            let local = root.join(join_rel_path("", &rel));
+            let is_file_ref = local
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| !JEKYLL_EXTS.contains(&e.to_lowercase().as_str()));
+            if !is_file_ref {
+                continue;
+            }
+            // `..`나 심볼릭 링크로 사이트 폴더 밖을 가리키면 읽지 않는다
+            let outside = escapes_root(&rel)
+                || local.canonicalize().ok().zip(root.canonicalize().ok()).is_some_and(|(l, r)| !l.starts_with(r));
+            if outside {
+                out.issue(&source, format!("File outside the site not imported: {}", rel));
+                continue;
+            }
+            match std::fs::read(&local) {
+                Ok(bytes) => assets.push(Asset { name: base_name(&rel), target, data: AssetData::Bytes(bytes) }),
+                Err(_) => out.issue(&source, format!("File not found: {}", rel)),
+            }
+        }
+
+        let slug = front_matter::get(&fm, "slug").map(|s| slugify(&s)).filter(|s| !s.is_empty()).unwrap_or_else(|| slugify(&file_slug));
+        out.docs.push(Doc {
+            source,
+            slug,
+            parent: None,
+            content: format!("{}{}", fm, body),
+            title,
+            assets,
+            links,
+        });
+    }
+    Ok(out)
+}
+
+// ── 로컬 마크다운 폴더 (Obsidian, Typora) ──
+//
+// 폴더 구조를 섹션 아래에 그대로 옮긴다 (이름은 slug). 노트 본문의 위키 링크(`[[노트|별칭]]`, `![[그림.png]]`)와
+// 상대경로 링크를 `ref` / 페이지별 이미지 경로(`{image_path}/{페이지}/{파일}`, sync_images_on_save와 같은 배치)로 바꾸고,
+// 페이지 · 중간 폴더 `_index.md` · 첨부를 tar 하나로 묶어 한 번에 올린다.
+
+static WIKI_LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"(!?)\[\[([^\]|#]*)(#[^\]|]*)?(?:\|([^\]]*))?\]\]").unwrap());
+
+const NOTE_EXTS: &[&str] = &["md", "markdown"];
+
+#[typeshare]
+#[derive(Deserialize, Debug, Clone)]
+pub struct FolderImportRequest {
+    /// 로컬 폴더 (Obsidian vault, Typora 폴더 등)
+    pub path: String,
+    /// 폴더를 대응시킬 섹션. 하위 폴더 구조는 그대로 유지
+    pub section: String,
+    /// true면 올리지 않고 만들어질 경로와 문제만 계산
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 로컬 폴더의 파일 목록 (상대경로는 '/' 구분, 숨김 파일/폴더 제외)
+struct LocalVault {
+    root: PathBuf,
+    notes: Vec<String>,
+    files: HashSet<String>,
+    /// 소문자 파일명 → 상대경로 (Obsidian은 이름만으로 링크한다)
+    by_name: HashMap<String, Vec<String>>,
+}
+
+impl LocalVault {
+    fn scan(root: &Path) -> Self {
+        let mut vault = LocalVault { root: root.to_path_buf(), notes: Vec::new(), files: HashSet::new(), by_name: HashMap::new() };
+        let entries = walkdir::WalkDir::new(root)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|e| e.depth() == 0 || !e.file_name().to_string_lossy().starts_with('.'))
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file());
+        for entry in entries {
+            let Ok(rel) = entry.path().strip_prefix(root) else { continue };
+            let rel = rel.to_string_lossy().replace('\\', "/");
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            if name.rsplit_once('.').is_some_and(|(_, ext)| NOTE_EXTS.contains(&ext)) {
+                vault.notes.push(rel.clone());
+            }
+            vault.by_name.entry(name).or_default().push(rel.clone());
+            vault.files.insert(rel);
+        }
+        vault
+    }
+
+    /// 노트 폴더 기준 → 폴더 루트 기준 → 파일명 순으로 찾는다. 확장자 없는 이름은 노트로 본다
+    fn resolve(&self, from_dir: &str, target: &str) -> Option<String> {
+        let target = target.trim();
+        for base in [from_dir, ""] {
+            let joined = join_rel_path(base, target);
+            for candidate in [format!("{}.md", joined), joined] {
+                if self.files.contains(&candidate) {
+                    return Some(candidate);
+                }
+            }
+        }
+        let name = target.rsplit('/').next()?.to_lowercase();
+        self.by_name
+            .get(&name)
+            .or_else(|| self.by_name.get(&format!("{}.md", name)))
+            .and_then(|found| found.first().cloned())
+    }
+
+    /// 절대경로 → 폴더 안 파일이면 상대경로 (폴더 밖이면 None)
+    fn relative(&self, abs: &Path) -> Option<String> {
+        let abs = abs.canonicalize().ok()?;
+        let root = self.root.canonicalize().ok()?;
+        let rel = abs.strip_prefix(root).ok()?.to_string_lossy().replace('\\', "/");
+        self.files.contains(&rel).then_some(rel)
+    }
+}
+
+/// 노트 하나의 첨부 (같은 파일은 한 번만, 같은 이름은 `-2`, `-3` …)
+struct NoteAssets<'a> {
+    page: &'a str,
+    image_dir: &'a str,
+    cms: &'a CmsConfig,
+    dry_run: bool,
+    refs: HashMap<PathBuf, String>,
+    names: HashSet<String>,
+    files: Vec<(String, Vec<u8>)>,
+}
+
+impl NoteAssets<'_> {
+    /// 첨부를 추가하고 본문에 쓸 경로 ("/posts/a.md/x.png") 반환
+    fn attach(&mut self, path: &Path) -> Result<String> {
+        if let Some(existing) = self.refs.get(path) {
+            return Ok(existing.clone());
+        }
+        let name = sanitize_file_name(&path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default());
+        let (stem, ext) = name.rsplit_once('.').unwrap_or((name.as_str(), ""));
+        let (data, ext) = if self.dry_run {
+            (Vec::new(), ext.to_string())
+        } else if self.cms.attachment_exts().contains(&ext.to_lowercase()) {
+            (std::fs::read(path)?, ext.to_string())
+        } else {
+            let processed = image_service::process_upload(std::fs::read(path)?, &self.cms.image_policy)?;
+            (processed.data, processed.ext.unwrap_or(ext).to_string())
+        };
+
+        let mut unique = format!("{}.{}", stem, ext);
+        let mut n = 2;
+        while !self.names.insert(unique.clone()) {
+            unique = format!("{}-{}.{}", stem, n, ext);
+            n += 1;
+        }
+        let reference = format!("{}/{}", self.page, unique);
+        self.files.push((format!("{}{}", self.image_dir, reference), data));
+        self.refs.insert(path.to_path_buf(), reference.clone());
+        Ok(reference)
+    }
+}
+
+/// 섹션 아래 기존 페이지 (content + hidden, "/posts/a.md")
+fn existing_pages(hugo: &HugoConfig, section: &str) -> Result<HashSet<String>> {
+    let section_path = format!("/{}", section);
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(
+        &mut channel,
+        &format!(
+            "find {} {} -name '*.md' 2>/dev/null; true",
+            shq(&hugo.content_abs(&section_path)),
+            shq(&hugo.hidden_abs(&section_path)),
+        ),
+    )?;
+    let (content_root, hidden_root) = (hugo.content_abs(""), hugo.hidden_abs(""));
+    Ok(output
+        .lines()
+        .filter_map(|l| l.strip_prefix(hidden_root.as_str()).or_else(|| l.strip_prefix(content_root.as_str())))
+        .map(str::to_string)
+        .collect())
+}
+
+fn slug_or_untitled(s: &str) -> String {
+    let slug = slugify(s);
+    if slug.is_empty() { "untitled".to_string() } else { slug }
+}
+
+/// 로컬 마크다운 폴더를 섹션 아래로 가져오기. 모든 파일을 tar 하나로 올린다 (진행은 `transfer:progress`)
+pub fn import_markdown_folder(request: &FolderImportRequest, app: &tauri::AppHandle) -> Result<ImportReport> {
+    let section = validate_section(&request.section)?;
+    let root = Path::new(&request.path);
+    if !root.is_dir() {
+        bail!("Not a folder: {}", request.path);
+    }
+    let vault = LocalVault::scan(root);
+    if vault.notes.is_empty() {
+        bail!("No markdown files in {}", request.path);
+    }
+    let hugo = get_hugo_config()?;
+    let cms = get_app_config()?.cms_config;
+    let mut issues: Vec<ImportIssue> = Vec::new();
+
+    // 1. 노트 → 페이지 경로 (폴더는 slug, 기존 페이지와 겹치면 `-2`, `-3` …)
+    let section_dir = format!("/{}", section);
+    let mut folder_titles: BTreeMap<String, String> = BTreeMap::new();
+    let mut targets: BTreeMap<String, String> = BTreeMap::new();
+    let mut taken = existing_pages(&hugo, section)?;
+    for note in &vault.notes {
+        let (dir, file) = note.rsplit_once('/').unwrap_or(("", note));
+        let stem = file.rsplit_once('.').map_or(file, |(s, _)| s);
+        let mut base = section_dir.clone();
+        for segment in dir.split('/').filter(|s| !s.is_empty()) {
+            base = format!("{}/{}", base, slug_or_untitled(segment));
+            folder_titles.entry(base.clone()).or_insert_with(|| segment.to_string());
+        }
+        if matches!(stem, "_index" | "index") {
+            let rel = format!("{}/{}.md", base, stem);
+            if !taken.insert(rel.clone()) {
+                issues.push(ImportIssue { source: note.clone(), message: format!("Skipped: {} already exists", rel) });
+                continue;
+            }
+            targets.insert(note.clone(), rel);
+            continue;
+        }
+        let name = slug_or_untitled(stem);
+        let mut n = 1;
+        let rel = loop {
+            let candidate = if n == 1 { format!("{}/{}.md", base, name) } else { format!("{}/{}-{}.md", base, name, n) };
+            if !taken.contains(&candidate) {
+                break candidate;
+            }
+            n += 1;
+        };
+        taken.insert(rel.clone());
+        targets.insert(note.clone(), rel);
+    }
+
+    let image_dir = hugo.image_path.trim_matches('/').to_string();
+    let archive_name = |rel: &str| {
+        let abs = hugo.content_abs(rel);
+        let under_base = abs.strip_prefix(&hugo.base_path).unwrap_or(&abs);
+        under_base.trim_start_matches('/').to_string()
+    };
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut written: Vec<(String, String)> = Vec::new();
+    let mut pages = Vec::new();
+
+    // 2. 노트 변환
+    for (note, rel) in &targets {
+        let text = match std::fs::read_to_string(root.join(note)) {
+            Ok(t) => t,
+            Err(e) => {
+                issues.push(ImportIssue { source: note.clone(), message: format!("Failed to read: {}", e) });
+                continue;
+            }
+        };
+        let note_dir = note.rsplit_once('/').map_or("", |(d, _)| d);
+        let (head, body) = split_front_matter(&text);
+        let original_targets = link_targets(body);
+        let mut assets =
+            NoteAssets { page: rel, image_dir: &image_dir, cms: &cms, dry_run: request.dry_run, refs: HashMap::new(), names: HashSet::new(), files: Vec::new() };
+        let mut issue = |message: String| issues.push(ImportIssue { source: note.clone(), message });
+
+        let mut body = WIKI_LINK
+            .replace_all(body, |c: &Captures| {
+                let embed = !c[1].is_empty();
+                let target = c[2].trim();
+                let heading = c.get(3).map_or("", |m| m.as_str().trim_start_matches('#').trim());
+                // Hugo 제목 anchor 형식으로
+                let fragment = if heading.is_empty() { String::new() } else { format!("#{}", slugify(heading)) };
+                let alias = c.get(4).map(|m| m.as_str().trim()).filter(|a| !a.is_empty());
+                let label = alias.unwrap_or(if target.is_empty() { heading } else { target });
+                let found = if target.is_empty() { Some(note.clone()) } else { vault.resolve(note_dir, target) };
+                let Some(found) = found else {
+                    issue(format!("Wiki link not found: {}", &c[0]));
+                    return label.to_string();
+                };
+                if let Some(page) = targets.get(&found) {
+                    if embed {
+                        issue(format!("Embedded note converted to a link: {}", &c[0]));
+                    }
+                    return format!("[{}]({{{{< ref \"{}{}\" >}}}})", label, page, fragment);
+                }
+                match assets.attach(&vault.root.join(&found)) {
+                    // `![[a.png|300]]`의 숫자는 크기라서 alt로 쓰지 않는다
+                    Ok(reference) if embed => {
+                        let alt = alias.filter(|a| !a.chars().all(|ch| ch.is_ascii_digit() || ch == 'x')).unwrap_or("");
+                        format!("![{}]({})", alt, reference)
+                    }
+                    Ok(reference) => format!("[{}]({})", label, reference),
+                    Err(e) => {
+                        issue(format!("Attachment {} not imported: {}", found, e));
+                        c[0].to_string()
+                    }
+                }
+            })
+            .into_owned();
+
+        for target in original_targets {
+            let local = target.strip_prefix("file://");
+            if target.starts_with("{{") || (local.is_none() && is_external(&target)) {
+                continue;
+            }
+            let fragment = target.find('#').map(|i| &target[i..]).unwrap_or("");
+            let decoded = percent_decode(strip_query(local.unwrap_or(&target)));
+            // Typora는 절대경로로 넣기도 한다. 폴더 안 파일만 받는다
+            let absolute = Path::new(&decoded).is_absolute();
+            let found = if absolute { vault.relative(Path::new(&decoded)) } else { vault.resolve(note_dir, &decoded) };
+            let replacement = match found {
+                Some(found) => match targets.get(&found) {
+                    Some(page) => Ok(format!("{{{{< ref \"{}{}\" >}}}}", page, fragment)),
+                    None => assets.attach(&vault.root.join(&found)),
+                },
+                None if absolute && Path::new(&decoded).exists() => {
+                    issue(format!("Link target outside the import folder not imported: {}", decoded));
+                    continue;
+                }
+                None => {
+                    issue(format!("Link target not found: {}", decoded));
+                    continue;
+                }
+            };
+            match replacement {
+                Ok(to) => body = replace_target(&body, &target, &to),
+                Err(e) => issue(format!("Attachment {} not imported: {}", decoded, e)),
+            }
+        }
+
+        let mut content = format!("{}{}", head, body);
+        let title = match front_matter::get(&content, "title") {
+            Some(title) => title,
+            None => {
+                let stem = note.rsplit('/').next().unwrap_or(note);
+                let stem = stem.rsplit_once('.').map_or(stem, |(s, _)| s);
+                let title = match stem {
+                    "_index" | "index" => folder_titles.get(rel.rsplit_once('/').map_or("", |(d, _)| d)).cloned().unwrap_or_else(|| section.to_string()),
+                    s => s.to_string(),
+                };
+                content = front_matter::set(&content, "title", &FmValue::Str(title.clone()));
+                title
+            }
+        };
+
+        pages.push(ImportedPage { source: note.clone(), path: rel.clone(), title, attachments: assets.files.len() as u32 });
+        files.append(&mut assets.files);
+        files.push((archive_name(rel), content.clone().into_bytes()));
+        written.push((rel.clone(), content));
+    }
+
+    // 3. 중간 폴더 `_index.md` (이미 있거나 leaf bundle이면 만들지 않음)
+    for (dir, title) in &folder_titles {
+        let index = format!("{}/_index.md", dir);
+        if taken.contains(&index) || taken.contains(&format!("{}/index.md", dir)) {
+            continue;
+        }
+        let content = with_fields("", &[("title".to_string(), FmValue::Str(title.clone()))]);
+        pages.push(ImportedPage { source: format!("{}/", title), path: index.clone(), title: title.clone(), attachments: 0 });
+        files.push((archive_name(&index), content.clone().into_bytes()));
+        written.push((index, content));
+    }
+
+    if !request.dry_run {
+        for (rel, _) in &written {
+            crate::services::watch_service::mark_self_write(&hugo.content_abs(rel));
+        }
+        transfer_service::upload_generated(files, &hugo.base_path, app)?;
+        for (rel, content) in &written {
+            search_index_service::note_saved(&hugo, &hugo.content_abs(rel), content);
+        }
+    }
+    Ok(ImportReport { pages, issues, dry_run: request.dry_run })
+}
@@ -0,0 +1,294 @@
+//! 사이트 전체 링크 점검: 깨진 내부 링크, 없는 이미지/첨부파일, 공개 페이지 → 숨김 영역 참조, (선택) 외부 URL.
+//!
+//! 본문 수집/링크 해석은 link_service, 이미지 참조 추출은 저장 시 동기화와 같은 image_refs를 그대로 쓴다.
+//! 일반 markdown 링크는 content_paths 섹션 안을 가리킬 때만 점검한다
+//! (섹션 밖 URL은 taxonomy/static/레이아웃이 만드는 페이지일 수 있음). ref/relref는 항상 점검.
+
+use std::collections::{BTreeMap, HashSet};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+use typeshare::typeshare;
+
+use crate::services::config_service::get_hugo_config;
+use crate::services::file_service;
+use crate::services::link_service::{
+    body_lines, classify_rel, fetch_markdown, find_links, is_asset, is_external, normalize_path, page_key, resolve, split_fragment,
+    LinkKind, PageIndex,
+};
+use crate::services::ssh_service::{execute_ssh_command, get_channel_session};
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::image_refs::{find_image_refs, ImageRefKind};
+use crate::utils::shell::quote as shq;
+
+const EXTERNAL_TIMEOUT: Duration = Duration::from_secs(10);
+const EXTERNAL_WORKERS: usize = 8;
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum LinkIssueKind {
+    /// 내부 링크 / ref·relref 대상 페이지 없음
+    BrokenLink,
+    /// image_path 아래에 이미지 파일 없음
+    MissingImage,
+    /// 페이지 디렉토리에 첨부파일 없음
+    MissingAttachment,
+    /// 공개 페이지에서 숨김 영역의 페이지를 가리킴
+    HiddenTarget,
+    /// 외부 URL 응답 오류 (check_external일 때만)
+    ExternalError,
+}
+
+/// 점검 결과 한 건
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct LinkIssue {
+    /// 문제가 있는 파일 (content 상대경로)
+    pub file: String,
+    pub hidden: bool,
+    /// 1-based
+    pub line: u32,
+    pub kind: LinkIssueKind,
+    /// 본문에 적힌 링크/이미지 경로
+    pub target: String,
+    /// ExternalError: HTTP 상태 코드 또는 오류 메시지
+    pub detail: Option<String>,
+}
+
+/// 외부 URL 등장 위치 (URL당 한 번만 요청하고 결과를 모든 위치에 붙인다)
+struct ExternalRef {
+    file: String,
+    hidden: bool,
+    line: u32,
+    url: String,
+}
+
+/// image_path 아래 모든 파일 ("/posts/a.md/x.png" 형태)
+fn list_image_files(hugo: &HugoConfig) -> Result<HashSet<String>> {
+    let image_dir = format!("{}/{}", hugo.base_path, hugo.image_path.trim_matches('/'));
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(
+        &mut channel,
+        &format!("cd {} 2>/dev/null && find . -type f; true", shq(&image_dir)),
+    )?;
+    Ok(output
+        .lines()
+        .filter_map(|l| l.strip_prefix('.'))
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// content/hidden 아래 .md가 아닌 모든 파일 (leaf bundle 리소스 확인용, hidden은 공개 경로로)
+fn list_content_resources(hugo: &HugoConfig) -> Result<HashSet<String>> {
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(
+        &mut channel,
+        &format!(
+            "cd {} 2>/dev/null && find . -type f ! -name '*.md'; true",
+            shq(&format!("{}/content", hugo.base_path))
+        ),
+    )?;
+    Ok(output
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| classify_rel(hugo, l.trim()).0)
+        .collect())
+}
+
+/// 파일이 있는 모든 디렉토리 키 (_index.md 없는 섹션도 목록 페이지가 생긴다)
+fn section_keys(pages: &PageIndex) -> HashSet<String> {
+    let mut dirs = HashSet::new();
+    for f in &pages.files {
+        let mut path = f.rel.as_str();
+        while let Some(i) = path.rfind('/').filter(|&i| i > 0) {
+            path = &path[..i];
+            if !dirs.insert(path.to_string()) {
+                break;
+            }
+        }
+    }
+    dirs
+}
+
+/// 사이트 자신의 URL(`HugoConfig.url`)로 시작하는 절대 URL → 내부 경로
+fn strip_site_url(target: &str, site_url: &str) -> Option<String> {
+    if site_url.is_empty() {
+        return None;
+    }
+    let rest = target.strip_prefix(site_url)?;
+    if rest.is_empty() {
+        Some("/".to_string())
+    } else if rest.starts_with(['/', '#', '?']) {
+        Some(if rest.starts_with('/') { rest.to_string() } else { format!("/{}", rest) })
+    } else {
+        None
+    }
+}
+
+fn is_managed(hugo: &HugoConfig, key: &str) -> bool {
+    let first = key.trim_start_matches('/').split('/').next().unwrap_or("");
+    hugo.content_paths.iter().any(|s| s.trim_matches('/') == first)
+}
+
+/// 사이트 전체 점검. check_external이면 외부 링크/이미지 URL도 요청해 본다.
+pub fn check_site_links(check_external: bool) -> Result<Vec<LinkIssue>> {
+    let hugo = get_hugo_config()?;
+    if hugo.is_empty() {
+        bail!("Hugo config is not set");
+    }
+    let (pages, files) = fetch_markdown(&hugo, None, &[])?;
+    let images = list_image_files(&hugo)?;
+    let resources = list_content_resources(&hugo)?;
+    let sections = section_keys(&pages);
+    let syntax = file_service::site_ref_syntax();
+    let site_url = hugo.url.trim_end_matches('/');
+
+    let mut issues = Vec::new();
+    let mut externals: Vec<ExternalRef> = Vec::new();
+
+    for file in &files {
+        let issue = |line_no: usize, kind: LinkIssueKind, target: &str| LinkIssue {
+            file: file.rel.clone(),
+            hidden: file.hidden,
+            line: line_no as u32,
+            kind,
+            target: target.to_string(),
+            detail: None,
+        };
+        let external = |line_no: usize, url: &str| ExternalRef {
+            file: file.rel.clone(),
+            hidden: file.hidden,
+            line: line_no as u32,
+            url: url.to_string(),
+        };
+
+        // 페이지 링크
+        let body = body_lines(&file.content);
+        for &(line_no, line) in &body {
+            for mut link in find_links(line) {
+                let written = link.target.clone();
+                if let Some(internal) = strip_site_url(&link.target, site_url) {
+                    link.target = internal;
+                }
+                if is_external(split_fragment(&link.target).0) {
+                    if check_external && link.target.starts_with("http") {
+                        externals.push(external(line_no, &link.target));
+                    }
+                    continue;
+                }
+                let Some(resolved) = resolve(&file.rel, &link, &pages) else { continue };
+                if is_asset(&resolved.path) {
+                    continue;
+                }
+                let key = page_key(&resolved.path);
+                match pages.lookup(&key) {
+                    Some(true) if !file.hidden => issues.push(issue(line_no, LinkIssueKind::HiddenTarget, &written)),
+                    Some(_) => {}
+                    None if sections.contains(&key) => {}
+                    None if link.kind == LinkKind::Ref || is_managed(&hugo, &key) => {
+                        issues.push(issue(line_no, LinkIssueKind::BrokenLink, &written));
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        // 이미지/첨부파일 (image_path 기준 경로). 참조형 정의와 front matter 때문에 파일 전체에서 찾는다
+        let body_line_nos: HashSet<usize> = body.iter().map(|(n, _)| *n).collect();
+        for r in find_image_refs(&file.content, &syntax) {
+            let line_no = file.content[..r.start].matches('\n').count() + 1;
+            // 코드 블록 안의 예시는 건너뛴다 (front matter는 본문이 아니지만 점검 대상)
+            if r.kind != ImageRefKind::FrontMatter && !body_line_nos.contains(&line_no) {
+                continue;
+            }
+            if r.path.starts_with("http://") || r.path.starts_with("https://") {
+                // 외부 첨부파일 링크는 페이지 링크 점검에서 이미 수집됨
+                if check_external && r.kind != ImageRefKind::Attachment {
+                    externals.push(external(line_no, &r.path));
+                }
+                continue;
+            }
+            let (path, _) = file_service::split_image_fragment(&r.path);
+            if path.is_empty() || is_external(path) {
+                continue;
+            }
+            let missing_kind = if r.kind == ImageRefKind::Attachment {
+                LinkIssueKind::MissingAttachment
+            } else {
+                LinkIssueKind::MissingImage
+            };
+            // leaf bundle의 상대경로 참조는 번들 디렉토리의 page resource
+            if let Some(dir) = file_service::bundle_dir(&file.rel).filter(|_| !path.starts_with('/')) {
+                if !resources.contains(&normalize_path(dir, path)) {
+                    issues.push(issue(line_no, missing_kind, &r.path));
+                }
+                continue;
+            }
+            // 페이지 디렉토리("/posts/a.md/x.pdf") 밖의 첨부 링크는 static 파일일 수 있어 점검하지 않는다
+            if r.kind == ImageRefKind::Attachment && !path.contains(".md/") {
+                continue;
+            }
+            if !images.contains(&normalize_path("/", path)) {
+                issues.push(issue(line_no, missing_kind, &r.path));
+            }
+        }
+    }
+
+    if !externals.is_empty() {
+        let unique: Vec<String> = externals.iter().map(|e| e.url.clone()).collect::<HashSet<_>>().into_iter().collect();
+        let failures = check_urls(&unique);
+        for e in externals {
+            if let Some(detail) = failures.get(&e.url) {
+                issues.push(LinkIssue {
+                    file: e.file,
+                    hidden: e.hidden,
+                    line: e.line,
+                    kind: LinkIssueKind::ExternalError,
+                    target: e.url,
+                    detail: Some(detail.clone()),
+                });
+            }
+        }
+    }
+
+    issues.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    Ok(issues)
+}
+
+/// 외부 URL 병렬 확인. 실패한 URL → 오류 설명
+fn check_urls(urls: &[String]) -> BTreeMap<String, String> {
+    let agent = ureq::AgentBuilder::new().timeout(EXTERNAL_TIMEOUT).build();
+    let chunk_size = urls.len().div_ceil(EXTERNAL_WORKERS).max(1);
+    thread::scope(|scope| {
+        let handles: Vec<_> = urls
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let agent = agent.clone();
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .filter_map(|url| check_url(&agent, url).map(|detail| (url.clone(), detail)))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles.into_iter().flat_map(|h| h.join().unwrap_or_default()).collect()
+    })
+}
+
+fn check_url(agent: &ureq::Agent, url: &str) -> Option<String> {
+    let (url, _) = file_service::split_image_fragment(url);
+    let result = match agent.head(url).call() {
+        // HEAD를 거부하는 서버가 있어 GET으로 재시도
+        Err(ureq::Error::Status(403 | 405 | 501, _)) => agent.get(url).call(),
+        other => other,
+    };
+    match result {
+        Ok(_) => None,
+        Err(ureq::Error::Status(code, _)) => Some(format!("HTTP {}", code)),
+        Err(e) => Some(e.to_string()),
+    }
+}
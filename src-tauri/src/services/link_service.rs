@@ -0,0 +1,540 @@
+//! 사이트 내부 링크: 마크다운 링크 / 참조 정의(`[id]: url`) / `ref`·`relref` shortcode
+//! 파싱, content 트리 기준 해석, 이동 시 inbound 링크 재작성.
+//!
+//! 경로 규칙 (file_service와 동일): content 상대경로는 섹션을 포함한 "/posts/a.md".
+//! 페이지 키는 URL 기준 경로로, "/posts/a.md" · "/posts/a/_index.md" · "/posts/a/index.md"가 모두 "/posts/a".
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use typeshare::typeshare;
+
+use crate::services::config_service::get_hugo_config;
+use crate::services::file_service::{self, get_file, save_file};
+use crate::services::search_index_service;
+use crate::services::ssh_service::{execute_ssh_command, get_channel_session, get_sftp_session};
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::front_matter::{self, FmValue};
+use crate::utils::shell::quote as shq;
+
+// ── 파싱 ──
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LinkKind {
+    /// `[text](target)` / `[id]: target`
+    Markdown,
+    /// `{{< ref "target" >}}` / `{{< relref "target" >}}`
+    Ref,
+}
+
+/// 한 줄 안의 링크. start는 줄 안에서 target의 byte 위치.
+#[derive(Debug, Clone)]
+pub struct LinkRef {
+    pub kind: LinkKind,
+    pub target: String,
+    pub start: usize,
+}
+
+static MD_LINK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(!?)\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+["'(][^)]*)?\)"#).unwrap()
+});
+static REF_DEF_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s{0,3}\[[^\]]+\]:\s*<?([^\s>]+)").unwrap());
+static REF_SHORTCODE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\{\{[<%]\s*(?:ref|relref)\s+(?:"([^"]*)"|'([^']*)'|([^\s"'>%}]+))"#).unwrap()
+});
+
+/// 한 줄에서 페이지 링크 후보 추출 (이미지 `![..](..)`는 제외)
+pub fn find_links(line: &str) -> Vec<LinkRef> {
+    let mut links = Vec::new();
+    for caps in MD_LINK_RE.captures_iter(line) {
+        if &caps[1] == "!" {
+            continue;
+        }
+        let m = caps.get(2).unwrap();
+        links.push(LinkRef { kind: LinkKind::Markdown, target: m.as_str().to_string(), start: m.start() });
+    }
+    if let Some(m) = REF_DEF_RE.captures(line).and_then(|c| c.get(1)) {
+        links.push(LinkRef { kind: LinkKind::Markdown, target: m.as_str().to_string(), start: m.start() });
+    }
+    for caps in REF_SHORTCODE_RE.captures_iter(line) {
+        let Some(m) = caps.get(1).or_else(|| caps.get(2)).or_else(|| caps.get(3)) else { continue };
+        links.push(LinkRef { kind: LinkKind::Ref, target: m.as_str().to_string(), start: m.start() });
+    }
+    links
+}
+
+/// 본문의 (1-based 줄 번호, 줄) — front matter와 펜스 코드 블록은 건너뛴다
+pub fn body_lines(content: &str) -> Vec<(usize, &str)> {
+    let mut out = Vec::new();
+    let mut front_matter: Option<&str> = None;
+    let mut fence: Option<&str> = None;
+    for (i, line) in content.split('\n').enumerate() {
+        let trimmed = line.trim_end();
+        if i == 0 && (trimmed == "---" || trimmed == "+++") {
+            front_matter = Some(if trimmed == "---" { "---" } else { "+++" });
+            continue;
+        }
+        if let Some(delim) = front_matter {
+            if trimmed == delim {
+                front_matter = None;
+            }
+            continue;
+        }
+        let t = trimmed.trim_start();
+        if let Some(f) = fence {
+            if t.starts_with(f) {
+                fence = None;
+            }
+            continue;
+        }
+        if t.starts_with("```") {
+            fence = Some("```");
+            continue;
+        }
+        if t.starts_with("~~~") {
+            fence = Some("~~~");
+            continue;
+        }
+        out.push((i + 1, line));
+    }
+    out
+}
+
+// ── 경로 ──
+
+/// content 파일 상대경로 → 페이지 키
+pub fn page_key(rel: &str) -> String {
+    let p = rel.trim_end_matches('/');
+    let p = p.strip_suffix(".md").unwrap_or(p);
+    let p = p.strip_suffix("/_index").or_else(|| p.strip_suffix("/index")).unwrap_or(p);
+    if p.is_empty() { "/".to_string() } else { p.to_string() }
+}
+
+fn parent_dir(path: &str) -> &str {
+    match path.rfind('/') {
+        Some(0) | None => "/",
+        Some(i) => &path[..i],
+    }
+}
+
+/// base 디렉토리 기준으로 target을 해석한 절대경로 ('.'/'..' 정리, 끝 '/' 제거)
+pub fn normalize_path(base_dir: &str, target: &str) -> String {
+    let joined = if target.starts_with('/') { target.to_string() } else { format!("{}/{}", base_dir, target) };
+    let mut parts: Vec<&str> = Vec::new();
+    for comp in joined.split('/') {
+        match comp {
+            "" | "." => {}
+            ".." => { parts.pop(); }
+            c => parts.push(c),
+        }
+    }
+    format!("/{}", parts.join("/"))
+}
+
+/// from_dir에서 to로 가는 상대경로
+pub fn relative_path(from_dir: &str, to: &str) -> String {
+    let from: Vec<&str> = from_dir.split('/').filter(|c| !c.is_empty()).collect();
+    let to_parts: Vec<&str> = to.split('/').filter(|c| !c.is_empty()).collect();
+    let common = from.iter().zip(&to_parts).take_while(|(a, b)| a == b).count();
+    let mut parts: Vec<&str> = vec![".."; from.len() - common];
+    parts.extend(&to_parts[common..]);
+    if parts.is_empty() { ".".to_string() } else { parts.join("/") }
+}
+
+/// target에서 `#fragment`/`?query` 분리
+pub fn split_fragment(target: &str) -> (&str, &str) {
+    match target.find(['#', '?']) {
+        Some(i) => (&target[..i], &target[i..]),
+        None => (target, ""),
+    }
+}
+
+pub fn is_external(target: &str) -> bool {
+    target.contains("://") || target.starts_with("//")
+        || ["mailto:", "tel:", "data:", "javascript:"].iter().any(|s| target.starts_with(s))
+}
+
+/// 마지막 경로 요소에 .md 외 확장자가 있으면 정적 파일(이미지/첨부) 링크
+pub fn is_asset(path: &str) -> bool {
+    let last = path.rsplit('/').next().unwrap_or("");
+    match last.rfind('.') {
+        Some(i) if i > 0 => !last[i + 1..].eq_ignore_ascii_case("md"),
+        _ => false,
+    }
+}
+
+// ── 페이지 인덱스 ──
+
+/// 사이트의 모든 .md 파일
+#[derive(Debug, Clone)]
+pub struct PageFile {
+    pub rel: String,
+    pub hidden: bool,
+}
+
+#[derive(Default)]
+pub struct PageIndex {
+    pub files: Vec<PageFile>,
+    keys: HashMap<String, bool>,
+    by_name: HashMap<String, Vec<String>>,
+}
+
+impl PageIndex {
+    pub fn new(files: Vec<PageFile>) -> Self {
+        let mut keys = HashMap::new();
+        let mut by_name: HashMap<String, Vec<String>> = HashMap::new();
+        for f in &files {
+            let key = page_key(&f.rel);
+            if !keys.contains_key(&key) {
+                let name = key.rsplit('/').next().unwrap_or("").to_string();
+                by_name.entry(name).or_default().push(key.clone());
+            }
+            // 같은 키가 content/hidden 양쪽에 있으면 공개 쪽 우선
+            let hidden = keys.get(&key).copied().unwrap_or(true) && f.hidden;
+            keys.insert(key, hidden);
+        }
+        Self { files, keys, by_name }
+    }
+
+    /// 페이지 존재 여부 (Some(hidden))
+    pub fn lookup(&self, key: &str) -> Option<bool> {
+        self.keys.get(key).copied()
+    }
+
+    fn unique_by_name(&self, name: &str) -> Option<&str> {
+        match self.by_name.get(name).map(|v| v.as_slice()) {
+            Some([only]) => Some(only),
+            _ => None,
+        }
+    }
+}
+
+// ── 해석 ──
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LinkMode {
+    /// "/posts/a/" — content 루트 기준
+    Absolute,
+    /// "../a.md" — 파일이 있는 디렉토리 기준
+    FileRelative,
+    /// "../a/" — 페이지 URL 기준 (pretty URL이라 페이지 자체가 디렉토리처럼 동작)
+    UrlRelative,
+    /// ref "posts/a" — 상대 해석 실패 시 content 루트 기준
+    RootRelative,
+    /// ref "a" — 사이트에서 유일한 이름
+    BareName,
+}
+
+impl LinkMode {
+    fn is_relative(self) -> bool {
+        matches!(self, LinkMode::FileRelative | LinkMode::UrlRelative)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Resolved {
+    pub mode: LinkMode,
+    /// 링크가 가리키는 절대경로 (링크에 적힌 형태 유지: .md 유무 등)
+    pub path: String,
+    pub trailing_slash: bool,
+    pub fragment: String,
+}
+
+/// source 페이지(content 상대경로)의 링크를 해석. 외부/앵커 전용 링크는 None.
+pub fn resolve(source: &str, link: &LinkRef, pages: &PageIndex) -> Option<Resolved> {
+    let (path_part, fragment) = split_fragment(&link.target);
+    if path_part.is_empty() || is_external(path_part) {
+        return None;
+    }
+    let trailing_slash = path_part.len() > 1 && path_part.ends_with('/');
+    let file_dir = parent_dir(source);
+
+    let (mode, path) = if path_part.starts_with('/') {
+        (LinkMode::Absolute, normalize_path("/", path_part))
+    } else if link.kind == LinkKind::Markdown {
+        if split_fragment(path_part).0.ends_with(".md") {
+            (LinkMode::FileRelative, normalize_path(file_dir, path_part))
+        } else {
+            (LinkMode::UrlRelative, normalize_path(&page_key(source), path_part))
+        }
+    } else {
+        // Hugo ref: 현재 페이지 기준 → content 루트 기준 → 유일한 이름
+        let relative = normalize_path(file_dir, path_part);
+        let from_root = normalize_path("/", path_part);
+        let name = path_part.trim_end_matches('/');
+        let bare_key = page_key(&format!("/{}", name));
+        let bare_match = if name.contains('/') { None } else { pages.unique_by_name(&bare_key[1..]) };
+        if pages.lookup(&page_key(&relative)).is_some() {
+            (LinkMode::FileRelative, relative)
+        } else if pages.lookup(&page_key(&from_root)).is_some() {
+            (LinkMode::RootRelative, from_root)
+        } else if let Some(key) = bare_match {
+            (LinkMode::BareName, format!("{}/{}", parent_dir(key).trim_end_matches('/'), name))
+        } else {
+            (LinkMode::FileRelative, relative)
+        }
+    };
+
+    Some(Resolved {
+        mode,
+        path,
+        trailing_slash,
+        fragment: fragment.to_string(),
+    })
+}
+
+/// 이동 매핑: 파일 경로 형태(src/dst)와 페이지 키 형태 양쪽으로 비교
+fn map_moved(path: &str, src: &str, dst: &str) -> Option<String> {
+    let prefix_map = |p: &str, from: &str, to: &str| -> Option<String> {
+        if p == from {
+            Some(to.to_string())
+        } else {
+            p.strip_prefix(from).filter(|rest| rest.starts_with('/')).map(|rest| format!("{}{}", to, rest))
+        }
+    };
+    prefix_map(path, src, dst).or_else(|| prefix_map(path, &page_key(src), &page_key(dst)))
+}
+
+/// 해석 결과와 같은 형태로 새 target 문자열 생성
+fn render_target(original: &str, resolved: &Resolved, source: &str, new_path: &str) -> String {
+    let (orig_path, _) = split_fragment(original);
+    let mut text = match resolved.mode {
+        LinkMode::Absolute => new_path.to_string(),
+        LinkMode::RootRelative => new_path.trim_start_matches('/').to_string(),
+        LinkMode::FileRelative => relative_path(parent_dir(source), new_path),
+        LinkMode::UrlRelative => relative_path(&page_key(source), new_path),
+        LinkMode::BareName => new_path.rsplit('/').next().unwrap_or(new_path).to_string(),
+    };
+    if orig_path.starts_with("./") && !text.starts_with('.') {
+        text = format!("./{}", text);
+    }
+    if resolved.trailing_slash && !text.ends_with('/') {
+        text.push('/');
+    }
+    text.push_str(&resolved.fragment);
+    text
+}
+
+// ── 원격 수집 ──
+
+/// 본문을 내려받은 .md 파일
+pub struct MarkdownFile {
+    pub rel: String,
+    pub hidden: bool,
+    pub content: String,
+}
+
+/// 절대경로(content 기준 "./x") → (상대경로, hidden)
+pub fn classify_rel(hugo: &HugoConfig, dot_path: &str) -> (String, bool) {
+    let rel = format!("/{}", dot_path.trim_start_matches("./"));
+    if !hugo.hidden_path.is_empty() {
+        if let Some(rest) = rel.strip_prefix(&format!("/{}", hugo.hidden_path)) {
+            if rest.starts_with('/') {
+                return (rest.to_string(), true);
+            }
+        }
+    }
+    (rel, false)
+}
+
+/// SSH 1회로 전체 .md 목록 + 본문 수집.
+/// needle이 있으면 그 문자열을 포함하는 파일과 include 경로(content 상대) 아래 파일만 본문을 받는다.
+pub fn fetch_markdown(hugo: &HugoConfig, needle: Option<&str>, include: &[String]) -> Result<(PageIndex, Vec<MarkdownFile>)> {
+    let content_dir = format!("{}/content", hugo.base_path);
+    let body_targets = match needle {
+        Some(n) => {
+            let mut extra: Vec<String> = Vec::new();
+            for rel in include {
+                for abs in [hugo.content_abs(rel), hugo.hidden_abs(rel)] {
+                    extra.push(shq(&format!(".{}", abs.strip_prefix(&content_dir).unwrap_or(""))));
+                }
+            }
+            format!(
+                "{{ find . -type f -name '*.md' -print0 | xargs -0 -r grep -lZF -e {} -- ; find {} -type f -name '*.md' -print0 2>/dev/null; }} | sort -zu",
+                shq(n),
+                if extra.is_empty() { "/dev/null".to_string() } else { extra.join(" ") },
+            )
+        }
+        None => "find . -type f -name '*.md' -print0".to_string(),
+    };
+    let cmd = format!(
+        "cd {} || exit 1; echo '---PAGES---'; find . -type f -name '*.md'; echo '---BODIES---'; {} | xargs -0 -r awk 'FNR==1{{printf \"\\036%s\\n\", FILENAME}} {{print}}'; true",
+        shq(&content_dir),
+        body_targets,
+    );
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(&mut channel, &cmd)?;
+
+    let pages_start = output.find("---PAGES---").map(|p| p + "---PAGES---".len());
+    let bodies_start = output.find("---BODIES---");
+    let (Some(ps), Some(bs)) = (pages_start, bodies_start) else {
+        bail!("Failed to list content files");
+    };
+
+    let files: Vec<PageFile> = output[ps..bs]
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let (rel, hidden) = classify_rel(hugo, l.trim());
+            PageFile { rel, hidden }
+        })
+        .collect();
+
+    let mut bodies = Vec::new();
+    let body_output = &output[bs + "---BODIES---".len()..];
+    for chunk in body_output.split('\u{1e}').skip(1) {
+        let (name, content) = chunk.split_once('\n').unwrap_or((chunk, ""));
+        let (rel, hidden) = classify_rel(hugo, name);
+        bodies.push(MarkdownFile { rel, hidden, content: content.to_string() });
+    }
+    Ok((PageIndex::new(files), bodies))
+}
+
+// ── 이동 시 링크 재작성 ──
+
+/// 링크 재작성 한 건
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct LinkRewrite {
+    /// 링크가 있는 파일 (이동 전 content 상대경로)
+    pub file: String,
+    pub hidden: bool,
+    /// 1-based
+    pub line: u32,
+    /// 줄 안의 byte 위치
+    pub column: u32,
+    pub old_target: String,
+    pub new_target: String,
+}
+
+/// src → dst 이동 시 바뀌어야 할 링크 목록.
+/// 다른 페이지의 inbound 링크 + 이동하는 페이지 자신의 상대 링크(깊이가 바뀌는 경우)를 포함한다.
+fn plan_rewrites(hugo: &HugoConfig, src: &str, dst: &str) -> Result<(PageIndex, Vec<LinkRewrite>)> {
+    let src_key = page_key(src);
+    let needle = src_key.rsplit('/').next().unwrap_or("").to_string();
+    if needle.is_empty() {
+        bail!("Invalid source path: {}", src);
+    }
+    let (pages, files) = fetch_markdown(hugo, Some(&needle), &[src.to_string()])?;
+
+    let mut rewrites = Vec::new();
+    for file in &files {
+        let source_new = map_moved(&file.rel, src, dst).unwrap_or_else(|| file.rel.clone());
+        let source_moved = source_new != file.rel;
+        for (line_no, line) in body_lines(&file.content) {
+            for link in find_links(line) {
+                let Some(resolved) = resolve(&file.rel, &link, &pages) else { continue };
+                if is_asset(&resolved.path) {
+                    continue;
+                }
+                let target_new = map_moved(&resolved.path, src, dst);
+                // 상대 링크는 양쪽이 함께 이동하면 그대로 유효하다
+                let needs_rewrite = if resolved.mode.is_relative() {
+                    target_new.is_some() != source_moved
+                } else {
+                    target_new.is_some()
+                };
+                if !needs_rewrite {
+                    continue;
+                }
+                let new_path = target_new.unwrap_or_else(|| resolved.path.clone());
+                let new_target = render_target(&link.target, &resolved, &source_new, &new_path);
+                if new_target != link.target {
+                    rewrites.push(LinkRewrite {
+                        file: file.rel.clone(),
+                        hidden: file.hidden,
+                        line: line_no as u32,
+                        column: link.start as u32,
+                        old_target: link.target.clone(),
+                        new_target,
+                    });
+                }
+            }
+        }
+    }
+    rewrites.sort_by(|a, b| (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column)));
+    Ok((pages, rewrites))
+}
+
+/// 이동 전 미리보기
+pub fn preview_link_rewrites(src: &str, dst: &str) -> Result<Vec<LinkRewrite>> {
+    let hugo = get_hugo_config()?;
+    Ok(plan_rewrites(&hugo, src, dst)?.1)
+}
+
+/// 이동 + inbound 링크 재작성 (+ 선택적으로 옛 URL을 aliases에 추가). 적용된 재작성 목록 반환.
+pub fn move_with_links(src: &str, dst: &str, add_aliases: bool) -> Result<Vec<LinkRewrite>> {
+    let hugo = get_hugo_config()?;
+    let (pages, rewrites) = plan_rewrites(&hugo, src, dst)?;
+    file_service::move_content(src, dst)?;
+
+    let sftp = get_sftp_session()?;
+    let mut applied = Vec::new();
+
+    // 파일별로 묶어서 한 번씩 읽고 쓴다
+    let mut by_file: BTreeMap<(String, bool), Vec<&LinkRewrite>> = BTreeMap::new();
+    for r in &rewrites {
+        by_file.entry((r.file.clone(), r.hidden)).or_default().push(r);
+    }
+    for ((file, hidden), mut items) in by_file {
+        let current = map_moved(&file, src, dst).unwrap_or(file);
+        let abs = if hidden { hugo.hidden_abs(&current) } else { hugo.content_abs(&current) };
+        let Ok(content) = get_file(&sftp, Path::new(&abs)) else { continue };
+        let mut lines: Vec<String> = content.split('\n').map(String::from).collect();
+        // 같은 줄에서는 뒤쪽부터 바꿔야 앞쪽 byte 위치가 유지된다
+        items.sort_by_key(|r| std::cmp::Reverse((r.line, r.column)));
+        for r in items {
+            let Some(line) = lines.get_mut(r.line as usize - 1) else { continue };
+            let start = r.column as usize;
+            let end = start + r.old_target.len();
+            if line.get(start..end) == Some(r.old_target.as_str()) {
+                line.replace_range(start..end, &r.new_target);
+                applied.push(r.clone());
+            }
+        }
+        let updated = lines.join("\n");
+        if updated != content {
+            crate::services::watch_service::mark_self_write(&abs);
+            save_file(&sftp, Path::new(&abs), updated.clone())?;
+            search_index_service::note_saved(&hugo, &abs, &updated);
+        }
+    }
+
+    if add_aliases {
+        add_old_url_aliases(&sftp, &hugo, &pages, src, dst);
+    }
+    applied.sort_by(|a, b| (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column)));
+    Ok(applied)
+}
+
+/// 이동된 공개 페이지마다 옛 URL(기본 permalink 기준)을 aliases에 추가.
+/// front matter에 url이 지정된 페이지는 URL이 바뀌지 않으므로 건너뛴다.
+fn add_old_url_aliases(sftp: &ssh2::Sftp, hugo: &HugoConfig, pages: &PageIndex, src: &str, dst: &str) {
+    let mut seen = HashSet::new();
+    for page in pages.files.iter().filter(|p| !p.hidden) {
+        let Some(new_rel) = map_moved(&page.rel, src, dst) else { continue };
+        if !seen.insert(new_rel.clone()) {
+            continue;
+        }
+        let abs = hugo.content_abs(&new_rel);
+        let Ok(content) = get_file(sftp, Path::new(&abs)) else { continue };
+        if front_matter::get(&content, "url").is_some() {
+            continue;
+        }
+        let old_url = format!("{}/", page_key(&page.rel).trim_end_matches('/'));
+        let mut aliases = front_matter::get_list(&content, "aliases");
+        if aliases.iter().any(|a| a.trim_end_matches('/') == old_url.trim_end_matches('/')) {
+            continue;
+        }
+        aliases.push(old_url);
+        let updated = front_matter::set(&content, "aliases", &FmValue::List(aliases));
+        crate::services::watch_service::mark_self_write(&abs);
+        if save_file(sftp, Path::new(&abs), updated.clone()).is_ok() {
+            search_index_service::note_saved(hugo, &abs, &updated);
+        }
+    }
+}
@@ -6,3 +6,15 @@ pub mod pty_service;
 pub mod plugin_service;
 pub mod transfer_service;
 pub mod fs_service;
+pub mod watch_service;
+pub mod history_service;
+pub mod trash_service;
+pub mod link_service;
+pub mod link_check_service;
+pub mod image_service;
+pub mod taxonomy_service;
+pub mod search_service;
+pub mod search_index_service;
+pub mod replace_service;
+pub mod import_service;
+pub mod export_service;
@@ -0,0 +1,443 @@
+//! 사이트 전체 찾아 바꾸기.
+//!
+//! 1. `preview_replace`: 범위(섹션/hidden/태그) 안의 페이지에서 줄 단위 일치 목록과 바뀐 줄을 보여준다.
+//!    일치마다 파일 안 순번(index)이 붙는다.
+//! 2. `apply_replace`: 사용자가 고른 (파일, 순번)만 바꾼다. 미리보기 때의 버전과 다르면 그 파일은 건너뛴다.
+//!    저장은 에디터와 같은 `write_content`(수동 저장: 리비전/이미지 sync/hook/검색 인덱스)로 한다.
+//! 3. 적용 묶음은 `~/.inn_replace/<site>/<id>.json.gz`에 바꾸기 전 내용과 함께 남아
+//!    `undo_replace_batch`로 한 번에 되돌릴 수 있다 (그 뒤 다시 고친 파일은 건너뜀).
+
+use std::io::prelude::*;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use ssh2::Sftp;
+use typeshare::typeshare;
+
+use crate::services::config_service::get_hugo_config;
+use crate::services::file_service::{self, content_version, mkdir_recursive, site_data_dir};
+use crate::services::link_service::fetch_markdown;
+use crate::services::search_service::HiddenScope;
+use crate::services::ssh_service::get_sftp_session;
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::front_matter;
+
+const REPLACE_DIR: &str = ".inn_replace";
+const BATCH_EXT: &str = ".json.gz";
+/// 보관하는 최근 적용 묶음 수
+const KEEP_BATCHES: usize = 20;
+
+/// 찾아 바꾸기 조건
+#[typeshare]
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReplaceRequest {
+    pub find: String,
+    /// regex 모드에서는 `$1`, `${name}`으로 캡처 그룹 참조
+    pub replace: String,
+    #[serde(default)]
+    pub regex: bool,
+    #[serde(default)]
+    pub case_insensitive: bool,
+    /// 이 섹션(content 상대경로 접두어, 예: "posts") 아래만
+    #[serde(default)]
+    pub section: Option<String>,
+    #[serde(default)]
+    pub hidden: HiddenScope,
+    /// 이 태그를 가진 페이지만 (대소문자 무시)
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+/// 일치 하나
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplaceMatch {
+    /// 파일 안 순번 (적용 시 선택 키)
+    pub index: u32,
+    /// 1-based
+    pub line_num: u32,
+    /// 줄 안의 byte 위치
+    pub column: u32,
+    pub matched: String,
+    pub replacement: String,
+    /// 원래 줄
+    pub before: String,
+    /// 이 일치만 바꾼 줄
+    pub after: String,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplaceFilePreview {
+    pub file: String,
+    pub hidden: bool,
+    /// 미리보기 시점의 내용 버전 (적용 시 그대로 돌려준다)
+    pub version: String,
+    pub matches: Vec<ReplaceMatch>,
+}
+
+/// 적용할 일치 선택
+#[typeshare]
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReplaceSelection {
+    pub file: String,
+    pub version: String,
+    pub indices: Vec<u32>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReplacedFile {
+    pub file: String,
+    pub replaced: u32,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct SkippedFile {
+    pub file: String,
+    pub reason: String,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplaceResult {
+    /// 되돌리기용 묶음 id (바뀐 파일이 없으면 None)
+    pub batch_id: Option<String>,
+    pub files: Vec<ReplacedFile>,
+    pub skipped: Vec<SkippedFile>,
+}
+
+/// 적용 묶음 요약
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplaceBatchSummary {
+    pub id: String,
+    /// 적용 시각 (RFC3339, UTC)
+    pub created_at: String,
+    pub find: String,
+    pub replace: String,
+    pub files: Vec<ReplacedFile>,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplaceUndoResult {
+    pub restored: Vec<String>,
+    pub skipped: Vec<SkippedFile>,
+}
+
+/// 서버에 남기는 묶음 기록
+#[derive(Serialize, Deserialize, Debug)]
+struct BatchRecord {
+    id: String,
+    created_at: String,
+    find: String,
+    replace: String,
+    files: Vec<BatchFile>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct BatchFile {
+    file: String,
+    replaced: u32,
+    /// 바꾸기 전 내용
+    before: String,
+    /// 바꾼 뒤 버전 (되돌리기 전에 그대로인지 확인)
+    after_version: String,
+}
+
+// ── 매칭 ──
+
+fn build_regex(req: &ReplaceRequest) -> Result<Regex> {
+    if req.find.is_empty() {
+        bail!("Search text is empty");
+    }
+    let pattern = if req.regex { req.find.clone() } else { regex::escape(&req.find) };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(req.case_insensitive)
+        .build()
+        .with_context(|| format!("Invalid pattern: {}", req.find))
+}
+
+fn replacement_for(re_caps: &regex::Captures, req: &ReplaceRequest) -> String {
+    if req.regex {
+        let mut out = String::new();
+        re_caps.expand(&req.replace, &mut out);
+        out
+    } else {
+        req.replace.clone()
+    }
+}
+
+/// 내용의 모든 일치를 순서대로 (줄 index, 시작, 끝, 치환 문자열). 빈 일치는 제외
+fn find_matches(content: &str, re: &Regex, req: &ReplaceRequest) -> Vec<(usize, usize, usize, String)> {
+    let mut out = Vec::new();
+    for (i, line) in content.split('\n').enumerate() {
+        for caps in re.captures_iter(line) {
+            let Some(m) = caps.get(0).filter(|m| !m.is_empty()) else { continue };
+            out.push((i, m.start(), m.end(), replacement_for(&caps, req)));
+        }
+    }
+    out
+}
+
+/// 선택한 순번의 일치만 바꾼 내용과 바꾼 개수
+fn apply_selected(content: &str, re: &Regex, req: &ReplaceRequest, indices: &[u32]) -> (String, u32) {
+    let matches = find_matches(content, re, req);
+    let mut lines: Vec<String> = content.split('\n').map(str::to_string).collect();
+    let mut count = 0;
+    // 같은 줄 안의 위치가 밀리지 않도록 뒤에서부터 바꾼다
+    for (index, (line, start, end, replacement)) in matches.into_iter().enumerate().rev() {
+        if indices.contains(&(index as u32)) {
+            lines[line].replace_range(start..end, &replacement);
+            count += 1;
+        }
+    }
+    (lines.join("\n"), count)
+}
+
+fn in_scope(req: &ReplaceRequest, rel: &str, hidden: bool, content: &str) -> bool {
+    let hidden_ok = match req.hidden {
+        HiddenScope::Include => true,
+        HiddenScope::Exclude => !hidden,
+        HiddenScope::Only => hidden,
+    };
+    let section_ok = req
+        .section
+        .as_deref()
+        .map(|s| s.trim_matches('/'))
+        .filter(|s| !s.is_empty())
+        .is_none_or(|s| rel.starts_with(&format!("/{}/", s)));
+    let tag_ok = req
+        .tag
+        .as_deref()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .is_none_or(|t| front_matter::get_list(content, "tags").iter().any(|x| x.eq_ignore_ascii_case(t)));
+    hidden_ok && section_ok && tag_ok
+}
+
+// ── 미리보기 / 적용 ──
+
+/// 바뀔 일치 목록 (아무것도 쓰지 않음). 파일 경로순
+pub fn preview_replace(req: &ReplaceRequest) -> Result<Vec<ReplaceFilePreview>> {
+    let re = build_regex(req)?;
+    let hugo = get_hugo_config()?;
+    // 리터럴이고 대소문자 구분이면 원격에서 미리 좁힌다
+    let needle = (!req.regex && !req.case_insensitive).then_some(req.find.as_str());
+    let (_, files) = fetch_markdown(&hugo, needle, &[])?;
+    let candidates: Vec<_> = files
+        .into_iter()
+        .filter(|f| in_scope(req, &f.rel, f.hidden, &f.content) && re.is_match(&f.content))
+        .collect();
+
+    // 일괄 수집한 본문은 끝 개행이 달라질 수 있어, 버전/순번은 실제 파일로 다시 계산한다
+    let sftp = get_sftp_session()?;
+    let mut previews: Vec<ReplaceFilePreview> = candidates
+        .into_iter()
+        .filter_map(|f| {
+            let abs = if f.hidden { hugo.hidden_abs(&f.rel) } else { hugo.content_abs(&f.rel) };
+            let content = file_service::get_file(&sftp, Path::new(&abs)).ok()?;
+            let lines: Vec<&str> = content.split('\n').collect();
+            let matches: Vec<ReplaceMatch> = find_matches(&content, &re, req)
+                .into_iter()
+                .enumerate()
+                .map(|(index, (line, start, end, replacement))| {
+                    let before = lines[line];
+                    let after = format!("{}{}{}", &before[..start], replacement, &before[end..]);
+                    ReplaceMatch {
+                        index: index as u32,
+                        line_num: line as u32 + 1,
+                        column: start as u32,
+                        matched: before[start..end].to_string(),
+                        replacement,
+                        before: before.to_string(),
+                        after,
+                    }
+                })
+                .collect();
+            (!matches.is_empty()).then(|| ReplaceFilePreview {
+                version: content_version(&content),
+                file: f.rel,
+                hidden: f.hidden,
+                matches,
+            })
+        })
+        .collect();
+    previews.sort_by(|a, b| a.file.cmp(&b.file));
+    Ok(previews)
+}
+
+/// 선택한 일치만 바꿔 저장하고 되돌리기용 묶음을 남긴다
+pub fn apply_replace(req: &ReplaceRequest, selections: &[ReplaceSelection]) -> Result<ReplaceResult> {
+    let re = build_regex(req)?;
+    let hugo = get_hugo_config()?;
+
+    let mut record = BatchRecord {
+        id: new_id(),
+        created_at: front_matter::format_rfc3339(front_matter::now_unix()),
+        find: req.find.clone(),
+        replace: req.replace.clone(),
+        files: Vec::new(),
+    };
+    let mut skipped = Vec::new();
+
+    for sel in selections.iter().filter(|s| !s.indices.is_empty()) {
+        let skip = |reason: String| SkippedFile { file: sel.file.clone(), reason };
+        let current = match file_service::read_content_versioned(&sel.file) {
+            Ok(c) => c,
+            Err(e) => {
+                skipped.push(skip(format!("Failed to read: {}", e)));
+                continue;
+            }
+        };
+        if current.version != sel.version {
+            skipped.push(skip("Changed since preview".to_string()));
+            continue;
+        }
+        let (updated, replaced) = apply_selected(&current.content, &re, req, &sel.indices);
+        if replaced == 0 || updated == current.content {
+            continue;
+        }
+        match file_service::write_content(&sel.file, &updated, true, Some(&current.version)) {
+            Ok(outcome) => record.files.push(BatchFile {
+                file: sel.file.clone(),
+                replaced,
+                before: current.content,
+                after_version: outcome.version,
+            }),
+            Err(e) => skipped.push(skip(format!("Failed to save: {}", e))),
+        }
+    }
+
+    let files: Vec<ReplacedFile> =
+        record.files.iter().map(|f| ReplacedFile { file: f.file.clone(), replaced: f.replaced }).collect();
+    let batch_id = if record.files.is_empty() {
+        None
+    } else {
+        // 기록 실패는 되돌리기만 못 할 뿐 적용은 유지
+        let sftp = get_sftp_session()?;
+        match write_batch(&sftp, &hugo, &record) {
+            Ok(()) => Some(record.id.clone()),
+            Err(e) => {
+                eprintln!("Failed to record replace batch: {:#}", e);
+                None
+            }
+        }
+    };
+    Ok(ReplaceResult { batch_id, files, skipped })
+}
+
+// ── 묶음 기록 / 되돌리기 ──
+
+fn new_id() -> String {
+    use rand::Rng;
+    let suffix: u32 = rand::thread_rng().gen_range(0..0x10000);
+    format!("{}-{:04x}", front_matter::now_unix(), suffix)
+}
+
+fn batch_root(sftp: &Sftp, hugo: &HugoConfig) -> Result<String> {
+    if hugo.site_key().is_empty() {
+        bail!("Hugo base path is not set");
+    }
+    site_data_dir(sftp, REPLACE_DIR, &hugo.site_key())
+}
+
+fn batch_path(root: &str, id: &str) -> Result<String> {
+    if id.is_empty() || id.contains('/') || id.contains("..") {
+        bail!("Invalid batch id: {}", id);
+    }
+    Ok(format!("{}/{}{}", root, id, BATCH_EXT))
+}
+
+/// 묶음 id 목록 (최근순). id가 `<unix초>-<hex>`라 문자열 역순 정렬이 시간 역순이다
+fn list_ids(sftp: &Sftp, root: &str) -> Vec<String> {
+    let mut ids: Vec<String> = sftp
+        .readdir(Path::new(root))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(p, _)| p.file_name()?.to_string_lossy().strip_suffix(BATCH_EXT).map(str::to_string))
+        .collect();
+    ids.sort_by(|a, b| b.cmp(a));
+    ids
+}
+
+fn write_batch(sftp: &Sftp, hugo: &HugoConfig, record: &BatchRecord) -> Result<()> {
+    let root = batch_root(sftp, hugo)?;
+    mkdir_recursive(sftp, Path::new(&root))?;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&serde_json::to_vec(record)?)?;
+    let mut file = sftp.create(Path::new(&batch_path(&root, &record.id)?))?;
+    file.write_all(&encoder.finish()?)?;
+
+    for old in list_ids(sftp, &root).into_iter().skip(KEEP_BATCHES) {
+        if let Ok(path) = batch_path(&root, &old) {
+            let _ = sftp.unlink(Path::new(&path));
+        }
+    }
+    Ok(())
+}
+
+fn read_batch(sftp: &Sftp, root: &str, id: &str) -> Result<BatchRecord> {
+    let file = sftp
+        .open(Path::new(&batch_path(root, id)?))
+        .with_context(|| format!("Replace batch not found: {}", id))?;
+    let mut json = String::new();
+    GzDecoder::new(file).read_to_string(&mut json)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// 되돌릴 수 있는 적용 묶음 목록 (최근순)
+pub fn list_replace_batches() -> Result<Vec<ReplaceBatchSummary>> {
+    let sftp = get_sftp_session()?;
+    let hugo = get_hugo_config()?;
+    let root = batch_root(&sftp, &hugo)?;
+    Ok(list_ids(&sftp, &root)
+        .into_iter()
+        .filter_map(|id| read_batch(&sftp, &root, &id).ok())
+        .map(|r| ReplaceBatchSummary {
+            id: r.id,
+            created_at: r.created_at,
+            find: r.find,
+            replace: r.replace,
+            files: r.files.into_iter().map(|f| ReplacedFile { file: f.file, replaced: f.replaced }).collect(),
+        })
+        .collect())
+}
+
+/// 묶음 되돌리기: 적용 뒤 그대로인 파일만 바꾸기 전 내용으로 저장 (에디터 저장 경로). 끝나면 묶음 기록 삭제
+pub fn undo_replace_batch(id: &str) -> Result<ReplaceUndoResult> {
+    let (root, record) = {
+        let sftp = get_sftp_session()?;
+        let hugo = get_hugo_config()?;
+        let root = batch_root(&sftp, &hugo)?;
+        let record = read_batch(&sftp, &root, id)?;
+        (root, record)
+    };
+
+    let mut restored = Vec::new();
+    let mut skipped = Vec::new();
+    for f in record.files {
+        let skip = |reason: String| SkippedFile { file: f.file.clone(), reason };
+        match file_service::read_content_versioned(&f.file) {
+            Ok(current) if current.version == f.after_version => {
+                match file_service::write_content(&f.file, &f.before, true, Some(&current.version)) {
+                    Ok(_) => restored.push(f.file.clone()),
+                    Err(e) => skipped.push(skip(format!("Failed to save: {}", e))),
+                }
+            }
+            Ok(_) => skipped.push(skip("Modified after replace".to_string())),
+            Err(e) => skipped.push(skip(format!("Failed to read: {}", e))),
+        }
+    }
+
+    let sftp = get_sftp_session()?;
+    let _ = sftp.unlink(Path::new(&batch_path(&root, id)?));
+    Ok(ReplaceUndoResult { restored, skipped })
+}
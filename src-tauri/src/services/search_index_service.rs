@@ -0,0 +1,743 @@
+//! 로컬 전문 검색 인덱스.
+//!
+//! 사이트(서버 + base_path)마다 페이지 본문/front matter의 역색인을
+//! `<OS 캐시 디렉토리>/im-not-notion/search-index/<서버>_<site_key>.json`에 저장해 두고,
+//! 검색은 SSH 없이 로컬에서 처리한다 (오프라인에서도 마지막 상태로 검색 가능).
+//! 본문은 역색인에 넣지 않고 `<서버>_<site_key>/<해시>.md`에 문서별로 두어 스니펫을 만들 때만 읽는다.
+//! 디스크 기록은 `FLUSH_DELAY_MS` 동안 모아 한 번에 한다 (자동 저장이 잦아도 저장마다 전체를 쓰지 않는다).
+//!
+//! 갱신 경로:
+//! - 앱의 저장/이동/삭제/숨김 토글 직후 해당 문서만 반영 (`note_*`, 백그라운드 스레드)
+//! - 검색 시 마지막 동기화가 `SYNC_INTERVAL_SECS`보다 오래됐으면 백그라운드에서 원격 `sha256sum`과 비교해
+//!   바뀐 파일만 다시 받는다 (외부 편집, 플러그인, 일괄 변경 반영)
+//!
+//! 문서 경로는 content 디렉토리 기준(hidden 디렉토리 포함)이라 폴더 이동도 접두어 치환으로 처리된다.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::services::config_service::{get_app_config, get_hugo_config};
+use crate::services::file_service::content_version;
+use crate::services::link_service::classify_rel;
+use crate::services::search_service::HiddenScope;
+use crate::services::ssh_service::{execute_ssh_command, get_channel_session};
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::front_matter;
+use crate::utils::shell::quote as shq;
+
+/// 저장 형식이 바뀌면 올린다 (다른 버전 파일은 버리고 새로 만든다)
+const INDEX_VERSION: u32 = 2;
+/// 검색 시 원격 해시 비교를 다시 하는 간격 (초)
+const SYNC_INTERVAL_SECS: i64 = 300;
+/// 바뀐 파일이 이보다 많으면 목록 대신 전체 본문을 받는다 (명령 길이 제한)
+const FETCH_ALL_THRESHOLD: usize = 200;
+/// 제목 단어의 가중치 (본문 등장 횟수에 더함)
+const TITLE_WEIGHT: u32 = 5;
+/// 검색어 하나가 prefix로 확장되는 최대 단어 수
+const MAX_PREFIX_TERMS: usize = 200;
+const DEFAULT_LIMIT: u32 = 50;
+const MAX_SNIPPETS: usize = 3;
+/// 스니펫 한 줄의 최대 길이 (문자)
+const SNIPPET_WIDTH: usize = 160;
+/// 변경을 모아 디스크에 쓰기까지 기다리는 시간
+const FLUSH_DELAY_MS: u64 = 2000;
+
+#[typeshare]
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct IndexQueryOptions {
+    /// true면 prefix/fuzzy 확장 없이 정확히 일치하는 단어만
+    pub exact: bool,
+    pub hidden: HiddenScope,
+    /// 결과 파일 수 상한 (기본 50)
+    pub limit: Option<u32>,
+}
+
+/// 스니펫 조각. highlight=true인 조각이 검색어에 걸린 단어
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlight: bool,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct IndexSnippet {
+    /// 1-based
+    pub line_num: u32,
+    pub parts: Vec<SnippetPart>,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct IndexHit {
+    pub file_path: String,
+    pub is_hidden: bool,
+    pub title: Option<String>,
+    pub score: f32,
+    pub snippets: Vec<IndexSnippet>,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct IndexSyncReport {
+    pub added: u32,
+    pub updated: u32,
+    pub removed: u32,
+    /// 동기화 후 문서 수
+    pub documents: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct IndexedDoc {
+    /// content 디렉토리 기준 경로 ("/posts/a.md", hidden이면 "/{hidden_path}/posts/a.md")
+    path: String,
+    /// `content_version` (sha256 앞 16자)
+    hash: String,
+    title: Option<String>,
+    /// 이 문서가 들어 있는 색인 단어 (삭제 시 postings 정리용)
+    terms: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct SiteIndex {
+    version: u32,
+    /// 마지막 원격 해시 비교 시각 (unix 초)
+    last_sync: Option<i64>,
+    /// 문서 id = 위치. 삭제된 자리는 None (다음 추가 때 재사용)
+    docs: Vec<Option<IndexedDoc>>,
+    /// 단어 → (문서 id, 가중 등장 횟수)
+    postings: BTreeMap<String, Vec<(u32, u32)>>,
+    #[serde(skip)]
+    by_path: HashMap<String, u32>,
+}
+
+/// 현재 로드된 인덱스 (파일 키, 인덱스)
+static INDEX: Lazy<Mutex<Option<(String, SiteIndex)>>> = Lazy::new(|| Mutex::new(None));
+static SYNCING: AtomicBool = AtomicBool::new(false);
+/// 메모리 인덱스에 디스크에 아직 쓰지 않은 변경이 있음
+static DIRTY: AtomicBool = AtomicBool::new(false);
+/// 디스크 기록 스레드가 돌고 있음
+static FLUSHING: AtomicBool = AtomicBool::new(false);
+
+// ── 토큰화 ──
+
+/// 단어(문자/숫자 연속) 구간의 byte 범위
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                spans.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+    spans
+}
+
+/// 색인 단어로 정규화. 너무 짧거나 긴 단어는 None
+fn normalize_word(word: &str) -> Option<String> {
+    let len = word.chars().count();
+    if !(2..=40).contains(&len) {
+        return None;
+    }
+    Some(word.to_lowercase())
+}
+
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    word_spans(text).into_iter().filter_map(move |(s, e)| normalize_word(&text[s..e]))
+}
+
+/// 편집 거리 (인접 문자 뒤바뀜도 1로 센다: optimal string alignment)
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut d = (rows[i - 1][j - 1] + cost).min(rows[i - 1][j] + 1).min(rows[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = d;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+// ── 인덱스 ──
+
+impl SiteIndex {
+    fn rebuild_paths(&mut self) {
+        self.by_path = self
+            .docs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, d)| d.as_ref().map(|d| (d.path.clone(), i as u32)))
+            .collect();
+    }
+
+    fn document_count(&self) -> u32 {
+        self.by_path.len() as u32
+    }
+
+    fn term_counts(content: &str, title: Option<&str>) -> HashMap<String, u32> {
+        let mut counts: HashMap<String, u32> = HashMap::new();
+        for term in tokenize(content) {
+            *counts.entry(term).or_default() += 1;
+        }
+        for term in title.into_iter().flat_map(tokenize) {
+            *counts.entry(term).or_default() += TITLE_WEIGHT;
+        }
+        counts
+    }
+
+    fn remove(&mut self, path: &str) -> bool {
+        let Some(id) = self.by_path.remove(path) else { return false };
+        let Some(doc) = self.docs[id as usize].take() else { return false };
+        for term in doc.terms {
+            if let Some(list) = self.postings.get_mut(&term) {
+                list.retain(|(d, _)| *d != id);
+                if list.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+        true
+    }
+
+    /// 문서 추가/교체. 이미 같은 해시면 false.
+    /// hash는 원격 파일 기준 값 (awk로 받은 본문은 끝 개행이 달라질 수 있어 다시 계산하지 않는다)
+    fn upsert(&mut self, path: &str, content: &str, hash: String) -> bool {
+        if let Some(&id) = self.by_path.get(path) {
+            if self.docs[id as usize].as_ref().is_some_and(|d| d.hash == hash) {
+                return false;
+            }
+        }
+        self.remove(path);
+
+        let title = front_matter::get(content, "title");
+        let id = match self.docs.iter().position(Option::is_none) {
+            Some(free) => free as u32,
+            None => {
+                self.docs.push(None);
+                (self.docs.len() - 1) as u32
+            }
+        };
+        let mut terms = Vec::new();
+        for (term, count) in Self::term_counts(content, title.as_deref()) {
+            self.postings.entry(term.clone()).or_default().push((id, count));
+            terms.push(term);
+        }
+        self.docs[id as usize] = Some(IndexedDoc { path: path.to_string(), hash, title, terms });
+        self.by_path.insert(path.to_string(), id);
+        true
+    }
+
+    /// src(파일 또는 폴더) 아래 문서 경로를 dst로 치환
+    fn rename_prefix(&mut self, src: &str, dst: &str) {
+        let folder = format!("{}/", src.trim_end_matches('/'));
+        for doc in self.docs.iter_mut().flatten() {
+            if doc.path == src {
+                doc.path = dst.to_string();
+            } else if let Some(rest) = doc.path.strip_prefix(&folder) {
+                doc.path = format!("{}/{}", dst.trim_end_matches('/'), rest);
+            }
+        }
+        self.rebuild_paths();
+    }
+
+    fn remove_prefix(&mut self, path: &str) {
+        let folder = format!("{}/", path.trim_end_matches('/'));
+        let targets: Vec<String> = self
+            .by_path
+            .keys()
+            .filter(|p| *p == path || p.starts_with(&folder))
+            .cloned()
+            .collect();
+        for p in targets {
+            self.remove(&p);
+        }
+    }
+
+    /// 검색어 하나에 해당하는 (단어, 가중치) 목록: 정확히 일치 1.0, prefix 0.7, fuzzy 0.5
+    fn expand(&self, query: &str, exact: bool) -> Vec<(&str, f32)> {
+        let mut out: Vec<(&str, f32)> = Vec::new();
+        if let Some((term, _)) = self.postings.get_key_value(query) {
+            out.push((term.as_str(), 1.0));
+        }
+        if exact {
+            return out;
+        }
+        for (term, _) in self
+            .postings
+            .range::<str, _>((std::ops::Bound::Excluded(query), std::ops::Bound::Unbounded))
+            .take_while(|(t, _)| t.starts_with(query))
+            .take(MAX_PREFIX_TERMS)
+        {
+            out.push((term.as_str(), 0.7));
+        }
+        let q: Vec<char> = query.chars().collect();
+        if q.len() >= 4 {
+            let max_dist = if q.len() >= 8 { 2 } else { 1 };
+            for term in self.postings.keys() {
+                if term.starts_with(query) {
+                    continue;
+                }
+                let t: Vec<char> = term.chars().collect();
+                if t.len().abs_diff(q.len()) <= max_dist && edit_distance(&q, &t) <= max_dist {
+                    out.push((term.as_str(), 0.5));
+                }
+            }
+        }
+        out
+    }
+
+    /// body: 문서 해시 → 본문 (스니펫용, 없으면 스니펫 없이)
+    fn search(&self, hugo: &HugoConfig, query: &str, options: &IndexQueryOptions, body: impl Fn(&str) -> Option<String>) -> Vec<IndexHit> {
+        let mut words: Vec<String> = tokenize(query).collect();
+        words.sort();
+        words.dedup();
+        if words.is_empty() {
+            return Vec::new();
+        }
+        let total = self.document_count().max(1) as f32;
+
+        let mut scores: Option<HashMap<u32, f32>> = None;
+        let mut matched_terms: HashSet<&str> = HashSet::new();
+        for word in &words {
+            let mut word_scores: HashMap<u32, f32> = HashMap::new();
+            for (term, weight) in self.expand(word, options.exact) {
+                let Some(list) = self.postings.get(term) else { continue };
+                matched_terms.insert(term);
+                let idf = (1.0 + total / list.len() as f32).ln();
+                for &(doc, tf) in list {
+                    let s = weight * idf * (tf as f32 / (tf as f32 + 1.2));
+                    let entry = word_scores.entry(doc).or_default();
+                    if s > *entry {
+                        *entry = s;
+                    }
+                }
+            }
+            // 모든 검색어가 있어야 한다 (AND)
+            scores = Some(match scores {
+                None => word_scores,
+                Some(prev) => prev
+                    .into_iter()
+                    .filter_map(|(doc, s)| word_scores.get(&doc).map(|w| (doc, s + w)))
+                    .collect(),
+            });
+        }
+
+        let mut ranked: Vec<(u32, f32)> = scores.unwrap_or_default().into_iter().collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then(a.0.cmp(&b.0)));
+
+        let limit = options.limit.unwrap_or(DEFAULT_LIMIT) as usize;
+        ranked
+            .into_iter()
+            .filter_map(|(id, score)| {
+                let doc = self.docs[id as usize].as_ref()?;
+                let (file_path, is_hidden) = classify_rel(hugo, &format!(".{}", doc.path));
+                let visible = match options.hidden {
+                    HiddenScope::Include => true,
+                    HiddenScope::Exclude => !is_hidden,
+                    HiddenScope::Only => is_hidden,
+                };
+                visible.then(|| IndexHit {
+                    file_path,
+                    is_hidden,
+                    title: doc.title.clone(),
+                    score,
+                    snippets: body(&doc.hash).map(|content| snippets(&content, &matched_terms)).unwrap_or_default(),
+                })
+            })
+            .take(limit)
+            .collect()
+    }
+}
+
+/// 검색어에 걸린 단어가 있는 줄을 최대 MAX_SNIPPETS개, 첫 일치 주변으로 잘라 강조 조각으로 나눈다
+fn snippets(content: &str, terms: &HashSet<&str>) -> Vec<IndexSnippet> {
+    let mut out = Vec::new();
+    for (i, line) in content.split('\n').enumerate() {
+        let hits: Vec<(usize, usize)> = word_spans(line)
+            .into_iter()
+            .filter(|&(s, e)| normalize_word(&line[s..e]).is_some_and(|w| terms.contains(w.as_str())))
+            .collect();
+        let Some(&(first, _)) = hits.first() else { continue };
+
+        // 첫 일치 앞을 1/4 정도 남기고 SNIPPET_WIDTH 문자만큼
+        let lead = SNIPPET_WIDTH / 4;
+        let start = line[..first].char_indices().rev().nth(lead.saturating_sub(1)).map(|(p, _)| p).unwrap_or(0);
+        let end = line[start..].char_indices().nth(SNIPPET_WIDTH).map(|(p, _)| start + p).unwrap_or(line.len());
+
+        let mut parts = Vec::new();
+        let mut pos = start;
+        if start > 0 {
+            parts.push(SnippetPart { text: "…".to_string(), highlight: false });
+        }
+        for (s, e) in hits.into_iter().filter(|&(s, e)| s >= start && e <= end) {
+            if s > pos {
+                parts.push(SnippetPart { text: line[pos..s].to_string(), highlight: false });
+            }
+            parts.push(SnippetPart { text: line[s..e].to_string(), highlight: true });
+            pos = e;
+        }
+        if pos < end {
+            parts.push(SnippetPart { text: line[pos..end].trim_end_matches('\r').to_string(), highlight: false });
+        }
+        if end < line.len() {
+            parts.push(SnippetPart { text: "…".to_string(), highlight: false });
+        }
+        out.push(IndexSnippet { line_num: i as u32 + 1, parts });
+        if out.len() >= MAX_SNIPPETS {
+            break;
+        }
+    }
+    out
+}
+
+// ── 저장소 ──
+
+fn index_dir() -> Result<PathBuf> {
+    let base = dirs_next::cache_dir()
+        .or_else(|| dirs_next::home_dir().map(|h| h.join(".cache")))
+        .context("Failed to determine cache directory")?;
+    Ok(base.join("im-not-notion").join("search-index"))
+}
+
+/// 인덱스 파일 키: 서버 id + site_key (파일명에 안전한 문자만)
+fn index_key(hugo: &HugoConfig) -> Result<String> {
+    if hugo.is_empty() {
+        bail!("Hugo config is not set");
+    }
+    let server = get_app_config()?.active_server;
+    let key = format!("{}_{}", server, hugo.site_key());
+    Ok(key.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' }).collect())
+}
+
+fn load_index(key: &str) -> SiteIndex {
+    let loaded = index_dir()
+        .ok()
+        .and_then(|dir| fs::read(dir.join(format!("{}.json", key))).ok())
+        .and_then(|bytes| serde_json::from_slice::<SiteIndex>(&bytes).ok())
+        .filter(|index| index.version == INDEX_VERSION);
+    let mut index = loaded.unwrap_or(SiteIndex { version: INDEX_VERSION, ..Default::default() });
+    index.rebuild_paths();
+    index
+}
+
+/// 문서 본문 저장소: `<키>/<해시>.md` (같은 내용이면 한 파일을 같이 쓴다)
+fn body_dir(key: &str) -> Result<PathBuf> {
+    Ok(index_dir()?.join(key))
+}
+
+fn store_body(key: &str, hash: &str, content: &str) {
+    let Ok(dir) = body_dir(key) else { return };
+    let file = dir.join(format!("{}.md", hash));
+    if file.exists() {
+        return;
+    }
+    if let Err(e) = fs::create_dir_all(&dir).and_then(|_| fs::write(&file, content)) {
+        eprintln!("Failed to save indexed body: {:#}", e);
+    }
+}
+
+fn read_body(key: &str, hash: &str) -> Option<String> {
+    fs::read_to_string(body_dir(key).ok()?.join(format!("{}.md", hash))).ok()
+}
+
+fn persist(key: &str, index: &SiteIndex) -> Result<()> {
+    let dir = index_dir()?;
+    fs::create_dir_all(&dir)?;
+    let tmp = dir.join(format!("{}.json.tmp", key));
+    fs::write(&tmp, serde_json::to_vec(index)?)?;
+    fs::rename(&tmp, dir.join(format!("{}.json", key)))?;
+
+    // 어느 문서도 쓰지 않는 본문 정리
+    let used: HashSet<String> = index.docs.iter().flatten().map(|d| format!("{}.md", d.hash)).collect();
+    if let Ok(entries) = fs::read_dir(body_dir(key)?) {
+        for entry in entries.flatten() {
+            if !used.contains(entry.file_name().to_string_lossy().as_ref()) {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 해당 사이트 인덱스를 (필요하면 디스크에서 읽어) 잠근 채 f 실행
+fn with_index<R>(key: &str, f: impl FnOnce(&mut SiteIndex) -> R) -> R {
+    let mut guard = INDEX.lock().unwrap_or_else(|p| p.into_inner());
+    if guard.as_ref().map(|(k, _)| k.as_str()) != Some(key) {
+        // 다른 사이트로 바뀌기 전에 쓰지 않은 변경을 기록
+        if let Some((old_key, old)) = guard.as_ref().filter(|_| DIRTY.swap(false, Ordering::SeqCst)) {
+            if let Err(e) = persist(old_key, old) {
+                eprintln!("Failed to save search index: {:#}", e);
+            }
+        }
+        *guard = Some((key.to_string(), load_index(key)));
+    }
+    let (_, index) = guard.as_mut().expect("index loaded above");
+    f(index)
+}
+
+/// 변경 후 디스크 기록 예약. 연달아 바뀌어도 FLUSH_DELAY_MS 동안 모아 한 번만 쓴다
+fn update_and_persist(key: &str, f: impl FnOnce(&mut SiteIndex)) {
+    with_index(key, f);
+    DIRTY.store(true, Ordering::SeqCst);
+    if FLUSHING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    thread::spawn(|| loop {
+        thread::sleep(Duration::from_millis(FLUSH_DELAY_MS));
+        if DIRTY.swap(false, Ordering::SeqCst) {
+            flush();
+            continue;
+        }
+        FLUSHING.store(false, Ordering::SeqCst);
+        // 그 사이 들어온 변경은 이 스레드가 계속 맡는다
+        if !DIRTY.load(Ordering::SeqCst) || FLUSHING.swap(true, Ordering::SeqCst) {
+            break;
+        }
+    });
+}
+
+/// 현재 로드된 인덱스를 디스크에 기록. 실패는 로그만 (다음 동기화에서 복구된다)
+fn flush() {
+    let guard = INDEX.lock().unwrap_or_else(|p| p.into_inner());
+    if let Some((key, index)) = guard.as_ref() {
+        if let Err(e) = persist(key, index) {
+            eprintln!("Failed to save search index: {:#}", e);
+        }
+    }
+}
+
+/// 원격 절대경로 → 인덱스 경로 (content 밖이거나 .md가 아니면 None)
+fn index_path(hugo: &HugoConfig, abs: &str) -> Option<String> {
+    let rel = abs.strip_prefix(&format!("{}/content", hugo.base_path))?;
+    rel.starts_with('/').then(|| rel.to_string())
+}
+
+// ── 앱 내 변경 반영 ──
+
+/// 저장 직후 호출. 인덱스 갱신은 백그라운드에서 한다
+pub fn note_saved(hugo: &HugoConfig, abs: &str, content: &str) {
+    let Some(path) = index_path(hugo, abs).filter(|p| p.ends_with(".md")) else { return };
+    let Ok(key) = index_key(hugo) else { return };
+    let content = content.to_string();
+    thread::spawn(move || update_and_persist(&key, |index| {
+        let hash = content_version(&content);
+        if index.upsert(&path, &content, hash.clone()) {
+            store_body(&key, &hash, &content);
+        }
+    }));
+}
+
+/// 생성/복제 직후 호출. 파일이면 그 파일, 폴더면 아래 .md 전부를 원격에서 읽어 반영한다
+pub fn note_created(hugo: &HugoConfig, abs: &str) {
+    let Some(path) = index_path(hugo, abs) else { return };
+    let Ok(key) = index_key(hugo) else { return };
+    let hugo = hugo.clone();
+    thread::spawn(move || {
+        let targets = format!("find {} -type f -name '*.md' -print0", shq(&format!(".{}", path)));
+        let bodies = match fetch_bodies_with(&hugo, &targets) {
+            Ok(bodies) => bodies,
+            Err(e) => return eprintln!("Failed to index {}: {:#}", path, e),
+        };
+        update_and_persist(&key, |index| {
+            for (path, content) in bodies {
+                let hash = content_version(&content);
+                if index.upsert(&path, &content, hash.clone()) {
+                    store_body(&key, &hash, &content);
+                }
+            }
+        });
+    });
+}
+
+/// 이동(파일/폴더, hidden 토글 포함) 직후 호출
+pub fn note_moved(hugo: &HugoConfig, src_abs: &str, dst_abs: &str) {
+    let (Some(src), Some(dst)) = (index_path(hugo, src_abs), index_path(hugo, dst_abs)) else { return };
+    let Ok(key) = index_key(hugo) else { return };
+    thread::spawn(move || update_and_persist(&key, |index| index.rename_prefix(&src, &dst)));
+}
+
+/// 삭제(파일/폴더) 직후 호출
+pub fn note_removed(hugo: &HugoConfig, abs: &str) {
+    let Some(path) = index_path(hugo, abs) else { return };
+    let Ok(key) = index_key(hugo) else { return };
+    thread::spawn(move || update_and_persist(&key, |index| index.remove_prefix(&path)));
+}
+
+// ── 원격 동기화 ──
+
+/// 원격 .md 파일의 해시 목록: 인덱스 경로 → content_version
+fn remote_hashes(hugo: &HugoConfig) -> Result<HashMap<String, String>> {
+    let content_dir = format!("{}/content", hugo.base_path);
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(
+        &mut channel,
+        &format!(
+            "cd {} || exit 1; echo '---HASHES---'; find . -type f -name '*.md' -exec sha256sum {{}} + 2>/dev/null; true",
+            shq(&content_dir)
+        ),
+    )?;
+    let Some(start) = output.find("---HASHES---") else {
+        bail!("Failed to list content files");
+    };
+    Ok(output[start + "---HASHES---".len()..]
+        .lines()
+        .filter_map(|line| {
+            let (hash, path) = line.split_once("  ")?;
+            Some((path.trim_start_matches('.').to_string(), hash.get(..16)?.to_string()))
+        })
+        .collect())
+}
+
+/// 지정한 파일(None이면 전체)의 본문
+fn fetch_bodies(hugo: &HugoConfig, paths: Option<&[String]>) -> Result<Vec<(String, String)>> {
+    let targets = match paths {
+        Some(list) => format!(
+            "printf '%s\\0' {}",
+            list.iter().map(|p| shq(&format!(".{}", p))).collect::<Vec<_>>().join(" ")
+        ),
+        None => "find . -type f -name '*.md' -print0".to_string(),
+    };
+    fetch_bodies_with(hugo, &targets)
+}
+
+/// targets: content 디렉토리 기준 상대경로를 NUL로 구분해 출력하는 셸 명령
+fn fetch_bodies_with(hugo: &HugoConfig, targets: &str) -> Result<Vec<(String, String)>> {
+    let content_dir = format!("{}/content", hugo.base_path);
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(
+        &mut channel,
+        &format!(
+            "cd {} || exit 1; {} | xargs -0 -r awk 'FNR==1{{printf \"\\036%s\\n\", FILENAME}} {{print}}'; true",
+            shq(&content_dir),
+            targets
+        ),
+    )?;
+    Ok(output
+        .split('\u{1e}')
+        .skip(1)
+        .map(|chunk| {
+            let (name, content) = chunk.split_once('\n').unwrap_or((chunk, ""));
+            (name.trim_start_matches('.').to_string(), content.to_string())
+        })
+        .collect())
+}
+
+/// 원격 해시와 비교해 바뀐 문서만 갱신. rebuild=true면 인덱스를 비우고 전체를 다시 만든다
+pub fn sync_search_index(rebuild: bool) -> Result<IndexSyncReport> {
+    if SYNCING.swap(true, Ordering::SeqCst) {
+        bail!("Search index sync is already running");
+    }
+    let result = run_sync(rebuild);
+    SYNCING.store(false, Ordering::SeqCst);
+    result
+}
+
+fn run_sync(rebuild: bool) -> Result<IndexSyncReport> {
+    let hugo = get_hugo_config()?;
+    let key = index_key(&hugo)?;
+    let known: HashMap<String, String> = if rebuild {
+        HashMap::new()
+    } else {
+        with_index(&key, |index| {
+            index.docs.iter().flatten().map(|d| (d.path.clone(), d.hash.clone())).collect()
+        })
+    };
+
+    let remote = remote_hashes(&hugo)?;
+    let changed: Vec<String> = remote
+        .iter()
+        .filter(|(path, hash)| known.get(*path) != Some(*hash))
+        .map(|(path, _)| path.clone())
+        .collect();
+    let removed: Vec<String> = known.keys().filter(|p| !remote.contains_key(*p)).cloned().collect();
+
+    let bodies = if changed.is_empty() {
+        Vec::new()
+    } else if changed.len() > FETCH_ALL_THRESHOLD {
+        let wanted: HashSet<&String> = changed.iter().collect();
+        fetch_bodies(&hugo, None)?.into_iter().filter(|(p, _)| wanted.contains(p)).collect()
+    } else {
+        fetch_bodies(&hugo, Some(&changed))?
+    };
+
+    let mut report = IndexSyncReport::default();
+    with_index(&key, |index| {
+        if rebuild {
+            *index = SiteIndex { version: INDEX_VERSION, ..Default::default() };
+        }
+        for path in &removed {
+            if index.remove(path) {
+                report.removed += 1;
+            }
+        }
+        for (path, content) in bodies {
+            let Some(hash) = remote.get(&path) else { continue };
+            let existed = index.by_path.contains_key(&path);
+            if index.upsert(&path, &content, hash.clone()) {
+                store_body(&key, hash, &content);
+                if existed {
+                    report.updated += 1;
+                } else {
+                    report.added += 1;
+                }
+            }
+        }
+        index.last_sync = Some(front_matter::now_unix());
+        report.documents = index.document_count();
+        DIRTY.store(false, Ordering::SeqCst);
+        persist(&key, index)
+    })?;
+    Ok(report)
+}
+
+/// 로컬 인덱스 검색. 처음(한 번도 동기화하지 않은 사이트)이면 동기화 후 검색하고,
+/// 마지막 동기화가 오래됐으면 결과는 바로 반환하고 백그라운드에서 동기화한다.
+pub fn query_search_index(query: &str, options: &IndexQueryOptions) -> Result<Vec<IndexHit>> {
+    let hugo = get_hugo_config()?;
+    let key = index_key(&hugo)?;
+
+    let last_sync = with_index(&key, |index| index.last_sync);
+    match last_sync {
+        None if !SYNCING.load(Ordering::SeqCst) => {
+            sync_search_index(false)?;
+        }
+        Some(t) if front_matter::now_unix() - t > SYNC_INTERVAL_SECS && !SYNCING.load(Ordering::SeqCst) => {
+            thread::spawn(|| {
+                if let Err(e) = sync_search_index(false) {
+                    eprintln!("Search index sync failed: {:#}", e);
+                }
+            });
+        }
+        _ => {}
+    }
+
+    Ok(with_index(&key, |index| index.search(&hugo, query, options, |hash| read_body(&key, hash))))
+}
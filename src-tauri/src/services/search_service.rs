@@ -0,0 +1,513 @@
+//! 구조화 쿼리 검색.
+//!
+//! 쿼리 문법 (공백으로 구분, 모든 조건 AND):
+//! - `word`, `"exact phrase"`: 본문(front matter 포함)에 있어야 하는 텍스트
+//! - `-word`, `-"phrase"`, `-tag:x`: 제외 조건
+//! - `key:value`: front matter 필드 일치 (대소문자 무시). 리스트 필드는 항목 중 하나라도 일치하면 통과.
+//!   key가 경로 조건/별칭, Hugo 기본 필드, 검색 대상 페이지에 실제로 있는 키가 아니면 필터가 아니라 텍스트로 본다
+//!   (`https://example.com`, `error:foo`, `a<b` 같은 일반 검색어가 필터로 해석되지 않도록).
+//!   `tag:`/`category:`는 `tags`/`categories` 별칭, `tag:a,b`처럼 쉼표로 나열하면 OR.
+//!   값이 `false`이고 키가 없는 페이지는 일치로 본다 (`draft:false`가 draft 키 없는 페이지도 포함하도록).
+//! - `section:posts`, `path:2024`: 경로 조건 (섹션 접두어 / 경로 부분 문자열)
+//! - `key>=v`, `key>v`, `key<=v`, `key<v`: 둘 다 숫자면 숫자 비교, 아니면 필드 값을 v 길이만큼 잘라 문자열 비교
+//!   (`date>=2024-01`이 2024년 1월 이후 전체를 뜻하도록)
+//!
+//! regex 모드에서는 텍스트 조건을 정규식으로 해석한다 (따옴표는 공백을 묶는 용도).
+
+use std::cmp::Ordering;
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::services::config_service::{get_app_config, get_hugo_config};
+use crate::services::link_service::{fetch_markdown, MarkdownFile};
+use crate::utils::front_matter;
+
+/// 결과 파일 수 기본 상한
+const DEFAULT_LIMIT: u32 = 200;
+/// 매칭 줄 앞뒤 기본 문맥 줄 수
+const DEFAULT_CONTEXT: u32 = 2;
+/// 파일당 반환하는 매칭 줄 상한 (점수 계산은 전체 기준)
+const MAX_LINES_PER_FILE: usize = 20;
+/// 제목에 텍스트 조건이 있을 때 가산점
+const TITLE_BONUS: u32 = 10;
+/// 경로(파일명)에 텍스트 조건이 있을 때 가산점
+const PATH_BONUS: u32 = 3;
+
+/// hidden 페이지 포함 범위
+#[typeshare]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum HiddenScope {
+    #[default]
+    Include,
+    Exclude,
+    Only,
+}
+
+#[typeshare]
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SearchOptions {
+    /// 텍스트 조건을 정규식으로 해석
+    pub regex: bool,
+    pub case_insensitive: bool,
+    pub hidden: HiddenScope,
+    /// 매칭 줄 앞뒤 문맥 줄 수 (기본 2)
+    pub context_lines: Option<u32>,
+    /// 결과 파일 수 상한 (기본 200)
+    pub limit: Option<u32>,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct SearchLine {
+    /// 1-based
+    pub line_num: u32,
+    pub line_text: String,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct SearchHit {
+    /// content 상대경로 (hidden이면 hidden_path 제외)
+    pub file_path: String,
+    pub is_hidden: bool,
+    pub title: Option<String>,
+    /// 높을수록 관련도 높음. 필터만 있는 쿼리는 0
+    pub score: u32,
+    /// 텍스트 조건에 걸린 줄 (필터만 있는 쿼리는 비어 있음)
+    pub matches: Vec<SearchLine>,
+}
+
+// ── 쿼리 파싱 ──
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cmp {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+#[derive(Debug, Clone)]
+struct FieldFilter {
+    key: String,
+    cmp: Cmp,
+    values: Vec<String>,
+    negate: bool,
+    /// 원래 토큰 (알 수 없는 키면 텍스트 조건으로 되돌린다)
+    raw: String,
+}
+
+#[derive(Debug, Clone)]
+struct TextTerm {
+    text: String,
+    negate: bool,
+}
+
+#[derive(Debug, Default)]
+struct ParsedQuery {
+    terms: Vec<TextTerm>,
+    filters: Vec<FieldFilter>,
+}
+
+/// 토큰 단위 분리: 공백 구분, 따옴표 안의 공백 유지. (토큰, 전체가 따옴표였는지, 제외 여부)
+fn tokenize(query: &str) -> Vec<(String, bool, bool)> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut negate = false;
+        if c == '-' {
+            chars.next();
+            // 홀로 떨어진 '-'는 무시
+            match chars.peek() {
+                Some(n) if !n.is_whitespace() => negate = true,
+                _ => continue,
+            }
+        }
+        let quoted = chars.peek() == Some(&'"');
+        let mut token = String::new();
+        let mut in_quote = false;
+        while let Some(&c) = chars.peek() {
+            if c == '"' {
+                in_quote = !in_quote;
+                chars.next();
+                if quoted && !in_quote {
+                    break;
+                }
+                continue;
+            }
+            if c.is_whitespace() && !in_quote {
+                break;
+            }
+            token.push(c);
+            chars.next();
+        }
+        if !token.is_empty() {
+            tokens.push((token, quoted, negate));
+        }
+    }
+    tokens
+}
+
+static FILTER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([A-Za-z_][A-Za-z0-9_.-]*)(>=|<=|:|>|<)(.+)$").unwrap());
+
+/// 페이지에 없어도 항상 필터로 보는 키: 경로 조건 + Hugo 기본 front matter 필드 (소문자)
+const KNOWN_KEYS: &[&str] = &[
+    "section", "path", "tags", "categories", "title", "date", "lastmod", "publishdate", "expirydate",
+    "draft", "weight", "slug", "url", "aliases", "description", "summary", "keywords", "type", "layout",
+];
+
+fn parse_query(query: &str) -> ParsedQuery {
+    let mut parsed = ParsedQuery::default();
+    for (token, quoted, negate) in tokenize(query) {
+        if !quoted {
+            if let Some(caps) = FILTER_RE.captures(&token) {
+                let key = match caps[1].to_lowercase().as_str() {
+                    "tag" => "tags".to_string(),
+                    "category" => "categories".to_string(),
+                    k => k.to_string(),
+                };
+                let cmp = match &caps[2] {
+                    ">=" => Cmp::Ge,
+                    "<=" => Cmp::Le,
+                    ">" => Cmp::Gt,
+                    "<" => Cmp::Lt,
+                    _ => Cmp::Eq,
+                };
+                let values = if cmp == Cmp::Eq {
+                    caps[3].split(',').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect()
+                } else {
+                    vec![caps[3].to_string()]
+                };
+                parsed.filters.push(FieldFilter { key, cmp, values, negate, raw: token });
+                continue;
+            }
+        }
+        parsed.terms.push(TextTerm { text: token, negate });
+    }
+    parsed
+}
+
+/// 알려진 키도 아니고 어느 페이지에도 없는 키의 필터는 텍스트 조건으로 되돌린다
+fn demote_unknown_filters(parsed: &mut ParsedQuery, files: &[MarkdownFile]) {
+    let (keep, unknown): (Vec<FieldFilter>, Vec<FieldFilter>) = std::mem::take(&mut parsed.filters)
+        .into_iter()
+        .partition(|f| {
+            KNOWN_KEYS.contains(&f.key.as_str()) || files.iter().any(|file| front_matter::has_key(&file.content, &f.key))
+        });
+    parsed.filters = keep;
+    parsed.terms.extend(unknown.into_iter().map(|f| TextTerm { text: f.raw, negate: f.negate }));
+}
+
+// ── 필터 ──
+
+fn compare(field: &str, value: &str) -> Ordering {
+    if let (Ok(a), Ok(b)) = (field.parse::<f64>(), value.parse::<f64>()) {
+        return a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+    }
+    let prefix: String = field.chars().take(value.chars().count()).collect();
+    prefix.to_lowercase().cmp(&value.to_lowercase())
+}
+
+fn filter_matches(filter: &FieldFilter, file: &MarkdownFile) -> bool {
+    let hit = match filter.key.as_str() {
+        "section" => filter.values.iter().any(|v| {
+            let v = v.trim_matches('/');
+            file.rel.to_lowercase().starts_with(&format!("/{}/", v.to_lowercase()))
+        }),
+        "path" => filter.values.iter().any(|v| file.rel.to_lowercase().contains(&v.to_lowercase())),
+        key => {
+            let field = front_matter::get_list(&file.content, key);
+            match filter.cmp {
+                Cmp::Eq if field.is_empty() => filter.values.iter().any(|v| v.eq_ignore_ascii_case("false")),
+                Cmp::Eq => field.iter().any(|f| filter.values.iter().any(|v| f.eq_ignore_ascii_case(v))),
+                cmp => {
+                    let Some(value) = filter.values.first() else { return false };
+                    field.iter().any(|f| {
+                        let ord = compare(f, value);
+                        match cmp {
+                            Cmp::Gt => ord == Ordering::Greater,
+                            Cmp::Ge => ord != Ordering::Less,
+                            Cmp::Lt => ord == Ordering::Less,
+                            _ => ord != Ordering::Greater,
+                        }
+                    })
+                }
+            }
+        }
+    };
+    hit != filter.negate
+}
+
+// ── 텍스트 매칭 ──
+
+struct Matcher {
+    re: Regex,
+    negate: bool,
+}
+
+fn build_matchers(terms: &[TextTerm], options: &SearchOptions) -> Result<Vec<Matcher>> {
+    terms
+        .iter()
+        .map(|t| {
+            let pattern = if options.regex { t.text.clone() } else { regex::escape(&t.text) };
+            let re = RegexBuilder::new(&pattern)
+                .case_insensitive(options.case_insensitive)
+                .build()
+                .with_context(|| format!("Invalid pattern: {}", t.text))?;
+            Ok(Matcher { re, negate: t.negate })
+        })
+        .collect()
+}
+
+/// 긴 줄은 잘라서 반환
+fn clip(line: &str) -> String {
+    const MAX: usize = 300;
+    let line = line.trim_end_matches('\r');
+    if line.len() <= MAX {
+        return line.to_string();
+    }
+    let mut end = MAX;
+    while !line.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}…", &line[..end])
+}
+
+/// 파일 하나 평가. 조건을 만족하지 않으면 None
+fn evaluate(file: &MarkdownFile, matchers: &[Matcher], context: usize) -> Option<SearchHit> {
+    let lines: Vec<&str> = file.content.split('\n').collect();
+    let title = front_matter::get(&file.content, "title");
+
+    let mut score = 0u32;
+    let mut matched_lines: Vec<usize> = Vec::new();
+    for m in matchers {
+        let hits: Vec<usize> = lines.iter().enumerate().filter(|(_, l)| m.re.is_match(l)).map(|(i, _)| i).collect();
+        if m.negate {
+            if !hits.is_empty() {
+                return None;
+            }
+            continue;
+        }
+        if hits.is_empty() {
+            return None;
+        }
+        score += hits.len().min(10) as u32;
+        if title.as_deref().is_some_and(|t| m.re.is_match(t)) {
+            score += TITLE_BONUS;
+        }
+        if m.re.is_match(&file.rel) {
+            score += PATH_BONUS;
+        }
+        matched_lines.extend(hits);
+    }
+    matched_lines.sort_unstable();
+    matched_lines.dedup();
+
+    let matches = matched_lines
+        .into_iter()
+        .take(MAX_LINES_PER_FILE)
+        .map(|i| SearchLine {
+            line_num: i as u32 + 1,
+            line_text: clip(lines[i]),
+            before: lines[i.saturating_sub(context)..i].iter().map(|l| clip(l)).collect(),
+            after: lines[i + 1..(i + 1 + context).min(lines.len())].iter().map(|l| clip(l)).collect(),
+        })
+        .collect();
+
+    Some(SearchHit { file_path: file.rel.clone(), is_hidden: file.hidden, title, score, matches })
+}
+
+/// 원격에서 미리 좁힐 수 있는 리터럴 (가장 긴 포함 조건). regex/대소문자 무시 모드에서는 없음
+fn prefilter_needle<'a>(terms: &'a [TextTerm], options: &SearchOptions) -> Option<&'a str> {
+    if options.regex || options.case_insensitive {
+        return None;
+    }
+    terms.iter().filter(|t| !t.negate).map(|t| t.text.as_str()).max_by_key(|t| t.len())
+}
+
+/// 파싱 + 검증된 쿼리
+struct PreparedQuery {
+    parsed: ParsedQuery,
+    matchers: Vec<Matcher>,
+}
+
+/// files: 검색 대상 페이지 (필터 키 판별용)
+fn prepare(query: &str, options: &SearchOptions, files: &[MarkdownFile]) -> Result<Option<PreparedQuery>> {
+    let mut parsed = parse_query(query);
+    demote_unknown_filters(&mut parsed, files);
+    if parsed.terms.is_empty() && parsed.filters.is_empty() {
+        return Ok(None);
+    }
+    if parsed.terms.iter().all(|t| t.negate) && parsed.filters.is_empty() {
+        bail!("Query has only exclusions");
+    }
+    let matchers = build_matchers(&parsed.terms, options)?;
+    Ok(Some(PreparedQuery { parsed, matchers }))
+}
+
+/// 받아 둔 파일들에 쿼리 적용. 점수 내림차순, 같으면 경로순
+fn run_query(files: &[MarkdownFile], query: &PreparedQuery, options: &SearchOptions) -> Vec<SearchHit> {
+    let context = options.context_lines.unwrap_or(DEFAULT_CONTEXT) as usize;
+    let mut hits: Vec<SearchHit> = files
+        .iter()
+        .filter(|f| match options.hidden {
+            HiddenScope::Include => true,
+            HiddenScope::Exclude => !f.hidden,
+            HiddenScope::Only => f.hidden,
+        })
+        .filter(|f| query.parsed.filters.iter().all(|filter| filter_matches(filter, f)))
+        .filter_map(|f| evaluate(f, &query.matchers, context))
+        .collect();
+
+    hits.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.file_path.cmp(&b.file_path)));
+    hits.truncate(options.limit.unwrap_or(DEFAULT_LIMIT) as usize);
+    hits
+}
+
+/// 구조화 쿼리 검색. 점수 내림차순, 같으면 경로순
+pub fn search_query(query: &str, options: &SearchOptions) -> Result<Vec<SearchHit>> {
+    // 필터처럼 보이는 토큰은 텍스트가 될 수도 있으므로 원격 prefilter에는 확실한 텍스트 조건만 쓴다
+    let parsed = parse_query(query);
+    if parsed.terms.is_empty() && parsed.filters.is_empty() {
+        return Ok(Vec::new());
+    }
+    let hugo = get_hugo_config()?;
+    let (_, files) = fetch_markdown(&hugo, prefilter_needle(&parsed.terms, options), &[])?;
+    let Some(prepared) = prepare(query, options, &files)? else { return Ok(Vec::new()) };
+    Ok(run_query(&files, &prepared, options))
+}
+
+// ── 저장된 검색 (스마트 폴더) ──
+
+/// 스마트 폴더 안의 페이지
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct SavedSearchPage {
+    /// content 상대경로 (hidden이면 hidden_path 제외)
+    pub path: String,
+    pub is_hidden: bool,
+    pub title: Option<String>,
+}
+
+/// 저장된 검색 하나를 실행한 가상 폴더
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct SavedSearchFolder {
+    pub name: String,
+    pub query: String,
+    pub pages: Vec<SavedSearchPage>,
+    /// 쿼리를 실행하지 못한 이유 (잘못된 쿼리 등). 있으면 pages는 비어 있다
+    pub error: Option<String>,
+}
+
+/// 서버 설정의 저장된 검색을 모두 실행 (본문은 SSH 1회로 한 번만 받는다). 설정 순서 유지
+pub fn list_saved_search_folders() -> Result<Vec<SavedSearchFolder>> {
+    let searches = get_app_config()?.saved_searches;
+    if searches.is_empty() {
+        return Ok(Vec::new());
+    }
+    let hugo = get_hugo_config()?;
+    let (_, files) = fetch_markdown(&hugo, None, &[])?;
+    // 스마트 폴더는 일치 페이지를 모두 보여준다 (기본 상한 없음)
+    let options = SearchOptions { context_lines: Some(0), limit: Some(u32::MAX), ..Default::default() };
+
+    Ok(searches
+        .into_iter()
+        .map(|saved| {
+            let (pages, error) = match prepare(&saved.query, &options, &files) {
+                Ok(Some(prepared)) => (
+                    run_query(&files, &prepared, &options)
+                        .into_iter()
+                        .map(|hit| SavedSearchPage { path: hit.file_path, is_hidden: hit.is_hidden, title: hit.title })
+                        .collect(),
+                    None,
+                ),
+                Ok(None) => (Vec::new(), Some("Query is empty".to_string())),
+                Err(e) => (Vec::new(), Some(e.to_string())),
+            };
+            SavedSearchFolder { name: saved.name, query: saved.query, pages, error }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_keeps_quoted_spaces() {
+        assert_eq!(
+            tokenize(r#"hello "big world" tag:"a b""#),
+            vec![
+                ("hello".to_string(), false, false),
+                ("big world".to_string(), true, false),
+                ("tag:a b".to_string(), false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_negation() {
+        assert_eq!(
+            tokenize(r#"-draft -"two words" - a-b"#),
+            vec![
+                ("draft".to_string(), false, true),
+                ("two words".to_string(), true, true),
+                ("a-b".to_string(), false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_query_comparison_filter() {
+        let parsed = parse_query("rust date>=2024-01");
+        assert_eq!(parsed.terms.len(), 1);
+        assert_eq!(parsed.terms[0].text, "rust");
+        let filter = &parsed.filters[0];
+        assert_eq!((filter.key.as_str(), filter.cmp), ("date", Cmp::Ge));
+        assert_eq!(filter.values, vec!["2024-01"]);
+    }
+
+    #[test]
+    fn parse_query_aliases_and_lists() {
+        let parsed = parse_query("-tag:a,b category:x");
+        assert_eq!(parsed.filters[0].key, "tags");
+        assert!(parsed.filters[0].negate);
+        assert_eq!(parsed.filters[0].values, vec!["a", "b"]);
+        assert_eq!(parsed.filters[1].key, "categories");
+    }
+
+    #[test]
+    fn quoted_filter_is_text() {
+        let parsed = parse_query(r#""a:b""#);
+        assert!(parsed.filters.is_empty());
+        assert_eq!(parsed.terms[0].text, "a:b");
+    }
+
+    #[test]
+    fn unknown_keys_become_text() {
+        let mut parsed = parse_query("http://x note:todo -mood:ok date>2024");
+        let files = vec![MarkdownFile {
+            rel: "/posts/a.md".to_string(),
+            hidden: false,
+            content: "---\nmood: ok\n---\n".to_string(),
+        }];
+        demote_unknown_filters(&mut parsed, &files);
+        let keys: Vec<&str> = parsed.filters.iter().map(|f| f.key.as_str()).collect();
+        assert_eq!(keys, vec!["mood", "date"]);
+        let terms: Vec<&str> = parsed.terms.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(terms, vec!["http://x", "note:todo"]);
+    }
+}
@@ -68,6 +68,16 @@ fn connect_inner(ssh_config: &SshConfig, force: bool) -> Result<()> {
         SSH_GENERATION.fetch_add(1, Ordering::SeqCst);
     }
 
+    let session = open_session(ssh_config)?;
+
+    let mut ssh_client = SSH_CLIENT.lock().unwrap_or_else(|p| p.into_inner());
+    *ssh_client = Some(session);
+
+    Ok(())
+}
+
+/// 전역 세션과 별개의 새 SSH 세션 생성 (장시간 점유하는 워커 스레드용)
+pub fn open_session(ssh_config: &SshConfig) -> Result<Session> {
     let mut session = Session::new().context("Failed to create SSH session")?;
     let addr = format!("{}:{}", ssh_config.host, ssh_config.port);
     let sock_addr = addr.to_socket_addrs()
@@ -85,11 +95,7 @@ fn connect_inner(ssh_config: &SshConfig, force: bool) -> Result<()> {
         session.userauth_password(&ssh_config.username, &ssh_config.password)
             .context("Failed to authenticate with password")?;
     }
-
-    let mut ssh_client = SSH_CLIENT.lock().unwrap_or_else(|p| p.into_inner());
-    *ssh_client = Some(session);
-
-    Ok(())
+    Ok(session)
 }
 
 /// SshConfig를 직접 받아 연결 (기존 세션 재사용)
@@ -0,0 +1,209 @@
+//! Hugo taxonomy(tags, categories 등) 용어 관리: 용어별 페이지 수, 이름 변경/병합.
+//!
+//! taxonomy 목록은 사이트 설정의 `[taxonomies]`(TOML) / `taxonomies:`(YAML) 블록에서 읽고,
+//! 없으면 Hugo 기본값(tags, categories)을 쓴다. 용어는 대소문자를 구분해 그대로 보여준다
+//! (`rust`/`Rust` 같은 중복을 찾아 병합할 수 있도록).
+//! 변경은 content + hidden 모든 페이지의 front matter에 적용하고, 페이지마다 리비전을 남긴다.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::services::config_service::get_hugo_config;
+use crate::services::file_service::{get_file, save_file, SITE_CONFIG_FILES};
+use crate::services::history_service;
+use crate::services::link_service::{fetch_markdown, MarkdownFile};
+use crate::services::search_index_service;
+use crate::services::ssh_service::{execute_ssh_command, get_channel_session, get_sftp_session};
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::front_matter::{self, FmValue};
+use crate::utils::shell::quote as shq;
+
+/// 설정에 taxonomies가 없을 때 Hugo 기본값
+const DEFAULT_TAXONOMIES: &[&str] = &["tags", "categories"];
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct TaxonomyTerm {
+    pub term: String,
+    /// 이 용어를 가진 페이지 수 (hidden 포함)
+    pub count: u32,
+    /// 그중 hidden 페이지 수
+    pub hidden_count: u32,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct Taxonomy {
+    /// front matter 키 (복수형, 예: "tags")
+    pub name: String,
+    /// 페이지 수 내림차순, 같으면 이름순
+    pub terms: Vec<TaxonomyTerm>,
+}
+
+/// 용어 이름 변경(from 1개) / 병합(from 여러 개)
+#[typeshare]
+#[derive(Deserialize, Debug, Clone)]
+pub struct TaxonomyChange {
+    pub taxonomy: String,
+    /// 바꿀 용어들 (정확히 일치하는 것만)
+    pub from: Vec<String>,
+    /// 새 용어. 비어 있으면 from 용어를 페이지에서 제거
+    pub to: String,
+}
+
+/// 페이지 하나의 변경 전/후 용어 목록
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct TaxonomyPageChange {
+    pub file: String,
+    pub hidden: bool,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+/// 사이트 설정의 taxonomy 키 (복수형 값)
+fn site_taxonomies(hugo: &HugoConfig) -> Result<Vec<String>> {
+    // TOML [taxonomies] 테이블 / YAML taxonomies: 블록 안의 `단수 = "복수"` 줄만 출력
+    let awk = r#"tolower($0)~/^\[taxonomies\]/{t=1;next}
+tolower($0)~/^taxonomies[[:space:]]*:/{t=2;next}
+t==1&&/^[[:space:]]*\[/{t=0}
+t==2&&/^[^[:space:]#]/{t=0}
+t&&/[:=]/{print}"#;
+    let mut channel = get_channel_session()?;
+    let output = execute_ssh_command(
+        &mut channel,
+        &format!("cd {} || exit 1; cat {} 2>/dev/null | awk {}; true", shq(&hugo.base_path), SITE_CONFIG_FILES, shq(awk)),
+    )?;
+
+    let mut names: Vec<String> = Vec::new();
+    for line in output.lines() {
+        let Some(sep) = line.find([':', '=']) else { continue };
+        let plural = front_matter::unquote(line[sep + 1..].trim()).trim();
+        if !plural.is_empty() && !names.iter().any(|n| n == plural) {
+            names.push(plural.to_string());
+        }
+    }
+    if names.is_empty() {
+        names = DEFAULT_TAXONOMIES.iter().map(|s| s.to_string()).collect();
+    }
+    Ok(names)
+}
+
+/// 모든 페이지 (content + hidden)
+fn fetch_pages(hugo: &HugoConfig) -> Result<Vec<MarkdownFile>> {
+    let (_, files) = fetch_markdown(hugo, None, &[])?;
+    Ok(files)
+}
+
+/// taxonomy별 용어 + 페이지 수
+pub fn list_taxonomies() -> Result<Vec<Taxonomy>> {
+    let hugo = get_hugo_config()?;
+    let names = site_taxonomies(&hugo)?;
+    let files = fetch_pages(&hugo)?;
+
+    Ok(names
+        .into_iter()
+        .map(|name| {
+            let mut counts: BTreeMap<String, (u32, u32)> = BTreeMap::new();
+            for file in &files {
+                let mut terms = front_matter::get_list(&file.content, &name);
+                terms.sort();
+                terms.dedup();
+                for term in terms {
+                    let entry = counts.entry(term).or_default();
+                    entry.0 += 1;
+                    if file.hidden {
+                        entry.1 += 1;
+                    }
+                }
+            }
+            let mut terms: Vec<TaxonomyTerm> = counts
+                .into_iter()
+                .map(|(term, (count, hidden_count))| TaxonomyTerm { term, count, hidden_count })
+                .collect();
+            terms.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.term.cmp(&b.term)));
+            Taxonomy { name, terms }
+        })
+        .collect())
+}
+
+/// 용어 목록에 변경 적용: from → to (중복은 처음 것만 유지, to가 비면 제거)
+fn apply_to_terms(terms: &[String], change: &TaxonomyChange) -> Vec<String> {
+    let to = change.to.trim();
+    let mut out: Vec<String> = Vec::with_capacity(terms.len());
+    for term in terms {
+        let mapped = if change.from.iter().any(|f| f == term) { to } else { term.as_str() };
+        if !mapped.is_empty() && !out.iter().any(|t| t == mapped) {
+            out.push(mapped.to_string());
+        }
+    }
+    out
+}
+
+/// 페이지 내용에 변경 적용. 바뀌지 않으면 None
+fn rewrite_page(content: &str, change: &TaxonomyChange) -> Option<(Vec<String>, Vec<String>, String)> {
+    let before = front_matter::get_list(content, &change.taxonomy);
+    if !before.iter().any(|t| change.from.contains(t)) {
+        return None;
+    }
+    let after = apply_to_terms(&before, change);
+    let updated = if after.is_empty() {
+        front_matter::remove(content, &change.taxonomy)
+    } else {
+        front_matter::set(content, &change.taxonomy, &FmValue::List(after.clone()))
+    };
+    Some((before, after, updated))
+}
+
+fn validate(change: &TaxonomyChange) -> Result<()> {
+    if change.taxonomy.trim().is_empty() {
+        bail!("Taxonomy is not set");
+    }
+    if change.from.iter().all(|f| f.trim().is_empty()) {
+        bail!("No terms to change");
+    }
+    Ok(())
+}
+
+/// 변경 미리보기 (아무것도 쓰지 않음)
+pub fn preview_taxonomy_change(change: &TaxonomyChange) -> Result<Vec<TaxonomyPageChange>> {
+    validate(change)?;
+    let hugo = get_hugo_config()?;
+    let mut changes: Vec<TaxonomyPageChange> = fetch_pages(&hugo)?
+        .into_iter()
+        .filter_map(|file| {
+            let (before, after, _) = rewrite_page(&file.content, change)?;
+            Some(TaxonomyPageChange { file: file.rel, hidden: file.hidden, before, after })
+        })
+        .collect();
+    changes.sort_by(|a, b| (&a.file, a.hidden).cmp(&(&b.file, b.hidden)));
+    Ok(changes)
+}
+
+/// 변경 적용. 미리보기 이후 바뀐 내용에도 맞도록 페이지를 다시 읽어 계산한다. 적용된 변경 목록 반환.
+pub fn apply_taxonomy_change(change: &TaxonomyChange) -> Result<Vec<TaxonomyPageChange>> {
+    let planned = preview_taxonomy_change(change)?;
+    let hugo = get_hugo_config()?;
+    let sftp = get_sftp_session()?;
+
+    let mut applied = Vec::new();
+    for page in planned {
+        let abs = if page.hidden { hugo.hidden_abs(&page.file) } else { hugo.content_abs(&page.file) };
+        let Ok(content) = get_file(&sftp, Path::new(&abs)) else { continue };
+        let Some((before, after, updated)) = rewrite_page(&content, change) else { continue };
+
+        crate::services::watch_service::mark_self_write(&abs);
+        save_file(&sftp, Path::new(&abs), updated.clone())?;
+        search_index_service::note_saved(&hugo, &abs, &updated);
+        // 되돌릴 수 있도록 리비전 기록 (실패해도 변경은 유지)
+        if let Err(e) = history_service::record_save(&sftp, &hugo, &page.file, Some(&content), &updated) {
+            eprintln!("Failed to record revision for {}: {}", page.file, e);
+        }
+        applied.push(TaxonomyPageChange { file: page.file, hidden: page.hidden, before, after });
+    }
+    Ok(applied)
+}
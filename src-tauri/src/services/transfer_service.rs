@@ -65,6 +65,16 @@ fn emit_progress(app: &tauri::AppHandle, p: &TransferProgress) {
     let _ = app.emit("transfer:progress", p);
 }
 
+fn emit_error(app: &tauri::AppHandle, id: &str, e: &anyhow::Error) {
+    emit_progress(app, &TransferProgress {
+        id: id.into(), phase: "error".into(),
+        current_bytes: 0, total_bytes: 0,
+        files_done: 0, files_total: 0,
+        current_file: String::new(),
+        error: Some(format!("{:#}", e)),
+    });
+}
+
 use crate::utils::shell::quote as shq;
 
 /* ===== Conflict check ===== */
@@ -166,13 +176,7 @@ pub fn upload_to_remote(
     match upload_inner(&id, local_paths, remote_dir, policy, &app) {
         Ok(()) => Ok(id),
         Err(e) => {
-            emit_progress(&app, &TransferProgress {
-                id: id.clone(), phase: "error".into(),
-                current_bytes: 0, total_bytes: 0,
-                files_done: 0, files_total: 0,
-                current_file: String::new(),
-                error: Some(format!("{:#}", e)),
-            });
+            emit_error(&app, &id, &e);
             Err(e)
         }
     }
@@ -225,9 +229,20 @@ fn upload_inner(
         });
     })?;
 
+    send_and_extract(id, &temp_tar, &remote_dir, files_total, app)
+}
+
+/// Phases 2-4: upload the local tar.gz, extract it into remote_dir, clean up both temp files.
+fn send_and_extract(
+    id: &str,
+    temp_tar: &Path,
+    remote_dir: &str,
+    files_total: u32,
+    app: &tauri::AppHandle,
+) -> Result<()> {
     // ── Phase 2: SFTP upload ──
     let remote_tar = format!("/tmp/inn-transfer-{}.tgz", id);
-    let tar_size = std::fs::metadata(&temp_tar)?.len();
+    let tar_size = std::fs::metadata(temp_tar)?.len();
     let short_id = &id[..8.min(id.len())];
     emit_progress(app, &TransferProgress {
         id: id.into(), phase: "uploading".into(),
@@ -236,7 +251,7 @@ fn upload_inner(
         current_file: format!("{}.tgz", short_id),
         error: None,
     });
-    sftp_upload(&temp_tar, &remote_tar, |sent| {
+    sftp_upload(temp_tar, &remote_tar, |sent| {
         emit_progress(app, &TransferProgress {
             id: id.into(), phase: "uploading".into(),
             current_bytes: sent, total_bytes: tar_size,
@@ -251,17 +266,17 @@ fn upload_inner(
         id: id.into(), phase: "extracting".into(),
         current_bytes: tar_size, total_bytes: tar_size,
         files_done: files_total, files_total,
-        current_file: remote_dir.clone(), error: None,
+        current_file: remote_dir.to_string(), error: None,
     });
     let mut ch = get_channel_session()?;
-    let mkdir_cmd = format!("mkdir -p {}", shq(&remote_dir));
+    let mkdir_cmd = format!("mkdir -p {}", shq(remote_dir));
     execute_ssh_command_checked(&mut ch, &mkdir_cmd)
         .with_context(|| format!("mkdir failed: {}", mkdir_cmd))?;
     let mut ch = get_channel_session()?;
     let extract_cmd = format!(
         "tar xzf {} -C {}",
         shq(&remote_tar),
-        shq(&remote_dir),
+        shq(remote_dir),
     );
     execute_ssh_command_checked(&mut ch, &extract_cmd)
         .with_context(|| format!("tar extract failed: {}", extract_cmd))?;
@@ -273,7 +288,7 @@ fn upload_inner(
         files_done: files_total, files_total,
         current_file: String::new(), error: None,
     });
-    let _ = std::fs::remove_file(&temp_tar);
+    let _ = std::fs::remove_file(temp_tar);
     let mut ch = get_channel_session()?;
     let _ = execute_ssh_command(&mut ch, &format!("rm -f {}", shq(&remote_tar)));
 
@@ -288,6 +303,47 @@ fn upload_inner(
     Ok(())
 }
 
+/// Upload in-memory files as a single tar bundle and extract them under remote_dir
+/// (names are relative to remote_dir). Emits the same progress events as upload_to_remote.
+pub fn upload_generated(files: Vec<(String, Vec<u8>)>, remote_dir: &str, app: &tauri::AppHandle) -> Result<String> {
+    let id = new_id();
+    match upload_generated_inner(&id, files, remote_dir, app) {
+        Ok(()) => Ok(id),
+        Err(e) => {
+            emit_error(app, &id, &e);
+            Err(e)
+        }
+    }
+}
+
+fn upload_generated_inner(id: &str, files: Vec<(String, Vec<u8>)>, remote_dir: &str, app: &tauri::AppHandle) -> Result<()> {
+    let temp_tar = std::env::temp_dir().join(format!("inn-transfer-{}.tgz", id));
+    let files_total = files.len() as u32;
+    let total_bytes: u64 = files.iter().map(|(_, data)| data.len() as u64).sum();
+    let mtime = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+    let mut builder = Builder::new(GzEncoder::new(File::create(&temp_tar)?, Compression::default()));
+    let mut bytes_done = 0u64;
+    for (files_done, (name, data)) in files.iter().enumerate() {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(mtime);
+        header.set_cksum();
+        builder.append_data(&mut header, name, data.as_slice())?;
+        bytes_done += data.len() as u64;
+        emit_progress(app, &TransferProgress {
+            id: id.into(), phase: "packing".into(),
+            current_bytes: bytes_done, total_bytes,
+            files_done: files_done as u32 + 1, files_total,
+            current_file: name.clone(), error: None,
+        });
+    }
+    builder.into_inner()?.finish()?;
+
+    send_and_extract(id, &temp_tar, remote_dir, files_total, app)
+}
+
 /* ===== Download ===== */
 
 pub fn download_to_local(
@@ -445,6 +501,106 @@ fn download_inner(
 }
 
 
+/// Pack the given paths (relative to `remote_dir`; missing ones are skipped) into a single
+/// archive on the server, download it and return the regular files as `(relative path, bytes)`.
+/// Progress is reported through the same `transfer:progress` events as a normal download.
+pub fn download_generated(paths: &[String], remote_dir: &str, app: &tauri::AppHandle) -> Result<Vec<(String, Vec<u8>)>> {
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+    let id = new_id();
+    match download_generated_inner(&id, paths, remote_dir, app) {
+        Ok(files) => Ok(files),
+        Err(e) => {
+            emit_error(app, &id, &e);
+            Err(e)
+        }
+    }
+}
+
+fn download_generated_inner(id: &str, paths: &[String], remote_dir: &str, app: &tauri::AppHandle) -> Result<Vec<(String, Vec<u8>)>> {
+    let files_total = paths.len() as u32;
+
+    // ── Phase 1: Pack on remote ──
+    // The path list goes over SFTP so long lists don't hit the shell's argument limit.
+    let remote_tar = format!("/tmp/inn-transfer-{}.tgz", id);
+    let remote_list = format!("/tmp/inn-transfer-{}.list", id);
+    emit_progress(app, &TransferProgress {
+        id: id.into(), phase: "packing".into(),
+        current_bytes: 0, total_bytes: 0,
+        files_done: 0, files_total,
+        current_file: format!("{} (packing on server...)", remote_dir),
+        error: None,
+    });
+    {
+        let sftp = get_sftp_session()?;
+        let mut list = sftp.create(Path::new(&remote_list))?;
+        for p in paths {
+            writeln!(list, "{}", p)?;
+        }
+    }
+    let pack_cmd = format!(
+        "cd {dir} || exit 1; while IFS= read -r p; do [ -e \"$p\" ] && printf '%s\\n' \"$p\"; done < {list} > {list}.ok; tar czf {tar} -T {list}.ok",
+        dir = shq(remote_dir), list = shq(&remote_list), tar = shq(&remote_tar),
+    );
+    let mut ch = get_channel_session()?;
+    let packed = execute_ssh_command_checked(&mut ch, &pack_cmd)
+        .with_context(|| format!("tar pack failed: {}", pack_cmd));
+
+    let result = packed.and_then(|_| {
+        // ── Phase 2: SFTP download ──
+        let tar_size = {
+            let sftp = get_sftp_session()?;
+            let stat = sftp.stat(Path::new(&remote_tar))?;
+            stat.size.unwrap_or(0)
+        };
+        let temp_tar = std::env::temp_dir().join(format!("inn-transfer-{}.tgz", id));
+        let short_id = &id[..8.min(id.len())];
+        let downloaded = sftp_download(&remote_tar, &temp_tar, |recv| {
+            emit_progress(app, &TransferProgress {
+                id: id.into(), phase: "downloading".into(),
+                current_bytes: recv, total_bytes: tar_size,
+                files_done: 0, files_total,
+                current_file: format!("{}.tgz", short_id),
+                error: None,
+            });
+        });
+
+        // ── Phase 3: Read entries into memory ──
+        let read = downloaded.and_then(|_| {
+            let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(File::open(&temp_tar)?));
+            let mut files = Vec::new();
+            for entry in archive.entries()? {
+                let mut entry = entry?;
+                if !entry.header().entry_type().is_file() {
+                    continue;
+                }
+                let name = entry.path()?.to_string_lossy().replace('\\', "/");
+                let name = name.trim_start_matches("./").to_string();
+                let mut data = Vec::new();
+                entry.read_to_end(&mut data)?;
+                files.push((name, data));
+            }
+            Ok(files)
+        });
+        let _ = std::fs::remove_file(&temp_tar);
+        read.map(|files| (files, tar_size))
+    });
+
+    // ── Phase 4: Cleanup ──
+    let mut ch = get_channel_session()?;
+    let _ = execute_ssh_command(&mut ch, &format!("rm -f {} {} {}.ok", shq(&remote_tar), shq(&remote_list), shq(&remote_list)));
+    let (files, tar_size) = result?;
+
+    emit_progress(app, &TransferProgress {
+        id: id.into(), phase: "done".into(),
+        current_bytes: tar_size, total_bytes: tar_size,
+        files_done: files.len() as u32, files_total,
+        current_file: String::new(), error: None,
+    });
+    Ok(files)
+}
+
 fn common_parent(paths: &[String]) -> Option<String> {
     let parents: Vec<String> = paths
         .iter()
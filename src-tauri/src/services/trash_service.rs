@@ -0,0 +1,409 @@
+//! 휴지통: 삭제 대신 서버의 사이트별 휴지통 디렉토리로 이동한다.
+//!
+//! 항목 하나 = `~/.inn_trash/<site>/<id>/`
+//! - `meta.json`: TrashItem
+//! - `content`, `hidden`: content/hidden 쪽 원본 (파일 또는 폴더, 있는 쪽만)
+//! - `images`: 함께 옮긴 이미지 디렉토리
+//! - `remote`: 파일 탐색기에서 지운 임의 원격 경로
+//! - `images/<rel>`: 고아 이미지 정리로 옮긴 개별 이미지 파일 (image_path 구조 유지)
+//!
+//! content 디렉토리와 홈이 다른 파일시스템일 수 있어 SFTP rename 대신 `mv`를 쓴다.
+
+use std::io::prelude::*;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use ssh2::Sftp;
+use typeshare::typeshare;
+
+use crate::services::config_service::get_app_config;
+use crate::services::file_service::{find_image_dir, mkdir_recursive, path_exists, site_data_dir};
+use crate::services::ssh_service::{execute_ssh_command_checked, get_channel_session, get_sftp_session};
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::front_matter;
+use crate::utils::shell::quote as shq;
+
+const TRASH_DIR: &str = ".inn_trash";
+const META_FILE: &str = "meta.json";
+/// Hugo 설정이 없는 서버에서 탐색기로 지운 항목의 휴지통
+const NO_SITE_KEY: &str = "_remote";
+/// SSH 호출 하나에 담는 파일 이동 수
+const MV_BATCH: usize = 200;
+
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum TrashOrigin {
+    /// 사이드바 content 트리 (content/hidden + 이미지)
+    Content,
+    /// 파일 탐색기의 임의 원격 경로
+    Remote,
+    /// 고아 이미지 정리로 옮긴 이미지 파일들
+    Images,
+}
+
+/// 휴지통 항목
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TrashItem {
+    pub id: String,
+    pub origin: TrashOrigin,
+    /// Content: content 상대경로 ("/posts/a.md"), Remote: 원격 절대경로, Images: 이미지 루트 절대경로
+    pub original_path: String,
+    pub is_dir: bool,
+    /// content 쪽에 있던 원본을 보관 중
+    pub in_content: bool,
+    /// hidden 쪽에 있던 원본을 보관 중 (in_content=false면 숨김 파일이었음)
+    pub in_hidden: bool,
+    /// 함께 옮긴 이미지 디렉토리의 원래 절대경로
+    pub image_dir: Option<String>,
+    /// 삭제 시각 (RFC3339, UTC)
+    pub deleted_at: String,
+    /// Images: 옮긴 파일들 (image_path 기준 "/posts/a.md/x.png")
+    #[serde(default)]
+    pub files: Vec<String>,
+}
+
+fn trash_root(sftp: &Sftp, hugo: &HugoConfig) -> Result<String> {
+    let key = hugo.site_key();
+    site_data_dir(sftp, TRASH_DIR, if key.is_empty() { NO_SITE_KEY } else { &key })
+}
+
+fn item_dir(root: &str, id: &str) -> Result<String> {
+    if id.is_empty() || id.contains('/') || id.contains("..") {
+        bail!("Invalid trash id: {}", id);
+    }
+    Ok(format!("{}/{}", root, id))
+}
+
+fn new_id() -> String {
+    use rand::Rng;
+    let suffix: u32 = rand::thread_rng().gen_range(0..0x10000);
+    format!("{}-{:04x}", front_matter::now_unix(), suffix)
+}
+
+/// `mv` (다른 파일시스템 간 이동 포함). 대상 부모 디렉토리는 미리 만든다.
+fn mv(src: &str, dst: &str) -> Result<()> {
+    let mut ch = get_channel_session()?;
+    execute_ssh_command_checked(&mut ch, &format!("mv -- {} {}", shq(src), shq(dst)))?;
+    Ok(())
+}
+
+fn mv_with_parent(sftp: &Sftp, src: &str, dst: &str) -> Result<()> {
+    if let Some(parent) = Path::new(dst).parent() {
+        mkdir_recursive(sftp, parent)?;
+    }
+    mv(src, dst)
+}
+
+/// 여러 파일을 묶어서 이동 (대상 부모 디렉토리 생성 포함)
+fn mv_many(pairs: &[(String, String)]) -> Result<()> {
+    for chunk in pairs.chunks(MV_BATCH) {
+        let script: Vec<String> = chunk
+            .iter()
+            .map(|(src, dst)| {
+                let parent = dst.rsplit_once('/').map(|(p, _)| p).unwrap_or("/");
+                format!("mkdir -p -- {} && mv -- {} {}", shq(parent), shq(src), shq(dst))
+            })
+            .collect();
+        let mut ch = get_channel_session()?;
+        execute_ssh_command_checked(&mut ch, &script.join(" && "))?;
+    }
+    Ok(())
+}
+
+/// mv_many 되돌리기: 이미 옮겨진 것만 원위치 (best-effort)
+fn unmv_many(pairs: &[(String, String)]) {
+    for chunk in pairs.chunks(MV_BATCH) {
+        let script: Vec<String> = chunk
+            .iter()
+            .map(|(src, dst)| format!("if [ -e {d} ]; then mv -- {d} {s}; fi", d = shq(dst), s = shq(src)))
+            .collect();
+        if let Ok(mut ch) = get_channel_session() {
+            execute_ssh_command_checked(&mut ch, &script.join("; ")).ok();
+        }
+    }
+}
+
+fn rm_rf(path: &str) -> Result<()> {
+    let mut ch = get_channel_session()?;
+    execute_ssh_command_checked(&mut ch, &format!("rm -rf -- {}", shq(path)))?;
+    Ok(())
+}
+
+fn write_meta(sftp: &Sftp, dir: &str, item: &TrashItem) -> Result<()> {
+    let mut file = sftp.create(Path::new(&format!("{}/{}", dir, META_FILE)))?;
+    file.write_all(serde_json::to_string_pretty(item)?.as_bytes())?;
+    Ok(())
+}
+
+fn read_meta(sftp: &Sftp, dir: &str) -> Result<TrashItem> {
+    let mut file = sftp.open(Path::new(&format!("{}/{}", dir, META_FILE)))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// 이동한 것들을 역순으로 되돌린다 (best-effort)
+fn rollback(moved: &[(String, String)]) {
+    for (src, dst) in moved.iter().rev() {
+        mv(dst, src).ok();
+    }
+}
+
+/// content 항목(파일/폴더)을 휴지통으로 이동. content/hidden 양쪽 + 이미지 디렉토리를 함께 옮긴다.
+pub fn trash_content(sftp: &Sftp, hugo: &HugoConfig, rel: &str) -> Result<TrashItem> {
+    let content_abs = hugo.content_abs(rel);
+    let hidden_abs = hugo.hidden_abs(rel);
+    let content_stat = sftp.stat(Path::new(&content_abs)).ok();
+    let hidden_stat = sftp.stat(Path::new(&hidden_abs)).ok();
+    let Some(stat) = content_stat.as_ref().or(hidden_stat.as_ref()) else {
+        bail!("Source does not exist: {}", rel);
+    };
+    let is_dir = stat.is_dir();
+
+    let root = trash_root(sftp, hugo)?;
+    let id = new_id();
+    let dir = item_dir(&root, &id)?;
+    mkdir_recursive(sftp, Path::new(&dir))?;
+
+    let image_dir = find_image_dir(sftp, hugo, rel).map(|(p, _)| p);
+    let mut sources: Vec<(String, String)> = Vec::new();
+    if content_stat.is_some() {
+        sources.push((content_abs, format!("{}/content", dir)));
+    }
+    if hidden_stat.is_some() {
+        sources.push((hidden_abs, format!("{}/hidden", dir)));
+    }
+    if let Some(img) = &image_dir {
+        sources.push((img.clone(), format!("{}/images", dir)));
+    }
+
+    let mut moved = Vec::new();
+    for (src, dst) in sources {
+        if let Err(e) = mv(&src, &dst) {
+            rollback(&moved);
+            sftp.rmdir(Path::new(&dir)).ok();
+            return Err(e.context(format!("Failed to move to trash: {}", src)));
+        }
+        moved.push((src, dst));
+    }
+
+    let item = TrashItem {
+        id,
+        origin: TrashOrigin::Content,
+        original_path: rel.to_string(),
+        is_dir,
+        in_content: content_stat.is_some(),
+        in_hidden: hidden_stat.is_some(),
+        image_dir,
+        deleted_at: front_matter::format_rfc3339(front_matter::now_unix()),
+        files: Vec::new(),
+    };
+    if let Err(e) = write_meta(sftp, &dir, &item) {
+        rollback(&moved);
+        rm_rf(&dir).ok();
+        return Err(e.context("Failed to write trash metadata"));
+    }
+
+    purge_expired(sftp, hugo);
+    Ok(item)
+}
+
+/// 파일 탐색기의 원격 경로를 휴지통으로 이동. 휴지통 내부 경로는 영구 삭제한다.
+pub fn trash_remote(paths: &[String]) -> Result<()> {
+    let sftp = get_sftp_session()?;
+    let hugo = get_app_config()?.cms_config.hugo_config;
+    let root = trash_root(&sftp, &hugo)?;
+    let trash_base = format!("{}/", Path::new(&root).parent().map(|p| p.to_string_lossy().to_string()).unwrap_or_default());
+
+    for p in paths {
+        let path = p.trim_end_matches('/');
+        if format!("{}/", path).starts_with(&trash_base) {
+            rm_rf(path)?;
+            continue;
+        }
+        let stat = sftp.stat(Path::new(path)).with_context(|| format!("Path does not exist: {}", path))?;
+        let id = new_id();
+        let dir = item_dir(&root, &id)?;
+        mkdir_recursive(&sftp, Path::new(&dir))?;
+        mv(path, &format!("{}/remote", dir))?;
+        let item = TrashItem {
+            id,
+            origin: TrashOrigin::Remote,
+            original_path: path.to_string(),
+            is_dir: stat.is_dir(),
+            in_content: false,
+            in_hidden: false,
+            image_dir: None,
+            deleted_at: front_matter::format_rfc3339(front_matter::now_unix()),
+            files: Vec::new(),
+        };
+        if let Err(e) = write_meta(&sftp, &dir, &item) {
+            mv(&format!("{}/remote", dir), path).ok();
+            rm_rf(&dir).ok();
+            return Err(e.context("Failed to write trash metadata"));
+        }
+    }
+    purge_expired(&sftp, &hugo);
+    Ok(())
+}
+
+/// 이미지 파일들(image_root 기준 상대경로)을 항목 하나로 묶어 휴지통으로 이동
+pub fn trash_images(sftp: &Sftp, hugo: &HugoConfig, image_root: &str, files: &[String]) -> Result<TrashItem> {
+    let root = trash_root(sftp, hugo)?;
+    let id = new_id();
+    let dir = item_dir(&root, &id)?;
+    mkdir_recursive(sftp, Path::new(&dir))?;
+
+    let pairs: Vec<(String, String)> = files
+        .iter()
+        .map(|rel| (format!("{}{}", image_root, rel), format!("{}/images{}", dir, rel)))
+        .collect();
+    if let Err(e) = mv_many(&pairs) {
+        unmv_many(&pairs);
+        rm_rf(&dir).ok();
+        return Err(e.context("Failed to move images to trash"));
+    }
+
+    let item = TrashItem {
+        id,
+        origin: TrashOrigin::Images,
+        original_path: image_root.to_string(),
+        is_dir: false,
+        in_content: false,
+        in_hidden: false,
+        image_dir: None,
+        deleted_at: front_matter::format_rfc3339(front_matter::now_unix()),
+        files: files.to_vec(),
+    };
+    if let Err(e) = write_meta(sftp, &dir, &item) {
+        unmv_many(&pairs);
+        rm_rf(&dir).ok();
+        return Err(e.context("Failed to write trash metadata"));
+    }
+
+    purge_expired(sftp, hugo);
+    Ok(item)
+}
+
+fn list_items(sftp: &Sftp, root: &str) -> Vec<TrashItem> {
+    let mut items: Vec<TrashItem> = sftp
+        .readdir(Path::new(root))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(_, stat)| stat.is_dir())
+        .filter_map(|(p, _)| {
+            let name = p.file_name()?.to_string_lossy().to_string();
+            read_meta(sftp, &format!("{}/{}", root, name)).ok()
+        })
+        .collect();
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at).then_with(|| b.id.cmp(&a.id)));
+    items
+}
+
+/// 설정된 보존 기간(trash_retention_days, 0=무제한)이 지난 항목 삭제 (best-effort)
+fn purge_expired(sftp: &Sftp, hugo: &HugoConfig) {
+    let days = get_app_config().map(|c| c.cms_config.trash_retention_days).unwrap_or(0);
+    if days == 0 {
+        return;
+    }
+    let Ok(root) = trash_root(sftp, hugo) else { return };
+    let cutoff = front_matter::now_unix() - i64::from(days) * 24 * 60 * 60;
+    for item in list_items(sftp, &root) {
+        let expired = front_matter::parse_date(&item.deleted_at).is_some_and(|t| t < cutoff);
+        if expired {
+            if let Ok(dir) = item_dir(&root, &item.id) {
+                rm_rf(&dir).ok();
+            }
+        }
+    }
+}
+
+/// 휴지통 목록 (최근 삭제순). 보존 기간이 지난 항목은 먼저 정리한다.
+pub fn list_trash() -> Result<Vec<TrashItem>> {
+    let sftp = get_sftp_session()?;
+    let hugo = get_app_config()?.cms_config.hugo_config;
+    purge_expired(&sftp, &hugo);
+    let root = trash_root(&sftp, &hugo)?;
+    Ok(list_items(&sftp, &root))
+}
+
+/// 원래 위치로 복원 (부모 폴더 재생성 + 이미지 디렉토리 재연결). 복원된 경로 반환.
+pub fn restore_trash(id: &str) -> Result<String> {
+    let sftp = get_sftp_session()?;
+    let hugo = get_app_config()?.cms_config.hugo_config;
+    let root = trash_root(&sftp, &hugo)?;
+    let dir = item_dir(&root, id)?;
+    let item = read_meta(&sftp, &dir).with_context(|| format!("Trash item not found: {}", id))?;
+
+    let mut targets: Vec<(String, String)> = Vec::new();
+    match item.origin {
+        TrashOrigin::Content => {
+            if path_exists(&sftp, &hugo, &item.original_path) {
+                bail!("Destination already exists: {}", item.original_path);
+            }
+            if item.in_content {
+                targets.push((format!("{}/content", dir), hugo.content_abs(&item.original_path)));
+            }
+            if item.in_hidden {
+                targets.push((format!("{}/hidden", dir), hugo.hidden_abs(&item.original_path)));
+            }
+            // 이미지 자리에 이미 다른 디렉토리가 있으면 덮어쓰지 않는다 (항목째 휴지통에 남긴다)
+            if let Some(img) = &item.image_dir {
+                let saved = format!("{}/images", dir);
+                if sftp.stat(Path::new(&saved)).is_ok() {
+                    if sftp.stat(Path::new(img)).is_ok() {
+                        bail!("Destination already exists: {}", img);
+                    }
+                    targets.push((saved, img.clone()));
+                }
+            }
+        }
+        TrashOrigin::Remote => {
+            if sftp.stat(Path::new(&item.original_path)).is_ok() {
+                bail!("Destination already exists: {}", item.original_path);
+            }
+            targets.push((format!("{}/remote", dir), item.original_path.clone()));
+        }
+        TrashOrigin::Images => {
+            let pairs: Vec<(String, String)> = item
+                .files
+                .iter()
+                .map(|rel| (format!("{}/images{}", dir, rel), format!("{}{}", item.original_path, rel)))
+                .collect();
+            if let Some((_, dst)) = pairs.iter().find(|(_, dst)| sftp.stat(Path::new(dst)).is_ok()) {
+                bail!("Destination already exists: {}", dst);
+            }
+            if let Err(e) = mv_many(&pairs) {
+                unmv_many(&pairs);
+                return Err(e.context("Failed to restore images"));
+            }
+            rm_rf(&dir)?;
+            return Ok(item.original_path);
+        }
+    }
+
+    let mut moved = Vec::new();
+    for (src, dst) in targets {
+        if let Err(e) = mv_with_parent(&sftp, &src, &dst) {
+            rollback(&moved);
+            return Err(e.context(format!("Failed to restore: {}", dst)));
+        }
+        moved.push((src, dst));
+    }
+
+    rm_rf(&dir)?;
+    Ok(item.original_path)
+}
+
+/// 휴지통 항목 영구 삭제
+pub fn purge_trash(ids: &[String]) -> Result<()> {
+    let sftp = get_sftp_session()?;
+    let hugo = get_app_config()?.cms_config.hugo_config;
+    let root = trash_root(&sftp, &hugo)?;
+    for id in ids {
+        rm_rf(&item_dir(&root, id)?)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,287 @@
+//! 원격 content/image 디렉토리 변경 감지 → `content:changed` 이벤트 push.
+//!
+//! 서버에 inotifywait가 있으면 `inotifywait -m -r` 스트림을 읽고,
+//! 없으면 주기적으로 `find -printf '%T@ %s %p'` 스냅샷을 떠서 이전 스냅샷과 비교한다.
+//! 전역 SSH 세션을 오래 점유하지 않도록 PTY처럼 전용 세션 + 전용 스레드를 쓴다.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use ssh2::Session;
+use tauri::Emitter;
+use typeshare::typeshare;
+
+use crate::services::config_service::{get_app_config, get_hugo_config};
+use crate::services::ssh_service::open_session;
+use crate::types::config::cms_config::HugoConfig;
+use crate::utils::shell::quote as shq;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// inotify 이벤트를 모아서 보내는 간격 (저장 1회에 이벤트 여러 개가 발생한다)
+const DEBOUNCE: Duration = Duration::from_millis(500);
+const IO_LOOP_SLEEP: Duration = Duration::from_millis(50);
+/// 앱이 직접 쓴 파일의 이벤트를 무시하는 시간
+const SELF_WRITE_GRACE: Duration = Duration::from_secs(3);
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum WatchMode {
+    Inotify,
+    Poll,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeTarget {
+    Content,
+    Hidden,
+    Image,
+}
+
+/// 변경된 경로 하나
+#[typeshare]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChangedPath {
+    /// Content/Hidden: "/posts/a.md" (hidden 접두 제거), Image: image_path 기준 "/posts/a.md/x.png"
+    pub path: String,
+    pub target: ChangeTarget,
+    /// 삭제/이동으로 사라진 경로
+    pub removed: bool,
+}
+
+/// `content:changed` 이벤트 payload
+#[typeshare]
+#[derive(Serialize, Debug, Clone)]
+pub struct ContentChangedEvent {
+    pub mode: WatchMode,
+    pub changes: Vec<ChangedPath>,
+}
+
+struct WatchHandle {
+    tx: mpsc::Sender<()>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+static WATCH_HANDLE: Lazy<Mutex<Option<WatchHandle>>> = Lazy::new(|| Mutex::new(None));
+/// 앱이 직접 저장한 절대경로 → 저장 시각
+static SELF_WRITES: Lazy<Mutex<HashMap<String, Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 앱이 직접 쓴 파일 기록 — 에디터가 자기 저장을 외부 변경으로 오인하지 않도록 한다
+pub fn mark_self_write(abs_path: &str) {
+    let mut writes = SELF_WRITES.lock().unwrap_or_else(|p| p.into_inner());
+    writes.retain(|_, t| t.elapsed() < SELF_WRITE_GRACE);
+    writes.insert(abs_path.to_string(), Instant::now());
+}
+
+fn is_self_write(abs_path: &str) -> bool {
+    SELF_WRITES.lock().unwrap_or_else(|p| p.into_inner())
+        .get(abs_path)
+        .is_some_and(|t| t.elapsed() < SELF_WRITE_GRACE)
+}
+
+/// 감시 시작 (이미 실행 중이면 재시작). 사용된 감지 방식을 반환.
+pub fn start_watcher() -> Result<WatchMode> {
+    stop_watcher().ok();
+
+    let config = get_app_config()?;
+    let ssh = config.get_active_ssh_config().context("No active server SSH config")?;
+    let hugo = get_hugo_config()?;
+    if hugo.is_empty() {
+        anyhow::bail!("Hugo config is not set");
+    }
+
+    let session = open_session(&ssh)?;
+    let mode = if has_inotifywait(&session) { WatchMode::Inotify } else { WatchMode::Poll };
+
+    let (tx, rx) = mpsc::channel::<()>();
+    let thread = thread::spawn(move || {
+        let result = match mode {
+            WatchMode::Inotify => inotify_loop(&session, &hugo, &rx),
+            WatchMode::Poll => poll_loop(&session, &hugo, &rx),
+        };
+        if let Err(e) = result {
+            eprintln!("content watcher stopped: {:#}", e);
+        }
+        let _ = session.disconnect(None, "watcher closed", None);
+    });
+
+    *WATCH_HANDLE.lock().unwrap_or_else(|p| p.into_inner()) = Some(WatchHandle {
+        tx,
+        thread: Some(thread),
+    });
+    Ok(mode)
+}
+
+/// 감시 중지 (실행 중이 아니면 no-op)
+pub fn stop_watcher() -> Result<()> {
+    let mut guard = WATCH_HANDLE.lock().unwrap_or_else(|p| p.into_inner());
+    if let Some(mut handle) = guard.take() {
+        let _ = handle.tx.send(());
+        if let Some(thread) = handle.thread.take() {
+            drop(guard); // 락 해제 후 join
+            let _ = thread.join();
+        }
+    }
+    Ok(())
+}
+
+fn has_inotifywait(session: &Session) -> bool {
+    run_blocking(session, "command -v inotifywait >/dev/null 2>&1 && echo yes")
+        .map(|o| o.trim() == "yes")
+        .unwrap_or(false)
+}
+
+/// 전용 세션(blocking)에서 명령 1회 실행
+fn run_blocking(session: &Session, cmd: &str) -> Result<String> {
+    let mut channel = session.channel_session()?;
+    channel.exec(cmd)?;
+    let mut out = String::new();
+    channel.read_to_string(&mut out)?;
+    channel.wait_close().ok();
+    Ok(out)
+}
+
+fn watch_dirs(hugo: &HugoConfig) -> (String, String) {
+    (
+        format!("{}/content", hugo.base_path),
+        format!("{}/{}", hugo.base_path, hugo.image_path.trim_matches('/')),
+    )
+}
+
+/// 원격 절대경로 → ChangedPath (감시 대상 밖이면 None)
+fn classify(hugo: &HugoConfig, abs: &str, removed: bool) -> Option<ChangedPath> {
+    let (content_dir, image_dir) = watch_dirs(hugo);
+    let hidden_dir = format!("{}/{}", content_dir, hugo.hidden_path);
+    let (path, target) = if let Some(rel) = abs.strip_prefix(&hidden_dir).filter(|r| r.starts_with('/')) {
+        (rel, ChangeTarget::Hidden)
+    } else if let Some(rel) = abs.strip_prefix(&image_dir).filter(|r| r.starts_with('/')) {
+        (rel, ChangeTarget::Image)
+    } else if let Some(rel) = abs.strip_prefix(&content_dir).filter(|r| r.starts_with('/')) {
+        (rel, ChangeTarget::Content)
+    } else {
+        return None;
+    };
+    Some(ChangedPath { path: path.to_string(), target, removed })
+}
+
+fn emit_changes(mode: WatchMode, changes: HashSet<ChangedPath>) {
+    if changes.is_empty() {
+        return;
+    }
+    let mut changes: Vec<ChangedPath> = changes.into_iter().collect();
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    if let Some(app) = crate::app_handle() {
+        let _ = app.emit("content:changed", &ContentChangedEvent { mode, changes });
+    }
+}
+
+/// inotifywait 스트림 읽기. 출력 형식: `EVENTS|절대경로`
+fn inotify_loop(session: &Session, hugo: &HugoConfig, stop: &mpsc::Receiver<()>) -> Result<()> {
+    let (content_dir, image_dir) = watch_dirs(hugo);
+    let mut channel = session.channel_session()?;
+    channel.exec(&format!(
+        "inotifywait -m -r -q -e close_write,create,delete,moved_from,moved_to --format '%e|%w%f' {} {} 2>/dev/null",
+        shq(&content_dir),
+        shq(&image_dir),
+    ))?;
+    session.set_blocking(false);
+
+    let mut buf = [0u8; 8192];
+    let mut line_buf: Vec<u8> = Vec::new();
+    let mut pending: HashSet<ChangedPath> = HashSet::new();
+    let mut last_event = Instant::now();
+
+    let result = loop {
+        match stop.try_recv() {
+            Ok(()) | Err(mpsc::TryRecvError::Disconnected) => break Ok(()),
+            Err(mpsc::TryRecvError::Empty) => {}
+        }
+        if channel.eof() {
+            break Err(anyhow::anyhow!("inotifywait exited"));
+        }
+
+        let mut had_activity = false;
+        match channel.read(&mut buf) {
+            Ok(n) if n > 0 => {
+                had_activity = true;
+                line_buf.extend_from_slice(&buf[..n]);
+                while let Some(pos) = line_buf.iter().position(|&b| b == b'\n') {
+                    let line: Vec<u8> = line_buf.drain(..=pos).collect();
+                    let line = String::from_utf8_lossy(&line);
+                    let Some((events, abs)) = line.trim_end().split_once('|') else { continue };
+                    if is_self_write(abs) {
+                        continue;
+                    }
+                    let removed = events.contains("DELETE") || events.contains("MOVED_FROM");
+                    if let Some(change) = classify(hugo, abs, removed) {
+                        pending.insert(change);
+                        last_event = Instant::now();
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(e) => break Err(e.into()),
+        }
+
+        if !pending.is_empty() && last_event.elapsed() >= DEBOUNCE {
+            emit_changes(WatchMode::Inotify, std::mem::take(&mut pending));
+        }
+        if !had_activity {
+            thread::sleep(IO_LOOP_SLEEP);
+        }
+    };
+
+    session.set_blocking(true);
+    let _ = channel.close();
+    result
+}
+
+/// (mtime, size) 스냅샷: 절대경로 → "mtime size"
+fn take_snapshot(session: &Session, hugo: &HugoConfig) -> Result<HashMap<String, String>> {
+    let (content_dir, image_dir) = watch_dirs(hugo);
+    let output = run_blocking(session, &format!(
+        "find {} {} -type f -printf '%T@ %s %p\\n' 2>/dev/null; true",
+        shq(&content_dir),
+        shq(&image_dir),
+    ))?;
+    let mut snapshot = HashMap::new();
+    for line in output.lines() {
+        let mut parts = line.splitn(3, ' ');
+        let (Some(mtime), Some(size), Some(path)) = (parts.next(), parts.next(), parts.next()) else { continue };
+        snapshot.insert(path.to_string(), format!("{} {}", mtime, size));
+    }
+    Ok(snapshot)
+}
+
+/// 주기적 스냅샷 비교 (inotifywait가 없는 서버용)
+fn poll_loop(session: &Session, hugo: &HugoConfig, stop: &mpsc::Receiver<()>) -> Result<()> {
+    let mut previous = take_snapshot(session, hugo)?;
+    loop {
+        match stop.recv_timeout(POLL_INTERVAL) {
+            Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+        }
+
+        let current = take_snapshot(session, hugo)?;
+        let mut changes = HashSet::new();
+        for (path, stamp) in &current {
+            if previous.get(path) != Some(stamp) && !is_self_write(path) {
+                changes.extend(classify(hugo, path, false));
+            }
+        }
+        for path in previous.keys() {
+            if !current.contains_key(path) {
+                changes.extend(classify(hugo, path, true));
+            }
+        }
+        emit_changes(WatchMode::Poll, changes);
+        previous = current;
+    }
+}
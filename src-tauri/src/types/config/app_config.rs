@@ -4,7 +4,7 @@ use anyhow::Result;
 use ssh2::Sftp;
 use typeshare::typeshare;
 
-use super::{ClientConfig, CmsConfig, ServerConfig, ServerEntry, SshConfig};
+use super::{ClientConfig, CmsConfig, SavedSearch, ServerConfig, ServerEntry, SshConfig};
 
 
 /// 프론트엔드와 통신하는 통합 설정 구조체
@@ -20,6 +20,8 @@ pub struct AppConfig {
     #[serde(default)]
     pub shortcuts: HashMap<String, Vec<String>>,
     #[serde(default)]
+    pub saved_searches: Vec<SavedSearch>,
+    #[serde(default)]
     pub plugin_local_path: String,
     #[serde(default)]
     pub download_path: String,
@@ -45,6 +47,7 @@ impl AppConfig {
         ServerConfig {
             cms_config: self.cms_config.clone(),
             shortcuts: self.shortcuts.clone(),
+            saved_searches: self.saved_searches.clone(),
         }
     }
 
@@ -53,6 +56,7 @@ impl AppConfig {
         let server = ServerConfig::load_from_sftp(sftp, home_path)?;
         self.cms_config = server.cms_config;
         self.shortcuts = server.shortcuts;
+        self.saved_searches = server.saved_searches;
         Ok(())
     }
 
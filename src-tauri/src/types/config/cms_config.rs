@@ -94,6 +94,11 @@ impl HugoConfig {
         format!("{}/content{}", self.base_path, sanitize_suffix(suffix))
     }
 
+    /// 서버 데이터 디렉토리(히스토리/휴지통)에서 사이트를 구분하는 이름: base_path의 '/'를 '_'로
+    pub fn site_key(&self) -> String {
+        self.base_path.trim_matches('/').replace('/', "_")
+    }
+
     /// 숨김 콘텐츠 절대경로: {base_path}/content/{hidden_path}{suffix}
     pub fn hidden_abs(&self, suffix: &str) -> String {
         format!("{}/content/{}{}", self.base_path, self.hidden_path, sanitize_suffix(suffix))
@@ -105,4 +110,98 @@ impl HugoConfig {
 #[serde(default)]
 pub struct CmsConfig {
     pub hugo_config: HugoConfig,
+    /// 휴지통 자동 비우기 기간 (일). 0이면 자동으로 비우지 않음
+    #[serde(default)]
+    pub trash_retention_days: u32,
+    /// 업로드/붙여넣기 이미지 처리 정책
+    #[serde(default)]
+    pub image_policy: ImagePolicy,
+    /// 이미지 참조로 인식할 추가 shortcode 속성 (`figure`의 `src`는 기본 포함)
+    #[serde(default)]
+    pub image_shortcodes: Vec<ImageShortcode>,
+    /// 일반 링크에서도 첨부파일로 추적할 확장자 (점 없이). None이면 DEFAULT_ATTACHMENT_EXTENSIONS
+    #[serde(default)]
+    pub attachment_extensions: Option<Vec<String>>,
+    /// leaf bundle(index.md) 페이지에 붙여넣은 이미지/첨부파일 저장 위치
+    #[serde(default)]
+    pub bundle_resource_location: ResourceLocation,
+}
+
+/// 페이지 리소스 저장 위치
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub enum ResourceLocation {
+    /// image_path 아래 페이지 디렉토리 (다른 페이지와 같은 방식)
+    #[default]
+    ImagePath,
+    /// 번들 디렉토리 자체 (Hugo page resource, 상대경로로 참조)
+    Bundle,
+}
+
+/// 첨부파일 기본 확장자
+pub const DEFAULT_ATTACHMENT_EXTENSIONS: &[&str] = &["pdf", "zip", "mp4", "webm", "mov", "mp3"];
+
+impl CmsConfig {
+    /// 첨부파일 확장자 (소문자, 점 없이)
+    pub fn attachment_exts(&self) -> Vec<String> {
+        match &self.attachment_extensions {
+            Some(exts) => exts
+                .iter()
+                .map(|e| e.trim().trim_start_matches('.').to_lowercase())
+                .filter(|e| !e.is_empty())
+                .collect(),
+            None => DEFAULT_ATTACHMENT_EXTENSIONS.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+}
+
+/// 이미지 경로를 담는 shortcode 속성: `{{< name attribute="path" >}}`
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImageShortcode {
+    pub name: String,
+    pub attribute: String,
+}
+
+/// 업로드 이미지 저장 형식
+#[typeshare]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, PartialEq)]
+pub enum ImageOutputFormat {
+    /// 원본 형식 유지
+    #[default]
+    Keep,
+    /// WebP (손실, `quality` 적용)
+    Webp,
+    Jpeg,
+}
+
+/// 업로드 이미지 처리 정책
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ImagePolicy {
+    /// 최대 가로 (px). 0이면 제한 없음. 비율을 유지하며 축소만 한다
+    pub max_width: u32,
+    /// 최대 세로 (px). 0이면 제한 없음
+    pub max_height: u32,
+    pub format: ImageOutputFormat,
+    /// JPEG 재인코딩 품질 (1-100)
+    pub quality: u8,
+    /// EXIF(GPS 포함)/XMP 메타데이터 제거
+    pub strip_metadata: bool,
+    /// EXIF Orientation에 맞춰 픽셀을 회전
+    pub auto_orient: bool,
+}
+
+impl Default for ImagePolicy {
+    fn default() -> Self {
+        Self {
+            max_width: 0,
+            max_height: 0,
+            format: ImageOutputFormat::Keep,
+            quality: 85,
+            strip_metadata: true,
+            auto_orient: true,
+        }
+    }
 }
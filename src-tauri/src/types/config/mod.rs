@@ -1,6 +1,7 @@
 pub mod app_config;
 pub mod client_config;
 pub mod cms_config;
+pub mod saved_search;
 pub mod server_config;
 pub mod server_entry;
 pub mod ssh_config;
@@ -8,6 +9,7 @@ pub mod ssh_config;
 pub use app_config::AppConfig;
 pub use client_config::ClientConfig;
 pub use cms_config::CmsConfig;
+pub use saved_search::SavedSearch;
 pub use server_config::ServerConfig;
 pub use server_entry::ServerEntry;
 pub use ssh_config::SshConfig;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+/// 이름 붙은 검색 쿼리 (사이드바 스마트 폴더). query는 `search_query` 문법
+#[typeshare]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(default)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+}
@@ -5,7 +5,7 @@ use ssh2::Sftp;
 use std::io::{Read, Write};
 use std::path::Path;
 
-use super::CmsConfig;
+use super::{CmsConfig, SavedSearch};
 
 const SERVER_CONFIG_PATH: &str = ".inn_server_config.json";
 
@@ -17,6 +17,9 @@ pub struct ServerConfig {
     pub cms_config: CmsConfig,
     #[serde(default)]
     pub shortcuts: HashMap<String, Vec<String>>,
+    /// 사이드바 스마트 폴더로 보여줄 저장된 검색
+    #[serde(default)]
+    pub saved_searches: Vec<SavedSearch>,
 }
 
 impl ServerConfig {
@@ -0,0 +1,339 @@
+//! Hugo front matter (YAML `---` / TOML `+++`) 줄 단위 파서/편집기.
+//! 완전한 YAML/TOML 파서 대신 최상위 `key: value` / `key = value` 항목만 다룬다.
+//! 편집 시 건드리지 않은 줄은 원문 그대로 보존한다.
+
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FmFormat {
+    Yaml,
+    Toml,
+}
+
+impl FmFormat {
+    fn delimiter(self) -> &'static str {
+        match self {
+            FmFormat::Yaml => "---",
+            FmFormat::Toml => "+++",
+        }
+    }
+}
+
+/// front matter에 기록할 값
+#[derive(Debug, Clone)]
+pub enum FmValue {
+    Bool(bool),
+    /// 따옴표 없이 기록 (날짜, 숫자)
+    Bare(String),
+    /// 큰따옴표 문자열 (YAML/TOML 공통 이스케이프)
+    Str(String),
+    /// 한 줄 배열 `["a", "b"]` (YAML flow sequence / TOML array 공통)
+    List(Vec<String>),
+}
+
+/// front matter 블록 위치: 여는 구분자 다음 줄 ~ 닫는 구분자 줄 (line index)
+struct Block {
+    format: FmFormat,
+    start: usize,
+    end: usize,
+}
+
+fn locate(lines: &[&str]) -> Option<Block> {
+    let format = match lines.first()?.trim_end() {
+        "---" => FmFormat::Yaml,
+        "+++" => FmFormat::Toml,
+        _ => return None,
+    };
+    let end = lines
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, l)| l.trim_end() == format.delimiter())?
+        .0;
+    Some(Block { format, start: 1, end })
+}
+
+/// 최상위 키 줄이면 키 이름 반환 (들여쓰기된 줄, 주석, 리스트 항목은 제외)
+fn key_of(line: &str) -> Option<&str> {
+    if line.starts_with(char::is_whitespace) || line.starts_with('#') || line.starts_with('-') {
+        return None;
+    }
+    let idx = line.find([':', '='])?;
+    let key = line[..idx].trim().trim_matches('"');
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(key)
+}
+
+/// 키 줄의 구분자 뒤 값 부분
+fn value_of(line: &str) -> &str {
+    match line.find([':', '=']) {
+        Some(idx) => line[idx + 1..].trim(),
+        None => "",
+    }
+}
+
+/// 키 항목이 차지하는 줄 범위 [from, to). YAML 블록 리스트/TOML 여러 줄 배열 포함.
+fn field_range(lines: &[&str], block: &Block, key: &str) -> Option<(usize, usize)> {
+    let from = (block.start..block.end)
+        .find(|&i| key_of(lines[i]).is_some_and(|k| k.eq_ignore_ascii_case(key)))?;
+    let mut to = from + 1;
+    let first_value = value_of(lines[from]);
+    if first_value.starts_with('[') && !first_value.contains(']') {
+        // 여러 줄 배열: 닫는 ']'까지
+        while to < block.end {
+            to += 1;
+            if lines[to - 1].contains(']') {
+                break;
+            }
+        }
+    } else if first_value.is_empty() || first_value == "|" || first_value == ">" {
+        // YAML 블록 값: 들여쓰기/리스트 줄이 이어지는 동안
+        while to < block.end && (lines[to].starts_with(char::is_whitespace) || lines[to].starts_with("- ")) {
+            to += 1;
+        }
+    }
+    Some((from, to))
+}
+
+/// 따옴표 제거
+pub fn unquote(s: &str) -> &str {
+    let s = s.trim();
+    for q in ['"', '\''] {
+        if s.len() >= 2 && s.starts_with(q) && s.ends_with(q) {
+            return &s[1..s.len() - 1];
+        }
+    }
+    s
+}
+
+/// YAML/TOML 양쪽에서 유효한 큰따옴표 문자열
+fn quote_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn render(format: FmFormat, key: &str, value: &FmValue) -> String {
+    let rendered = match value {
+        FmValue::Bool(b) => b.to_string(),
+        FmValue::Bare(s) => s.clone(),
+        FmValue::Str(s) => quote_str(s),
+        FmValue::List(items) => format!(
+            "[{}]",
+            items.iter().map(|s| quote_str(s)).collect::<Vec<_>>().join(", ")
+        ),
+    };
+    match format {
+        FmFormat::Yaml => format!("{}: {}", key, rendered),
+        FmFormat::Toml => format!("{} = {}", key, rendered),
+    }
+}
+
+/// 스칼라 값 조회 (따옴표 제거)
+pub fn get(content: &str, key: &str) -> Option<String> {
+    let lines: Vec<&str> = content.split('\n').collect();
+    let block = locate(&lines)?;
+    let (from, _) = field_range(&lines, &block, key)?;
+    let value = unquote(value_of(lines[from]));
+    if value.is_empty() {
+        return None;
+    }
+    Some(value.to_string())
+}
+
+/// 최상위 키가 있는지 (값이 비어 있어도 true)
+pub fn has_key(content: &str, key: &str) -> bool {
+    let lines: Vec<&str> = content.split('\n').collect();
+    locate(&lines).is_some_and(|block| field_range(&lines, &block, key).is_some())
+}
+
+/// 리스트 값 조회 (키가 없으면 빈 Vec)
+pub fn get_list(content: &str, key: &str) -> Vec<String> {
+    let lines: Vec<&str> = content.split('\n').collect();
+    let Some(block) = locate(&lines) else { return Vec::new() };
+    let Some((from, to)) = field_range(&lines, &block, key) else { return Vec::new() };
+    parse_list_lines(&lines[from..to])
+}
+
+/// 키 줄 + 이어지는 줄들에서 리스트 항목 추출.
+/// `[a, "b"]`(여러 줄 포함), YAML 블록 리스트(`- a`), 단일 스칼라 모두 지원.
+pub fn parse_list_lines(lines: &[&str]) -> Vec<String> {
+    let Some(first) = lines.first() else { return Vec::new() };
+    let first_value = value_of(first);
+    let items: Vec<String> = if first_value.starts_with('[') {
+        let joined = std::iter::once(first_value)
+            .chain(lines[1..].iter().map(|l| l.trim()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let inner = joined.trim_start_matches('[');
+        let inner = inner.split(']').next().unwrap_or("");
+        inner.split(',').map(|s| unquote(s).to_string()).collect()
+    } else if first_value.is_empty() {
+        lines[1..]
+            .iter()
+            .filter_map(|l| l.trim().strip_prefix('-'))
+            .map(|s| unquote(s).to_string())
+            .collect()
+    } else {
+        vec![unquote(first_value).to_string()]
+    };
+    items.into_iter().filter(|s| !s.is_empty()).collect()
+}
+
+/// 값 설정. 키가 있으면 교체(원래 키 표기 유지), 없으면 블록 끝에 추가,
+/// front matter 자체가 없으면 YAML 블록을 새로 만든다.
+pub fn set(content: &str, key: &str, value: &FmValue) -> String {
+    let lines: Vec<&str> = content.split('\n').collect();
+    let Some(block) = locate(&lines) else {
+        return format!("---\n{}\n---\n{}", render(FmFormat::Yaml, key, value), content);
+    };
+    let mut out: Vec<String> = Vec::with_capacity(lines.len() + 1);
+    match field_range(&lines, &block, key) {
+        Some((from, to)) => {
+            let existing_key = key_of(lines[from]).unwrap_or(key).to_string();
+            out.extend(lines[..from].iter().map(|l| l.to_string()));
+            out.push(render(block.format, &existing_key, value));
+            out.extend(lines[to..].iter().map(|l| l.to_string()));
+        }
+        None => {
+            out.extend(lines[..block.end].iter().map(|l| l.to_string()));
+            out.push(render(block.format, key, value));
+            out.extend(lines[block.end..].iter().map(|l| l.to_string()));
+        }
+    }
+    out.join("\n")
+}
+
+/// 키 삭제 (없으면 원문 그대로)
+pub fn remove(content: &str, key: &str) -> String {
+    let lines: Vec<&str> = content.split('\n').collect();
+    let Some(block) = locate(&lines) else { return content.to_string() };
+    let Some((from, to)) = field_range(&lines, &block, key) else { return content.to_string() };
+    lines[..from]
+        .iter()
+        .chain(lines[to..].iter())
+        .copied()
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// ── 날짜 ──
+
+/// Hugo 날짜 문자열을 unix 초로 변환.
+/// 지원: `2024-01-31`, `2024-01-31T10:00:00`, `2024-01-31 10:00`, 소수 초, `Z`/`+09:00`/`+0900`.
+/// 오프셋이 없으면 UTC로 간주한다.
+pub fn parse_date(s: &str) -> Option<i64> {
+    let s = unquote(s);
+    let date_part = s.get(..10)?;
+    let mut ymd = date_part.split('-');
+    let year: i32 = ymd.next()?.parse().ok()?;
+    let month: u8 = ymd.next()?.parse().ok()?;
+    let day: u8 = ymd.next()?.parse().ok()?;
+    let date = Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()?;
+
+    let rest = s[10..].trim_start_matches(['T', 't', ' ']);
+    if rest.is_empty() {
+        return Some(PrimitiveDateTime::new(date, Time::MIDNIGHT).assume_utc().unix_timestamp());
+    }
+
+    // 시각과 오프셋 분리
+    let offset_pos = rest.find(['Z', 'z', '+']).or_else(|| rest.rfind('-'));
+    let (time_part, offset_part) = match offset_pos {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, ""),
+    };
+    let time_part = time_part.split('.').next().unwrap_or("");
+    let mut hms = time_part.split(':');
+    let hour: u8 = hms.next()?.trim().parse().ok()?;
+    let minute: u8 = hms.next().unwrap_or("0").parse().ok()?;
+    let second: u8 = hms.next().unwrap_or("0").parse().ok()?;
+    let time = Time::from_hms(hour, minute, second).ok()?;
+
+    let offset = match offset_part.trim() {
+        "" | "Z" | "z" => UtcOffset::UTC,
+        o => {
+            let sign: i8 = if o.starts_with('-') { -1 } else { 1 };
+            let digits: String = o[1..].chars().filter(|c| c.is_ascii_digit()).collect();
+            let oh: i8 = digits.get(..2)?.parse().ok()?;
+            let om: i8 = digits.get(2..4).unwrap_or("0").parse().ok()?;
+            UtcOffset::from_hms(sign * oh, sign * om, 0).ok()?
+        }
+    };
+    Some(PrimitiveDateTime::new(date, time).assume_offset(offset).unix_timestamp())
+}
+
+pub fn now_unix() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+/// unix 초 → RFC3339 (UTC, `Z`)
+pub fn format_rfc3339(unix: i64) -> String {
+    let dt = OffsetDateTime::from_unix_timestamp(unix).unwrap_or(OffsetDateTime::UNIX_EPOCH);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        dt.year(),
+        u8::from(dt.month()),
+        dt.day(),
+        dt.hour(),
+        dt.minute(),
+        dt.second()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = "---\ntitle: \"Hello\"\ntags:\n  - rust\n  - \"web dev\"\ncategories: [a, 'b']\n---\nbody\n";
+    const TOML: &str = "+++\ntitle = \"Hello\"\ntags = [\n  \"rust\",\n  \"web dev\",\n]\ncategories = \"a\"\n+++\nbody\n";
+
+    #[test]
+    fn get_list_yaml() {
+        assert_eq!(get_list(YAML, "tags"), vec!["rust", "web dev"]);
+        assert_eq!(get_list(YAML, "categories"), vec!["a", "b"]);
+        assert!(get_list(YAML, "aliases").is_empty());
+    }
+
+    #[test]
+    fn get_list_toml() {
+        assert_eq!(get_list(TOML, "tags"), vec!["rust", "web dev"]);
+        // 단일 스칼라도 항목 하나로
+        assert_eq!(get_list(TOML, "categories"), vec!["a"]);
+    }
+
+    #[test]
+    fn set_replaces_block_list_yaml() {
+        let updated = set(YAML, "tags", &FmValue::List(vec!["go".into()]));
+        assert_eq!(updated, "---\ntitle: \"Hello\"\ntags: [\"go\"]\ncategories: [a, 'b']\n---\nbody\n");
+        assert_eq!(get_list(&updated, "tags"), vec!["go"]);
+    }
+
+    #[test]
+    fn set_replaces_multiline_array_toml() {
+        let updated = set(TOML, "tags", &FmValue::List(vec!["go".into(), "c".into()]));
+        assert_eq!(updated, "+++\ntitle = \"Hello\"\ntags = [\"go\", \"c\"]\ncategories = \"a\"\n+++\nbody\n");
+    }
+
+    #[test]
+    fn set_appends_missing_key() {
+        let updated = set(TOML, "draft", &FmValue::Bool(true));
+        assert!(updated.contains("categories = \"a\"\ndraft = true\n+++\n"));
+    }
+
+    #[test]
+    fn set_without_front_matter_creates_yaml() {
+        assert_eq!(set("body", "draft", &FmValue::Bool(false)), "---\ndraft: false\n---\nbody");
+    }
+}
@@ -0,0 +1,286 @@
+//! 마크다운 본문 + front matter의 이미지/첨부파일 참조 탐색.
+//!
+//! 지원 형식:
+//! - `![alt](path "title")`, `<img src="path">`
+//! - 참조형 `![alt][id]` / `![id][]` / `![id]` + 정의 `[id]: path` (이미지에서 쓰인 id의 정의만)
+//! - shortcode 속성: `{{< figure src="path" >}}` + 사이트별로 추가한 shortcode/속성
+//! - front matter: `images` (리스트/스칼라), `cover.image` (YAML 중첩, TOML `[cover]`/`cover.image`)
+//! - 첨부파일: 첨부 확장자(pdf, zip 등)로 끝나는 일반 링크 `[text](path)`, `<a href>`,
+//!   `<video|audio|source src>`, 참조 정의 `[id]: path`
+//!
+//! 각 참조는 경로 텍스트의 byte 범위를 가지므로 원문의 나머지 부분을 건드리지 않고 치환할 수 있다.
+
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::types::config::cms_config::ImageShortcode;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageRefKind {
+    Markdown,
+    HtmlImg,
+    Shortcode,
+    /// 참조형 이미지의 `[id]: path` 정의
+    RefDefinition,
+    FrontMatter,
+    /// 첨부 확장자로 끝나는 일반 링크/미디어 태그/참조 정의
+    Attachment,
+}
+
+/// 사이트별 참조 인식 설정
+#[derive(Debug, Clone, Default)]
+pub struct RefSyntax {
+    /// 추가 shortcode 속성 (`figure`의 `src`는 기본 포함)
+    pub shortcodes: Vec<ImageShortcode>,
+    /// 일반 링크에서도 첨부파일로 추적할 확장자 (소문자, 점 없이)
+    pub attachment_exts: Vec<String>,
+}
+
+impl RefSyntax {
+    /// 경로(fragment/query 제외)의 확장자가 첨부 확장자인지
+    pub fn is_attachment(&self, path: &str) -> bool {
+        let path = path.split(['#', '?']).next().unwrap_or(path);
+        let name = path.rsplit('/').next().unwrap_or(path);
+        match name.rsplit_once('.') {
+            Some((stem, ext)) if !stem.is_empty() => self.attachment_exts.iter().any(|e| e.eq_ignore_ascii_case(ext)),
+            _ => false,
+        }
+    }
+}
+
+/// 이미지 참조 한 건. start..end는 content 안에서 경로 텍스트의 byte 범위.
+#[derive(Debug, Clone)]
+pub struct ImageRef {
+    pub kind: ImageRefKind,
+    pub path: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// 기본으로 인식하는 shortcode 속성
+const BUILTIN_SHORTCODES: &[(&str, &str)] = &[("figure", "src")];
+/// 이미지 경로 리스트를 담는 front matter 키
+const FM_LIST_KEYS: &[&str] = &["images"];
+/// 이미지 경로를 담는 중첩 front matter 키 (table, key)
+const FM_NESTED_KEYS: &[(&str, &str)] = &[("cover", "image")];
+
+static MD_IMAGE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"!\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+["'(][^)]*)?\)"#).unwrap()
+});
+static HTML_IMG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"<img\s[^>]*src\s*=\s*["']([^"']+)["']"#).unwrap());
+/// `![alt][id]`, `![id][]`, `![id]` (뒤에 `(`/`[`가 오지 않는 경우)
+static REF_IMAGE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"!\[([^\]]*)\](?:\[([^\]]*)\])?").unwrap());
+static REF_DEF_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)^ {0,3}\[([^\]]+)\]:[ \t]*<?([^\s>]+)").unwrap());
+/// 일반 링크 `[text](path)` (이미지 여부는 앞 글자로 판단)
+static MD_LINK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+["'(][^)]*)?\)"#).unwrap()
+});
+static HTML_MEDIA_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"<(?:a\s[^>]*href|(?:video|audio|source)\s[^>]*src)\s*=\s*["']([^"']+)["']"#).unwrap()
+});
+
+fn shortcode_re(name: &str, attr: &str) -> Option<Regex> {
+    Regex::new(&format!(
+        r#"\{{\{{[<%]\s*{}\s[^}}]*?\b{}\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>%}}]+))"#,
+        regex::escape(name),
+        regex::escape(attr),
+    ))
+    .ok()
+}
+
+/// content의 모든 이미지/첨부파일 참조 (시작 위치순)
+pub fn find_image_refs(content: &str, syntax: &RefSyntax) -> Vec<ImageRef> {
+    let mut refs = Vec::new();
+    let mut push = |kind: ImageRefKind, m: regex::Match| {
+        refs.push(ImageRef { kind, path: m.as_str().trim().to_string(), start: m.start(), end: m.end() });
+    };
+
+    for caps in MD_IMAGE_RE.captures_iter(content) {
+        push(ImageRefKind::Markdown, caps.get(1).unwrap());
+    }
+    for caps in HTML_IMG_RE.captures_iter(content) {
+        push(ImageRefKind::HtmlImg, caps.get(1).unwrap());
+    }
+
+    let shortcodes = BUILTIN_SHORTCODES
+        .iter()
+        .map(|(n, a)| (n.to_string(), a.to_string()))
+        .chain(syntax.shortcodes.iter().map(|s| (s.name.clone(), s.attribute.clone())));
+    let mut seen = HashSet::new();
+    for (name, attr) in shortcodes {
+        if name.is_empty() || attr.is_empty() || !seen.insert((name.clone(), attr.clone())) {
+            continue;
+        }
+        let Some(re) = shortcode_re(&name, &attr) else { continue };
+        for caps in re.captures_iter(content) {
+            if let Some(m) = caps.get(1).or_else(|| caps.get(2)).or_else(|| caps.get(3)) {
+                push(ImageRefKind::Shortcode, m);
+            }
+        }
+    }
+
+    // 참조형: 이미지에서 쓰인 id의 정의만 이미지로 본다
+    let mut ids = HashSet::new();
+    for caps in REF_IMAGE_RE.captures_iter(content) {
+        let whole = caps.get(0).unwrap();
+        if caps.get(2).is_none() && content[whole.end()..].starts_with('(') {
+            continue; // 인라인 이미지
+        }
+        let id = caps.get(2).map(|m| m.as_str()).filter(|s| !s.is_empty()).unwrap_or(&caps[1]);
+        ids.insert(id.trim().to_lowercase());
+    }
+    if !ids.is_empty() {
+        for caps in REF_DEF_RE.captures_iter(content) {
+            if ids.contains(&caps[1].trim().to_lowercase()) {
+                push(ImageRefKind::RefDefinition, caps.get(2).unwrap());
+            }
+        }
+    }
+
+    refs.extend(front_matter_refs(content));
+
+    if !syntax.attachment_exts.is_empty() {
+        let mut attachments = Vec::new();
+        for caps in MD_LINK_RE.captures_iter(content) {
+            let whole = caps.get(0).unwrap();
+            if content[..whole.start()].ends_with('!') {
+                continue; // 이미지
+            }
+            attachments.push(caps.get(1).unwrap());
+        }
+        attachments.extend(HTML_MEDIA_RE.captures_iter(content).map(|c| c.get(1).unwrap()));
+        attachments.extend(REF_DEF_RE.captures_iter(content).map(|c| c.get(2).unwrap()));
+        for m in attachments {
+            if syntax.is_attachment(m.as_str().trim()) {
+                refs.push(ImageRef {
+                    kind: ImageRefKind::Attachment,
+                    path: m.as_str().trim().to_string(),
+                    start: m.start(),
+                    end: m.end(),
+                });
+            }
+        }
+    }
+
+    refs.sort_by_key(|r| r.start);
+    refs.dedup_by_key(|r| r.start);
+    refs
+}
+
+/// 줄 단위 (byte offset, 줄) — '\n' 기준
+fn lines_with_offsets(content: &str) -> Vec<(usize, &str)> {
+    let mut offset = 0;
+    content
+        .split('\n')
+        .map(|line| {
+            let item = (offset, line);
+            offset += line.len() + 1;
+            item
+        })
+        .collect()
+}
+
+/// 값 부분(value_start부터 줄 끝)에서 스칼라/인라인 배열 항목의 범위 추출
+fn value_items(line: &str, line_offset: usize, value_start: usize) -> Vec<ImageRef> {
+    let value = &line[value_start..];
+    let trimmed = value.trim_start();
+    let base = line_offset + value_start + (value.len() - trimmed.len());
+    let (body, body_offset) = match trimmed.strip_prefix('[') {
+        Some(inner) => (inner.split(']').next().unwrap_or(""), base + 1),
+        None => (trimmed.split(" #").next().unwrap_or(trimmed).trim_end(), base),
+    };
+
+    let mut items = Vec::new();
+    let mut pos = 0;
+    for part in body.split(',') {
+        let lead = part.len() - part.trim_start().len();
+        let item = part.trim();
+        let (inner, inner_shift) = match item.chars().next() {
+            Some(q @ ('"' | '\'')) if item.len() >= 2 && item.ends_with(q) => (&item[1..item.len() - 1], 1),
+            _ => (item, 0),
+        };
+        if !inner.is_empty() {
+            let start = body_offset + pos + lead + inner_shift;
+            items.push(ImageRef { kind: ImageRefKind::FrontMatter, path: inner.to_string(), start, end: start + inner.len() });
+        }
+        pos += part.len() + 1;
+    }
+    items
+}
+
+/// front matter의 images / cover.image 값 범위
+fn front_matter_refs(content: &str) -> Vec<ImageRef> {
+    let lines = lines_with_offsets(content);
+    let Some(delim) = lines.first().map(|(_, l)| l.trim_end()).filter(|l| *l == "---" || *l == "+++") else {
+        return Vec::new();
+    };
+    let Some(end) = lines.iter().skip(1).position(|(_, l)| l.trim_end() == delim).map(|i| i + 1) else {
+        return Vec::new();
+    };
+
+    let mut refs = Vec::new();
+    // 현재 블록: YAML 중첩 키 이름 또는 TOML 테이블 이름
+    let mut section: Option<&str> = None;
+    let mut in_list = false;
+    for &(offset, line) in &lines[1..end] {
+        let indented = line.starts_with(char::is_whitespace);
+        let trimmed = line.trim();
+
+        // TOML 테이블 헤더
+        if delim == "+++" && trimmed.starts_with('[') && !trimmed.contains('=') {
+            section = Some(trimmed.trim_matches(|c| c == '[' || c == ']').trim());
+            in_list = false;
+            continue;
+        }
+        // YAML 블록 리스트 항목 (`images:` 다음 줄들)
+        if in_list && (indented || trimmed.starts_with('-')) {
+            if let Some(rest) = trimmed.strip_prefix('-') {
+                let value_start = line.len() - rest.len();
+                refs.extend(value_items(line, offset, value_start));
+            }
+            continue;
+        }
+        in_list = false;
+        if delim == "---" && !indented {
+            section = None;
+        }
+
+        let Some(sep) = line.find([':', '=']) else { continue };
+        let key = line[..sep].trim().trim_matches('"');
+        let has_value = !line[sep + 1..].trim().is_empty();
+
+        let (table, field) = match key.split_once('.') {
+            Some((t, f)) => (Some(t), f),
+            None if indented || delim == "+++" => (section, key),
+            None => (None, key),
+        };
+        match table {
+            None if FM_LIST_KEYS.iter().any(|k| k.eq_ignore_ascii_case(field)) => {
+                if has_value {
+                    refs.extend(value_items(line, offset, sep + 1));
+                } else {
+                    in_list = true;
+                }
+            }
+            None if !has_value && delim == "---" => section = Some(field),
+            Some(t) if has_value && FM_NESTED_KEYS.iter().any(|(nt, nf)| nt.eq_ignore_ascii_case(t) && nf.eq_ignore_ascii_case(field)) => {
+                refs.extend(value_items(line, offset, sep + 1));
+            }
+            _ => {}
+        }
+    }
+    refs
+}
+
+/// 조건에 맞는 참조의 경로를 바꾼다. f가 Some(new)를 반환한 참조만 치환.
+pub fn replace_image_refs(content: &str, syntax: &RefSyntax, mut f: impl FnMut(&ImageRef) -> Option<String>) -> String {
+    let mut out = content.to_string();
+    for r in find_image_refs(content, syntax).iter().rev() {
+        if let Some(new_path) = f(r) {
+            out.replace_range(r.start..r.end, &new_path);
+        }
+    }
+    out
+}
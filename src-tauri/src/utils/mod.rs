@@ -1,5 +1,7 @@
 pub mod crypto;
 pub mod error;
+pub mod front_matter;
+pub mod image_refs;
 pub mod shell;
 
 pub use error::IntoInvokeError;
@@ -0,0 +1,39 @@
+import { invoke } from '@tauri-apps/api/core';
+import { writable } from 'svelte/store';
+import type { LinkRewrite } from '../types/generated';
+
+// 트리의 이동/이름 변경: 바뀔 inbound 링크를 먼저 미리보기로 보여주고,
+// 사용자가 고른 방식(링크 재작성 + aliases / 이동만)으로 이동한다.
+
+export interface MoveChoice {
+    rewriteLinks: boolean;
+    addAliases: boolean;
+}
+
+/** LinkRewriteModal이 표시할 대기 중인 이동 (resolve(null) = 취소) */
+export const pendingMove = writable<{
+    src: string;
+    dst: string;
+    rewrites: LinkRewrite[];
+    resolve: (choice: MoveChoice | null) => void;
+} | null>(null);
+
+/** 링크 미리보기 후 이동. 취소하면 false */
+export async function moveWithLinkPreview(src: string, dst: string): Promise<boolean> {
+    const rewrites = await invoke<LinkRewrite[]>('preview_link_rewrites', { src, dst });
+    if (rewrites.length === 0) {
+        await invoke('move_file_or_folder', { src, dst });
+        return true;
+    }
+    const choice = await new Promise<MoveChoice | null>((resolve) => {
+        pendingMove.set({ src, dst, rewrites, resolve });
+    });
+    pendingMove.set(null);
+    if (!choice) return false;
+    if (choice.rewriteLinks) {
+        await invoke('move_with_link_rewrite', { src, dst, addAliases: choice.addAliases });
+    } else {
+        await invoke('move_file_or_folder', { src, dst });
+    }
+    return true;
+}
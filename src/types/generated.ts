@@ -27,6 +27,54 @@ export interface HugoConfig {
 
 export interface CmsConfig {
 	hugo_config: HugoConfig;
+	/** 휴지통 자동 비우기 기간 (일). 0이면 자동으로 비우지 않음 */
+	trash_retention_days?: number;
+	/** 업로드/붙여넣기 이미지 처리 정책 */
+	image_policy?: ImagePolicy;
+	/** 이미지 참조로 인식할 추가 shortcode 속성 (`figure`의 `src`는 기본 포함) */
+	image_shortcodes?: ImageShortcode[];
+	/** 일반 링크에서도 첨부파일로 추적할 확장자 (점 없이). None이면 DEFAULT_ATTACHMENT_EXTENSIONS */
+	attachment_extensions?: string[];
+	/** leaf bundle(index.md) 페이지에 붙여넣은 이미지/첨부파일 저장 위치 */
+	bundle_resource_location?: ResourceLocation;
+}
+
+/** 페이지 리소스 저장 위치 */
+export enum ResourceLocation {
+	/** image_path 아래 페이지 디렉토리 (다른 페이지와 같은 방식) */
+	ImagePath = "ImagePath",
+	/** 번들 디렉토리 자체 (Hugo page resource, 상대경로로 참조) */
+	Bundle = "Bundle",
+}
+
+/** 업로드 이미지 저장 형식 */
+export enum ImageOutputFormat {
+	/** 원본 형식 유지 */
+	Keep = "Keep",
+	/** WebP (손실, `quality` 적용) */
+	Webp = "Webp",
+	Jpeg = "Jpeg",
+}
+
+/** 업로드 이미지 처리 정책 */
+export interface ImagePolicy {
+	/** 최대 가로 (px). 0이면 제한 없음. 비율을 유지하며 축소만 한다 */
+	max_width: number;
+	/** 최대 세로 (px). 0이면 제한 없음 */
+	max_height: number;
+	format: ImageOutputFormat;
+	/** JPEG 재인코딩 품질 (1-100) */
+	quality: number;
+	/** EXIF(GPS 포함)/XMP 메타데이터 제거 */
+	strip_metadata: boolean;
+	/** EXIF Orientation에 맞춰 픽셀을 회전 */
+	auto_orient: boolean;
+}
+
+/** 이름 붙은 검색 쿼리 (사이드바 스마트 폴더). query는 `search_query` 문법 */
+export interface SavedSearch {
+	name?: string;
+	query?: string;
 }
 
 /**
@@ -38,6 +86,7 @@ export interface AppConfig {
 	servers?: ServerEntry[];
 	cms_config: CmsConfig;
 	shortcuts?: Record<string, string[]>;
+	saved_searches?: SavedSearch[];
 	plugin_local_path?: string;
 	download_path?: string;
 }
@@ -53,11 +102,524 @@ export enum NodeType {
 	Directory = "Directory",
 }
 
+/** 트리에서 제목/필터에 쓰는 페이지 메타데이터 (build_file_tree의 같은 SSH 호출에서 수집) */
+export interface PageMeta {
+	title?: string;
+	date?: string;
+	tags: string[];
+	draft: boolean;
+	word_count: number;
+}
+
+/** 버전이 붙은 파일 내용 */
+export interface VersionedContent {
+	content: string;
+	version: string;
+}
+
+/** 저장 결과 */
+export interface SaveOutcome {
+	/** false = 저장은 됐지만 이미지 sync 실패 */
+	synced: boolean;
+	/** 저장된 내용의 버전 (다음 저장의 expected_version) */
+	version: string;
+}
+
+/** 저장 충돌: 읽은 뒤 원격 파일이 바뀌었다. UI에서 덮어쓰기/다시 읽기/병합을 고른다. */
+export interface SaveConflict {
+	path: string;
+	/** 현재 원격 내용 (None = 원격에서 삭제됨) */
+	remote_content?: string;
+	remote_version?: string;
+}
+
+/**
+ * front matter(draft, publishDate, expiryDate)로 계산한 Hugo 발행 상태.
+ * hidden_path 이동(is_hidden)과는 별개.
+ */
+export enum PublishState {
+	Published = "Published",
+	Draft = "Draft",
+	Scheduled = "Scheduled",
+	Expired = "Expired",
+}
+
 export interface FileSystemNode {
 	name: string;
 	type_: NodeType;
+	/** leaf bundle 디렉토리 (index.md + 리소스). meta/publish_state는 index.md 기준 */
+	bundle?: boolean;
 	is_hidden: boolean;
+	publish_state?: PublishState;
+	/** front matter 메타데이터 (.md 파일 / _index.md 또는 index.md가 있는 폴더) */
+	meta?: PageMeta;
+	children: FileSystemNode[];
+}
+
+/** 지연 로딩 트리의 한 페이지 */
+export interface DirPage {
+	/** 요청한 디렉토리 상대 경로 (예: "/posts/sub") */
+	path: string;
+	/** 정렬 + hidden 병합된 직계 자식 (하위 폴더의 children은 비어 있음) */
 	children: FileSystemNode[];
+	/** 병합 후 전체 자식 수 */
+	total: number;
+	offset: number;
+	has_more: boolean;
+}
+
+/** 리비전 하나 (id = 파일명에서 확장자를 뺀 `<millis>-<version>`) */
+export interface Revision {
+	id: string;
+	/** 저장 시각 (RFC3339, UTC) */
+	created_at: string;
+	/** 내용 버전 (read_content_versioned의 version과 같은 규칙) */
+	version: string;
+}
+
+export enum TrashOrigin {
+	/** 사이드바 content 트리 (content/hidden + 이미지) */
+	Content = "Content",
+	/** 파일 탐색기의 임의 원격 경로 */
+	Remote = "Remote",
+	/** 고아 이미지 정리로 옮긴 이미지 파일들 */
+	Images = "Images",
+}
+
+/** 휴지통 항목 */
+export interface TrashItem {
+	id: string;
+	origin: TrashOrigin;
+	/** Content: content 상대경로 ("/posts/a.md"), Remote: 원격 절대경로, Images: 이미지 루트 절대경로 */
+	original_path: string;
+	is_dir: boolean;
+	/** content 쪽에 있던 원본을 보관 중 */
+	in_content: boolean;
+	/** hidden 쪽에 있던 원본을 보관 중 (in_content=false면 숨김 파일이었음) */
+	in_hidden: boolean;
+	/** 함께 옮긴 이미지 디렉토리의 원래 절대경로 */
+	image_dir?: string;
+	/** 삭제 시각 (RFC3339, UTC) */
+	deleted_at: string;
+	/** Images: 옮긴 파일들 (image_path 기준 "/posts/a.md/x.png") */
+	files?: string[];
+}
+
+export enum WatchMode {
+	Inotify = "Inotify",
+	Poll = "Poll",
+}
+
+export enum ChangeTarget {
+	Content = "Content",
+	Hidden = "Hidden",
+	Image = "Image",
+}
+
+/** 변경된 경로 하나 */
+export interface ChangedPath {
+	/** Content/Hidden: "/posts/a.md" (hidden 접두 제거), Image: image_path 기준 "/posts/a.md/x.png" */
+	path: string;
+	target: ChangeTarget;
+	/** 삭제/이동으로 사라진 경로 */
+	removed: boolean;
+}
+
+/** `content:changed` 이벤트 payload */
+export interface ContentChangedEvent {
+	mode: WatchMode;
+	changes: ChangedPath[];
+}
+
+/** 링크 재작성 한 건 */
+export interface LinkRewrite {
+	/** 링크가 있는 파일 (이동 전 content 상대경로) */
+	file: string;
+	hidden: boolean;
+	/** 1-based */
+	line: number;
+	/** 줄 안의 byte 위치 */
+	column: number;
+	old_target: string;
+	new_target: string;
+}
+
+export enum LinkIssueKind {
+	/** 내부 링크 / ref·relref 대상 페이지 없음 */
+	BrokenLink = "BrokenLink",
+	/** image_path 아래에 이미지 파일 없음 */
+	MissingImage = "MissingImage",
+	/** 페이지 디렉토리에 첨부파일 없음 */
+	MissingAttachment = "MissingAttachment",
+	/** 공개 페이지에서 숨김 영역의 페이지를 가리킴 */
+	HiddenTarget = "HiddenTarget",
+	/** 외부 URL 응답 오류 (check_external일 때만) */
+	ExternalError = "ExternalError",
+}
+
+/** 점검 결과 한 건 */
+export interface LinkIssue {
+	/** 문제가 있는 파일 (content 상대경로) */
+	file: string;
+	hidden: boolean;
+	/** 1-based */
+	line: number;
+	kind: LinkIssueKind;
+	/** 본문에 적힌 링크/이미지 경로 */
+	target: string;
+	/** ExternalError: HTTP 상태 코드 또는 오류 메시지 */
+	detail?: string;
+}
+
+/** 고아 이미지 한 건 */
+export interface OrphanImage {
+	/** image_path 기준 경로 ("/posts/a.md/x.png") */
+	path: string;
+	size: number;
+}
+
+export enum OrphanAction {
+	/** 영구 삭제 */
+	Delete = "Delete",
+	/** 휴지통 항목 하나로 묶어 이동 */
+	Trash = "Trash",
+}
+
+/** 고아 이미지 목록 / 정리 결과 */
+export interface OrphanReport {
+	images: OrphanImage[];
+	total_size: number;
+	/** true면 아무것도 지우지 않은 미리보기 */
+	dry_run: boolean;
+	/** Trash로 정리했을 때 생성된 휴지통 항목 */
+	trash_id?: string;
+}
+
+/** image_path 아래 이미지 한 건 */
+export interface LibraryImage {
+	/** image_path 기준 경로 ("/posts/a.md/x.png") */
+	path: string;
+	size: number;
+	/** 내용 sha256 (썸네일 캐시 키) */
+	hash: string;
+	/** 원본 크기 (회전 반영). 헤더를 읽을 수 없는 파일(SVG 등)은 None */
+	width?: number;
+	height?: number;
+	/** 이 이미지를 참조하는 페이지 (content 상대경로) */
+	referenced_by: string[];
+}
+
+/** 썸네일 요청 (list_images 결과의 path/hash) */
+export interface ThumbnailRequest {
+	path: string;
+	hash: string;
+}
+
+export interface ImageThumbnail {
+	path: string;
+	hash: string;
+	/** 원본 크기 (회전 반영) */
+	width: number;
+	height: number;
+	/** "data:image/jpeg;base64,…" */
+	data_url: string;
+}
+
+/** 이미지 경로를 담는 shortcode 속성: `{{< name attribute="path" >}}` */
+export interface ImageShortcode {
+	name: string;
+	attribute: string;
+}
+
+/** 새 페이지에 바로 적용할 front matter 값 (None이면 archetype 값 유지) */
+export interface InitialFrontMatter {
+	title?: string;
+	tags?: string[];
+	draft?: boolean;
+}
+
+/** 사이트/테마의 archetype */
+export interface Archetype {
+	/** `hugo new --kind`에 넘기는 이름 (파일명에서 .md 제외) */
+	kind: string;
+	/** None = 사이트 archetypes/, Some = 테마 이름 */
+	theme?: string;
+	/** 디렉토리 archetype (leaf bundle을 통째로 생성) */
+	bundle: boolean;
+}
+
+export interface TaxonomyTerm {
+	term: string;
+	/** 이 용어를 가진 페이지 수 (hidden 포함) */
+	count: number;
+	/** 그중 hidden 페이지 수 */
+	hidden_count: number;
+}
+
+export interface Taxonomy {
+	/** front matter 키 (복수형, 예: "tags") */
+	name: string;
+	/** 페이지 수 내림차순, 같으면 이름순 */
+	terms: TaxonomyTerm[];
+}
+
+/** 용어 이름 변경(from 1개) / 병합(from 여러 개) */
+export interface TaxonomyChange {
+	taxonomy: string;
+	/** 바꿀 용어들 (정확히 일치하는 것만) */
+	from: string[];
+	/** 새 용어. 비어 있으면 from 용어를 페이지에서 제거 */
+	to: string;
+}
+
+/** 페이지 하나의 변경 전/후 용어 목록 */
+export interface TaxonomyPageChange {
+	file: string;
+	hidden: boolean;
+	before: string[];
+	after: string[];
+}
+
+/** hidden 페이지 포함 범위 */
+export enum HiddenScope {
+	Include = "Include",
+	Exclude = "Exclude",
+	Only = "Only",
+}
+
+export interface SearchOptions {
+	/** 텍스트 조건을 정규식으로 해석 */
+	regex?: boolean;
+	case_insensitive?: boolean;
+	hidden?: HiddenScope;
+	/** 매칭 줄 앞뒤 문맥 줄 수 (기본 2) */
+	context_lines?: number;
+	/** 결과 파일 수 상한 (기본 200) */
+	limit?: number;
+}
+
+export interface SearchLine {
+	/** 1-based */
+	line_num: number;
+	line_text: string;
+	before: string[];
+	after: string[];
+}
+
+export interface SearchHit {
+	/** content 상대경로 (hidden이면 hidden_path 제외) */
+	file_path: string;
+	is_hidden: boolean;
+	title?: string;
+	/** 높을수록 관련도 높음. 필터만 있는 쿼리는 0 */
+	score: number;
+	/** 텍스트 조건에 걸린 줄 (필터만 있는 쿼리는 비어 있음) */
+	matches: SearchLine[];
+}
+
+export interface IndexQueryOptions {
+	/** true면 prefix/fuzzy 확장 없이 정확히 일치하는 단어만 */
+	exact?: boolean;
+	hidden?: HiddenScope;
+	/** 결과 파일 수 상한 (기본 50) */
+	limit?: number;
+}
+
+/** 스니펫 조각. highlight=true인 조각이 검색어에 걸린 단어 */
+export interface SnippetPart {
+	text: string;
+	highlight: boolean;
+}
+
+export interface IndexSnippet {
+	/** 1-based */
+	line_num: number;
+	parts: SnippetPart[];
+}
+
+export interface IndexHit {
+	file_path: string;
+	is_hidden: boolean;
+	title?: string;
+	score: number;
+	snippets: IndexSnippet[];
+}
+
+export interface IndexSyncReport {
+	added: number;
+	updated: number;
+	removed: number;
+	/** 동기화 후 문서 수 */
+	documents: number;
+}
+
+/** 찾아 바꾸기 조건 */
+export interface ReplaceRequest {
+	find: string;
+	/** regex 모드에서는 `$1`, `${name}`으로 캡처 그룹 참조 */
+	replace: string;
+	regex?: boolean;
+	case_insensitive?: boolean;
+	/** 이 섹션(content 상대경로 접두어, 예: "posts") 아래만 */
+	section?: string;
+	hidden?: HiddenScope;
+	/** 이 태그를 가진 페이지만 (대소문자 무시) */
+	tag?: string;
+}
+
+/** 일치 하나 */
+export interface ReplaceMatch {
+	/** 파일 안 순번 (적용 시 선택 키) */
+	index: number;
+	/** 1-based */
+	line_num: number;
+	/** 줄 안의 byte 위치 */
+	column: number;
+	matched: string;
+	replacement: string;
+	/** 원래 줄 */
+	before: string;
+	/** 이 일치만 바꾼 줄 */
+	after: string;
+}
+
+export interface ReplaceFilePreview {
+	file: string;
+	hidden: boolean;
+	/** 미리보기 시점의 내용 버전 (적용 시 그대로 돌려준다) */
+	version: string;
+	matches: ReplaceMatch[];
+}
+
+/** 적용할 일치 선택 */
+export interface ReplaceSelection {
+	file: string;
+	version: string;
+	indices: number[];
+}
+
+export interface ReplacedFile {
+	file: string;
+	replaced: number;
+}
+
+export interface SkippedFile {
+	file: string;
+	reason: string;
+}
+
+export interface ReplaceResult {
+	/** 되돌리기용 묶음 id (바뀐 파일이 없으면 None) */
+	batch_id?: string;
+	files: ReplacedFile[];
+	skipped: SkippedFile[];
+}
+
+/** 적용 묶음 요약 */
+export interface ReplaceBatchSummary {
+	id: string;
+	/** 적용 시각 (RFC3339, UTC) */
+	created_at: string;
+	find: string;
+	replace: string;
+	files: ReplacedFile[];
+}
+
+export interface ReplaceUndoResult {
+	restored: string[];
+	skipped: SkippedFile[];
+}
+
+/** 스마트 폴더 안의 페이지 */
+export interface SavedSearchPage {
+	/** content 상대경로 (hidden이면 hidden_path 제외) */
+	path: string;
+	is_hidden: boolean;
+	title?: string;
+}
+
+/** 저장된 검색 하나를 실행한 가상 폴더 */
+export interface SavedSearchFolder {
+	name: string;
+	query: string;
+	pages: SavedSearchPage[];
+	/** 쿼리를 실행하지 못한 이유 (잘못된 쿼리 등). 있으면 pages는 비어 있다 */
+	error?: string;
+}
+
+export enum ImportSource {
+	/** Notion "Markdown & CSV" export zip */
+	Notion = "Notion",
+	/** WordPress 도구 > 내보내기 파일 (WXR .xml) */
+	WordPress = "WordPress",
+	/** Jekyll 사이트 루트 또는 `_posts` 폴더 */
+	Jekyll = "Jekyll",
+}
+
+export interface ImportRequest {
+	source: ImportSource;
+	/** 로컬 경로 (zip / xml 파일 또는 폴더) */
+	path: string;
+	/** 가져온 페이지를 둘 섹션 (content 기준, 예: "posts") */
+	section: string;
+	/** true면 아무것도 쓰지 않고 만들어질 경로와 문제만 계산 */
+	dry_run?: boolean;
+}
+
+export interface FolderImportRequest {
+	/** 로컬 폴더 (Obsidian vault, Typora 폴더 등) */
+	path: string;
+	/** 폴더를 대응시킬 섹션. 하위 폴더 구조는 그대로 유지 */
+	section: string;
+	/** true면 올리지 않고 만들어질 경로와 문제만 계산 */
+	dry_run?: boolean;
+}
+
+export interface ExportRequest {
+	/** 내보낼 섹션 (content 기준, 예: "posts" / "docs/guide"). 비어 있으면 사이트 전체 */
+	section?: string;
+	/** 만들 zip 파일의 로컬 경로 */
+	dest: string;
+	/** hidden 페이지 포함 */
+	include_hidden?: boolean;
+	/** zip 루트에 manifest.json 추가 */
+	manifest?: boolean;
+}
+
+export interface ExportIssue {
+	/** zip 안의 페이지 경로 */
+	path: string;
+	message: string;
+}
+
+export interface ExportReport {
+	dest: string;
+	pages: number;
+	/** 페이지 옆으로 복사한 이미지/첨부 수 */
+	assets: number;
+	issues: ExportIssue[];
+}
+
+export interface ImportedPage {
+	/** 원본에서의 이름 (zip 안 경로, 글 제목, 파일명) */
+	source: string;
+	/** 만든 페이지 ("/posts/a.md") */
+	path: string;
+	title: string;
+	/** 저장한 첨부 수 (dry_run이면 저장할 수) */
+	attachments: number;
+}
+
+/** 변환하지 못한 것 */
+export interface ImportIssue {
+	source: string;
+	message: string;
+}
+
+export interface ImportReport {
+	pages: ImportedPage[];
+	issues: ImportIssue[];
+	dry_run: boolean;
 }
 
 export interface InputField {
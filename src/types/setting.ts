@@ -43,3 +43,12 @@ export function createDefaultAppConfig(): AppConfig {
 export function createDefaultServerEntry(): ServerEntry {
     return { id: "", name: "", ssh_config: createDefaultSshConfig() };
 }
+
+/** 첨부파일 기본 확장자 (cms_config.rs DEFAULT_ATTACHMENT_EXTENSIONS와 같게 유지) */
+export const DEFAULT_ATTACHMENT_EXTENSIONS = ["pdf", "zip", "mp4", "webm", "mov", "mp3"];
+
+/** 첨부파일 확장자 (소문자, 점 없이). CmsConfig::attachment_exts와 같은 규칙 */
+export function attachmentExts(cms: CmsConfig): string[] {
+    if (!cms.attachment_extensions) return DEFAULT_ATTACHMENT_EXTENSIONS;
+    return cms.attachment_extensions.map((e) => e.trim().replace(/^\.+/, "").toLowerCase()).filter((e) => e);
+}